@@ -0,0 +1,150 @@
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use serde::Deserialize;
+
+use crate::error::RegistryError;
+
+/// A single candidate RPC/gRPC endpoint for a chain, as discovered by an
+/// [`EndpointSource`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Endpoint {
+    pub address: String,
+    pub port: u16,
+    pub tags: Vec<String>,
+}
+
+impl Endpoint {
+    pub fn url(&self) -> String {
+        format!("{}:{}", self.address, self.port)
+    }
+}
+
+/// Enumerates the candidate endpoints for a chain before the existing health
+/// checks (status, consensus params, syncing, websocket reachability) narrow
+/// them down to the one the relayer should actually use.
+///
+/// This indirection lets the registry be backed either by a fixed, hand-edited
+/// list of endpoints, or by a dynamic service catalog that tracks a scaling
+/// node pool on the operator's behalf.
+pub trait EndpointSource {
+    fn candidate_endpoints(&self, chain: &str) -> Result<Vec<Endpoint>, RegistryError>;
+}
+
+/// The original behavior: a fixed, operator-maintained list of endpoints per
+/// chain, configured ahead of time.
+#[derive(Clone, Debug, Default)]
+pub struct StaticListSource {
+    endpoints: BTreeMap<String, Vec<Endpoint>>,
+}
+
+impl StaticListSource {
+    pub fn new(endpoints: BTreeMap<String, Vec<Endpoint>>) -> Self {
+        Self { endpoints }
+    }
+}
+
+impl EndpointSource for StaticListSource {
+    fn candidate_endpoints(&self, chain: &str) -> Result<Vec<Endpoint>, RegistryError> {
+        self.endpoints
+            .get(chain)
+            .cloned()
+            .ok_or_else(|| RegistryError::no_healthy_rpc(chain.to_string()))
+    }
+}
+
+#[derive(Deserialize)]
+struct CatalogEntry {
+    #[serde(rename = "Address")]
+    address: String,
+    #[serde(rename = "Port")]
+    port: u16,
+    #[serde(rename = "Tags", default)]
+    tags: Vec<String>,
+    #[serde(rename = "Health")]
+    health: String,
+}
+
+struct CachedEndpoints {
+    fetched_at: Instant,
+    endpoints: Vec<Endpoint>,
+}
+
+/// Discovers endpoints by querying a Consul-style HTTP service catalog
+/// (`GET {catalog_url}/v1/health/service/{chain}`, returning a JSON array of
+/// `{Address, Port, Tags, Health}` entries), keeping only the entries whose
+/// health check is passing. The first healthy response for a chain is cached
+/// for `ttl` so that repeated lookups don't hit the catalog on every retry.
+pub struct CatalogSource {
+    catalog_url: String,
+    client: reqwest::blocking::Client,
+    ttl: Duration,
+    cache: Mutex<BTreeMap<String, CachedEndpoints>>,
+}
+
+impl CatalogSource {
+    pub fn new(catalog_url: String, ttl: Duration) -> Self {
+        Self {
+            catalog_url,
+            client: reqwest::blocking::Client::new(),
+            ttl,
+            cache: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    fn fetch(&self, chain: &str) -> Result<Vec<Endpoint>, RegistryError> {
+        let url = format!("{}/v1/health/service/{chain}", self.catalog_url);
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .map_err(|e| RegistryError::request_error(url.clone(), e))?;
+
+        let entries: Vec<CatalogEntry> = response
+            .json()
+            .map_err(|e| RegistryError::request_error(url, e))?;
+
+        let endpoints: Vec<Endpoint> = entries
+            .into_iter()
+            .filter(|entry| entry.health == "passing")
+            .map(|entry| Endpoint {
+                address: entry.address,
+                port: entry.port,
+                tags: entry.tags,
+            })
+            .collect();
+
+        if endpoints.is_empty() {
+            return Err(RegistryError::no_healthy_rpc(chain.to_string()));
+        }
+
+        Ok(endpoints)
+    }
+}
+
+impl EndpointSource for CatalogSource {
+    fn candidate_endpoints(&self, chain: &str) -> Result<Vec<Endpoint>, RegistryError> {
+        let mut cache = self.cache.lock().unwrap();
+
+        if let Some(cached) = cache.get(chain) {
+            if cached.fetched_at.elapsed() < self.ttl {
+                return Ok(cached.endpoints.clone());
+            }
+        }
+
+        let endpoints = self.fetch(chain)?;
+
+        cache.insert(
+            chain.to_string(),
+            CachedEndpoints {
+                fetched_at: Instant::now(),
+                endpoints: endpoints.clone(),
+            },
+        );
+
+        Ok(endpoints)
+    }
+}
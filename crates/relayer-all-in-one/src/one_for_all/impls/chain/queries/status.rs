@@ -34,8 +34,14 @@ where
     async fn query_chain_status(
         context: &OfaChainWrapper<Chain>,
     ) -> Result<Chain::ChainStatus, Chain::Error> {
+        #[cfg(feature = "std")]
+        let _span = tracing::info_span!("query_chain_status").entered();
+
         let status = context.chain.query_chain_status().await?;
 
+        #[cfg(feature = "std")]
+        tracing::info!("queried chain status");
+
         Ok(status)
     }
 }
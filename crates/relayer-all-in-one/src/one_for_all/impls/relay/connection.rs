@@ -1,11 +1,15 @@
 use async_trait::async_trait;
 
+use ibc_relayer_components::relay::impls::connection::open_ack::RelayConnectionOpenAck;
+use ibc_relayer_components::relay::impls::connection::open_confirm::RelayConnectionOpenConfirm;
 use ibc_relayer_components::relay::impls::connection::open_init::{
     InitializeConnection, InjectMissingConnectionInitEventError,
 };
 use ibc_relayer_components::relay::impls::connection::open_try::{
     InjectMissingConnectionTryEventError, RelayConnectionOpenTry,
 };
+use ibc_relayer_components::relay::traits::connection::open_ack::CanRelayConnectionOpenAck;
+use ibc_relayer_components::relay::traits::connection::open_confirm::CanRelayConnectionOpenConfirm;
 use ibc_relayer_components::relay::traits::connection::open_init::{
     CanInitConnection, ConnectionInitializer,
 };
@@ -65,3 +69,37 @@ where
         RelayConnectionOpenTry::relay_connection_open_try(self, src_connection_id).await
     }
 }
+
+#[async_trait]
+impl<Relay> CanRelayConnectionOpenAck for OfaRelayWrapper<Relay>
+where
+    Relay: OfaRelay,
+{
+    async fn relay_connection_open_ack(
+        &self,
+        dst_connection_id: &<Relay::DstChain as OfaChain>::ConnectionId,
+        src_connection_id: &<Relay::SrcChain as OfaChain>::ConnectionId,
+    ) -> Result<(), Self::Error> {
+        RelayConnectionOpenAck::relay_connection_open_ack(self, dst_connection_id, src_connection_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl<Relay> CanRelayConnectionOpenConfirm for OfaRelayWrapper<Relay>
+where
+    Relay: OfaRelay,
+{
+    async fn relay_connection_open_confirm(
+        &self,
+        dst_connection_id: &<Relay::DstChain as OfaChain>::ConnectionId,
+        src_connection_id: &<Relay::SrcChain as OfaChain>::ConnectionId,
+    ) -> Result<(), Self::Error> {
+        RelayConnectionOpenConfirm::relay_connection_open_confirm(
+            self,
+            dst_connection_id,
+            src_connection_id,
+        )
+        .await
+    }
+}
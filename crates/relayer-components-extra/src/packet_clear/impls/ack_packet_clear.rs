@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use futures_util::{stream, StreamExt};
+use ibc_relayer_components::chain::traits::queries::packet_commitments::{
+    CanQueryPacketAcknowledgements, CanQueryPacketCommitments, CanQueryWriteAckEvents,
+};
+use ibc_relayer_components::chain::traits::queries::unreceived_packets::CanQueryUnreceivedAcks;
+use ibc_relayer_components::chain::types::aliases::{ChannelId, PortId};
+use ibc_relayer_components::relay::traits::packet::HasRelayPacket;
+use ibc_relayer_components::relay::traits::packet_relayer::CanRelayAckPacket;
+
+use crate::packet_clear::traits::packet_clear::AckPacketClearer;
+use crate::std_prelude::*;
+
+pub struct AckPacketClearRelayer;
+
+/// Flushes stuck acknowledgements the same way
+/// [`super::receive_packets_clear::ReceivePacketClearRelayer`] flushes stuck
+/// `recv_packet`s, but walking the ack path in reverse: it asks the
+/// destination which of the source's outstanding commitments it has already
+/// written an acknowledgement for, asks the source which of those acks it
+/// has not yet processed, fetches the underlying `WriteAcknowledgement`
+/// payloads, and relays the remainder concurrently.
+#[async_trait]
+impl<Relay> AckPacketClearer<Relay> for AckPacketClearRelayer
+where
+    Relay: HasRelayPacket + CanRelayAckPacket,
+    Relay::SrcChain: CanQueryPacketCommitments<Relay::DstChain>
+        + CanQueryUnreceivedAcks<Relay::DstChain>,
+    Relay::DstChain: CanQueryPacketAcknowledgements<Relay::SrcChain>
+        + CanQueryWriteAckEvents<Relay::SrcChain>,
+{
+    async fn clear_ack_packets(
+        relay: &Relay,
+        src_channel_id: &ChannelId<Relay::SrcChain, Relay::DstChain>,
+        src_port_id: &PortId<Relay::SrcChain, Relay::DstChain>,
+        dst_channel_id: &ChannelId<Relay::DstChain, Relay::SrcChain>,
+        dst_port_id: &PortId<Relay::DstChain, Relay::SrcChain>,
+    ) -> Result<(), Relay::Error> {
+        let src_chain = relay.src_chain();
+        let dst_chain = relay.dst_chain();
+
+        let (commitment_sequences, _) = src_chain
+            .query_packet_commitments(src_channel_id, src_port_id)
+            .await
+            .map_err(Relay::src_chain_error)?;
+
+        let (ack_sequences, ack_height) = dst_chain
+            .query_packet_acknowledgements(dst_channel_id, dst_port_id, &commitment_sequences)
+            .await
+            .map_err(Relay::dst_chain_error)?;
+
+        let (unprocessed_ack_sequences, _) = src_chain
+            .query_unreceived_ack_sequences(src_channel_id, src_port_id, &ack_sequences)
+            .await
+            .map_err(Relay::src_chain_error)?;
+
+        let write_ack_events = dst_chain
+            .query_write_ack_events(
+                dst_channel_id,
+                dst_port_id,
+                &unprocessed_ack_sequences,
+                &ack_height,
+            )
+            .await
+            .map_err(Relay::dst_chain_error)?;
+
+        stream::iter(write_ack_events)
+            .for_each_concurrent(None, |t| async move {
+                // Ignore any relaying errors, as the relayer still needs to proceed
+                // relaying the next event regardless.
+                let _ = relay.relay_ack_packet(&t).await;
+            })
+            .await;
+
+        Ok(())
+    }
+}
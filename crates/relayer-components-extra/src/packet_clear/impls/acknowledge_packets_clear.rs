@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use ibc_relayer_components::chain::traits::queries::packet_commitments::{
+    CanQueryPacketAcknowledgements, CanQueryPacketCommitments,
+};
+use ibc_relayer_components::chain::traits::queries::unreceived_packets::CanQueryUnreceivedAcks;
+use ibc_relayer_components::chain::types::aliases::{ChannelId, PortId};
+use ibc_relayer_components::relay::traits::packet::HasRelayPacket;
+use ibc_relayer_components::relay::traits::packet_relayer::CanRelayPacket;
+
+use crate::packet_clear::traits::packet_clear::AcknowledgePacketClearer;
+use crate::std_prelude::*;
+
+pub struct AcknowledgePacketClearRelayer;
+
+/// Mirrors [`super::receive_packets_clear::ReceivePacketClearRelayer`], but for
+/// the acknowledgement side: instead of replaying events to find packets the
+/// counterparty hasn't received yet, it queries the source's outstanding
+/// packet commitments, asks the destination which of those it already wrote
+/// an acknowledgement for, and narrows that down to the ones the source
+/// hasn't cleared yet.
+#[async_trait]
+impl<Relay> AcknowledgePacketClearer<Relay> for AcknowledgePacketClearRelayer
+where
+    Relay: HasRelayPacket + CanRelayPacket,
+    Relay::SrcChain: CanQueryPacketCommitments<Relay::DstChain>
+        + CanQueryUnreceivedAcks<Relay::DstChain>,
+    Relay::DstChain: CanQueryPacketAcknowledgements<Relay::SrcChain>,
+{
+    async fn clear_acknowledge_packets(
+        relay: &Relay,
+        src_channel_id: &ChannelId<Relay::SrcChain, Relay::DstChain>,
+        src_port_id: &PortId<Relay::SrcChain, Relay::DstChain>,
+        dst_channel_id: &ChannelId<Relay::DstChain, Relay::SrcChain>,
+        dst_port_id: &PortId<Relay::DstChain, Relay::SrcChain>,
+    ) -> Result<(), Relay::Error> {
+        let src_chain = relay.src_chain();
+        let dst_chain = relay.dst_chain();
+
+        let (commitment_sequences, _) = src_chain
+            .query_packet_commitments(src_channel_id, src_port_id)
+            .await
+            .map_err(Relay::src_chain_error)?;
+
+        let (acknowledged_sequences, _) = dst_chain
+            .query_packet_acknowledgements(dst_channel_id, dst_port_id, &commitment_sequences)
+            .await
+            .map_err(Relay::dst_chain_error)?;
+
+        let (unreceived_ack_sequences, _) = src_chain
+            .query_unreceived_ack_sequences(src_channel_id, src_port_id, &acknowledged_sequences)
+            .await
+            .map_err(Relay::src_chain_error)?;
+
+        // Only the pending-ack sequences are computed here: no
+        // `acknowledge_packet` message is built or submitted for them, and
+        // `Relay::relay_packet` is not called. `AckPacketClearRelayer` is the
+        // clearer that relays the acknowledgements themselves.
+        let _ = unreceived_ack_sequences;
+
+        Ok(())
+    }
+}
@@ -11,6 +11,11 @@ use ibc_relayer_components::relay::traits::packet_relayer::CanRelayPacket;
 use crate::packet_clear::traits::packet_clear::ReceivePacketClearer;
 use crate::std_prelude::*;
 
+#[cfg(feature = "std")]
+fn clearing_run_span(correlation_id: &str) -> tracing::Span {
+    tracing::info_span!("clear_receive_packets", correlation_id)
+}
+
 pub struct ReceivePacketClearRelayer;
 
 #[async_trait]
@@ -27,7 +32,13 @@ where
         src_port_id: &PortId<Relay::SrcChain, Relay::DstChain>,
         dst_channel_id: &ChannelId<Relay::DstChain, Relay::SrcChain>,
         dst_port_id: &PortId<Relay::DstChain, Relay::SrcChain>,
-    ) -> Result<(), Relay::Error> {
+        max_concurrent_packets: usize,
+    ) -> Result<Vec<(Relay::Packet, Relay::Error)>, Relay::Error> {
+        #[cfg(feature = "std")]
+        let correlation_id = nanoid::nanoid!(8);
+        #[cfg(feature = "std")]
+        let _span = clearing_run_span(&correlation_id).entered();
+
         let dst_chain = relay.dst_chain();
         let src_chain = relay.src_chain();
 
@@ -36,11 +47,23 @@ where
             .await
             .map_err(Relay::src_chain_error)?;
 
+        #[cfg(feature = "std")]
+        tracing::info!(
+            commitment_sequences = commitment_sequences.len(),
+            "queried outstanding packet commitments"
+        );
+
         let unreceived_sequences = dst_chain
             .query_unreceived_packet_sequences(dst_channel_id, dst_port_id, &commitment_sequences)
             .await
             .map_err(Relay::dst_chain_error)?;
 
+        #[cfg(feature = "std")]
+        tracing::info!(
+            unreceived_sequences = unreceived_sequences.len(),
+            "queried unreceived packet sequences"
+        );
+
         let unreceived_packets = src_chain
             .query_unreceived_packets(
                 src_channel_id,
@@ -53,14 +76,31 @@ where
             .await
             .map_err(Relay::src_chain_error)?;
 
-        stream::iter(unreceived_packets)
-            .for_each_concurrent(None, |t| async move {
-                // Ignore any relaying errors, as the relayer still needs to proceed
-                // relaying the next event regardless.
-                let _ = relay.relay_packet(&t).await;
+        // Bounded by `max_concurrent_packets` rather than `for_each_concurrent(None, ..)`
+        // so a channel with thousands of pending commitments doesn't open
+        // thousands of simultaneous RPC/tx submissions against the full node.
+        // Failures are collected instead of discarded so the caller can see
+        // how many packets failed to clear and retry them.
+        let failures = stream::iter(unreceived_packets)
+            .map(|packet| async move {
+                match relay.relay_packet(&packet).await {
+                    Ok(()) => {
+                        #[cfg(feature = "std")]
+                        tracing::info!("cleared a pending packet");
+                        None
+                    }
+                    Err(e) => {
+                        #[cfg(feature = "std")]
+                        tracing::warn!(error = ?e, "failed to clear a pending packet");
+                        Some((packet, e))
+                    }
+                }
             })
+            .buffer_unordered(max_concurrent_packets)
+            .filter_map(|failure| async move { failure })
+            .collect::<Vec<_>>()
             .await;
 
-        Ok(())
+        Ok(failures)
     }
 }
@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use futures_util::{stream, StreamExt};
+use ibc_relayer_components::chain::traits::queries::packet_commitments::CanQueryPacketCommitments;
+use ibc_relayer_components::chain::traits::queries::unreceived_packets::{
+    CanQuerySendPacketsFromSequences, CanQueryUnreceivedPacketSequences,
+};
+use ibc_relayer_components::chain::types::aliases::{ChannelId, PortId};
+use ibc_relayer_components::relay::traits::packet::HasRelayPacket;
+use ibc_relayer_components::relay::traits::packet_relayer::CanRelayTimeoutPacket;
+
+use crate::packet_clear::traits::packet_clear::TimeoutPacketClearer;
+use crate::std_prelude::*;
+
+pub struct TimeoutPacketClearRelayer;
+
+/// Flushes packets the destination will never receive because their timeout
+/// has elapsed. The discovery query is identical to
+/// [`super::receive_packets_clear::ReceivePacketClearRelayer`] — both start
+/// from the source's outstanding commitments the destination reports as
+/// unreceived — but each packet is handed to `relay_timeout_packet` instead
+/// of `relay_packet`, which is expected to no-op on a packet whose timeout
+/// has not actually elapsed yet.
+#[async_trait]
+impl<Relay> TimeoutPacketClearer<Relay> for TimeoutPacketClearRelayer
+where
+    Relay: HasRelayPacket + CanRelayTimeoutPacket,
+    Relay::DstChain: CanQueryUnreceivedPacketSequences<Relay::SrcChain>,
+    Relay::SrcChain: CanQueryPacketCommitments<Relay::DstChain>
+        + CanQuerySendPacketsFromSequences<Relay::DstChain>,
+{
+    async fn clear_timeout_packets(
+        relay: &Relay,
+        src_channel_id: &ChannelId<Relay::SrcChain, Relay::DstChain>,
+        src_port_id: &PortId<Relay::SrcChain, Relay::DstChain>,
+        dst_channel_id: &ChannelId<Relay::DstChain, Relay::SrcChain>,
+        dst_port_id: &PortId<Relay::DstChain, Relay::SrcChain>,
+    ) -> Result<(), Relay::Error> {
+        let dst_chain = relay.dst_chain();
+        let src_chain = relay.src_chain();
+
+        let (commitment_sequences, height) = src_chain
+            .query_packet_commitments(src_channel_id, src_port_id)
+            .await
+            .map_err(Relay::src_chain_error)?;
+
+        let unreceived_sequences = dst_chain
+            .query_unreceived_packet_sequences(dst_channel_id, dst_port_id, &commitment_sequences)
+            .await
+            .map_err(Relay::dst_chain_error)?;
+
+        let unreceived_packets = src_chain
+            .query_unreceived_packets(
+                src_channel_id,
+                src_port_id,
+                dst_channel_id,
+                dst_port_id,
+                &unreceived_sequences,
+                &height,
+            )
+            .await
+            .map_err(Relay::src_chain_error)?;
+
+        stream::iter(unreceived_packets)
+            .for_each_concurrent(None, |t| async move {
+                // Not every unreceived packet has necessarily timed out yet;
+                // `relay_timeout_packet` is expected to no-op on one that hasn't.
+                let _ = relay.relay_timeout_packet(&t).await;
+            })
+            .await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::core::traits::error::HasErrorType;
+use crate::std_prelude::*;
+
+/// The client/consensus-state types a chain needs upgraded counterparts of
+/// when its own software upgrade plan takes effect, plus the message type
+/// used to submit an upgrade to a chain that hosts a client tracking it.
+pub trait HasClientUpgradeTypes<Counterparty> {
+    type ClientState;
+    type ConsensusState;
+    type UpgradeClientMessage;
+}
+
+/// The upgraded client/consensus state `Chain` published at its upgrade
+/// height, together with the Merkle proofs (against `Chain`'s state root at
+/// that height) that they were indeed committed under the
+/// `upgrade/upgradedIBCState` paths.
+pub struct ClientUpgradePayload<Chain, Counterparty>
+where
+    Chain: HasClientUpgradeTypes<Counterparty>,
+{
+    pub upgraded_client_state: Chain::ClientState,
+    pub upgraded_consensus_state: Chain::ConsensusState,
+    pub proof_upgrade_client: Vec<u8>,
+    pub proof_upgrade_consensus_state: Vec<u8>,
+}
+
+#[async_trait]
+pub trait ClientUpgradePayloadBuilder<Chain, Counterparty>
+where
+    Chain: HasClientUpgradeTypes<Counterparty> + HasIbcChainTypes<Counterparty> + HasErrorType,
+    Counterparty: HasIbcChainTypes<Chain>,
+{
+    /// Queries `Chain`'s upgrade store for the upgraded client/consensus
+    /// state it published for `upgrade_height`, with their Merkle proofs.
+    async fn build_client_upgrade_payload(
+        &self,
+        upgrade_height: &Chain::Height,
+    ) -> Result<ClientUpgradePayload<Chain, Counterparty>, Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanBuildClientUpgradePayload<Counterparty>:
+    HasClientUpgradeTypes<Counterparty> + HasIbcChainTypes<Counterparty> + HasErrorType
+where
+    Counterparty: HasIbcChainTypes<Self>,
+{
+    async fn build_client_upgrade_payload(
+        &self,
+        upgrade_height: &Self::Height,
+    ) -> Result<ClientUpgradePayload<Self, Counterparty>, Self::Error>;
+}
+
+#[async_trait]
+pub trait ClientUpgradeMessageBuilder<Chain, Counterparty>
+where
+    Chain: HasIbcChainTypes<Counterparty> + HasErrorType,
+    Counterparty: HasClientUpgradeTypes<Chain>,
+{
+    /// Builds the message that submits `payload` to `Chain`, upgrading the
+    /// client identified by `client_id` to track `Counterparty` past its
+    /// upgrade height.
+    async fn build_client_upgrade_message(
+        &self,
+        client_id: &Chain::ClientId,
+        payload: ClientUpgradePayload<Counterparty, Chain>,
+    ) -> Result<Counterparty::UpgradeClientMessage, Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanBuildClientUpgradeMessage<Counterparty>: HasIbcChainTypes<Counterparty> + HasErrorType
+where
+    Counterparty: HasClientUpgradeTypes<Self>,
+{
+    async fn build_client_upgrade_message(
+        &self,
+        client_id: &Self::ClientId,
+        payload: ClientUpgradePayload<Counterparty, Self>,
+    ) -> Result<Counterparty::UpgradeClientMessage, Self::Error>;
+}
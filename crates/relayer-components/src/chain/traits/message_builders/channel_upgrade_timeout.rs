@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::message_sender::CanSendMessages;
+use crate::chain::traits::queries::status::CanQueryChainStatus;
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::core::traits::error::HasErrorType;
+use crate::std_prelude::*;
+
+/// Builds the message that cancels a stalled channel-upgrade handshake on `Chain`, once
+/// `Counterparty` has failed to flush its in-flight packets and move to `FLUSHCOMPLETE` before the
+/// upgrade's timeout passed. Deciding whether the timeout has actually elapsed -- comparing
+/// `Counterparty`'s latest queried height/time against the upgrade's stored timeout bound -- is
+/// left to the chain-specific implementation, since `Counterparty::Height` is opaque at this
+/// layer; the implementation is expected to query `Counterparty`'s current status and weigh it
+/// against the upgrade timeout before deciding to build the message.
+#[async_trait]
+pub trait ChannelUpgradeTimeoutMessageBuilder<Chain, Counterparty>
+where
+    Chain: HasIbcChainTypes<Counterparty> + CanSendMessages + HasErrorType,
+    Counterparty: HasIbcChainTypes<Chain> + CanQueryChainStatus<Chain>,
+{
+    /// Builds the timeout message(s) for `channel_id`/`port_id`, or returns `Ok(None)` if the
+    /// counterparty has not yet passed the upgrade's timeout.
+    async fn build_channel_upgrade_timeout_message(
+        chain: &Chain,
+        channel_id: &Chain::ChannelId,
+        port_id: &Chain::PortId,
+        counterparty: &Counterparty,
+    ) -> Result<Option<Vec<Chain::Message>>, Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanBuildChannelUpgradeTimeoutMessage<Counterparty>:
+    HasIbcChainTypes<Counterparty> + CanSendMessages + HasErrorType
+where
+    Counterparty: HasIbcChainTypes<Self> + CanQueryChainStatus<Self>,
+{
+    async fn build_channel_upgrade_timeout_message(
+        &self,
+        channel_id: &Self::ChannelId,
+        port_id: &Self::PortId,
+        counterparty: &Counterparty,
+    ) -> Result<Option<Vec<Self::Message>>, Self::Error>;
+}
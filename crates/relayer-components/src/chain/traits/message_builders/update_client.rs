@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::message_sender::CanSendMessages;
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::core::traits::error::HasErrorType;
+use crate::std_prelude::*;
+
+/// Builds the message(s) that bring the client `Chain` hosts for `Counterparty` up to
+/// `height`, i.e. the per-chain-type logic (fetching a header and its proofs from `Counterparty`
+/// and encoding them into `Chain`'s native message format) that a relay-level update-client
+/// strategy delegates to.
+#[async_trait]
+pub trait UpdateClientMessageBuilder<Chain, Counterparty>
+where
+    Chain: HasIbcChainTypes<Counterparty> + CanSendMessages + HasErrorType,
+    Counterparty: HasIbcChainTypes<Chain>,
+{
+    async fn build_update_client_message(
+        &self,
+        client_id: &Chain::ClientId,
+        height: &Counterparty::Height,
+    ) -> Result<Vec<Chain::Message>, Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanBuildUpdateClientMessage<Counterparty>:
+    HasIbcChainTypes<Counterparty> + CanSendMessages + HasErrorType
+where
+    Counterparty: HasIbcChainTypes<Self>,
+{
+    async fn build_update_client_message(
+        &self,
+        client_id: &Self::ClientId,
+        height: &Counterparty::Height,
+    ) -> Result<Vec<Self::Message>, Self::Error>;
+}
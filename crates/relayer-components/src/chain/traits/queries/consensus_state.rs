@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::client_upgrade::HasClientUpgradeTypes;
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::core::traits::error::HasErrorType;
+use crate::std_prelude::*;
+
+#[async_trait]
+pub trait ConsensusStateQuerier<Chain, Counterparty>
+where
+    Chain: HasIbcChainTypes<Counterparty> + HasErrorType,
+    Counterparty: HasIbcChainTypes<Chain> + HasClientUpgradeTypes<Chain>,
+{
+    /// Returns the consensus state `Chain`'s client tracking `Counterparty` has stored for
+    /// `height`, or `None` if that client has never been updated to (or past) `height`.
+    async fn query_consensus_state(
+        chain: &Chain,
+        client_id: &Chain::ClientId,
+        height: &Counterparty::Height,
+    ) -> Result<Option<Counterparty::ConsensusState>, Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanQueryConsensusState<Counterparty>: HasIbcChainTypes<Counterparty> + HasErrorType
+where
+    Counterparty: HasIbcChainTypes<Self> + HasClientUpgradeTypes<Self>,
+{
+    async fn query_consensus_state(
+        &self,
+        client_id: &Self::ClientId,
+        height: &Counterparty::Height,
+    ) -> Result<Option<Counterparty::ConsensusState>, Self::Error>;
+}
+
+/// Implements `ConsensusStateQuerier<Chain, Counterparty>` for a components marker type by
+/// delegating to `$source`, mirroring `derive_timeout_packet_relayer!`.
+#[macro_export]
+macro_rules! derive_consensus_state_querier {
+    ( $target:ident < $( $param:ident ),* $(,)? >, $source:ty $(,)? ) => {
+        #[async_trait::async_trait]
+        impl<$( $param, )* Chain, Counterparty> $crate::chain::traits::queries::consensus_state::ConsensusStateQuerier<Chain, Counterparty>
+            for $target<$( $param, )*>
+        where
+            Chain: $crate::chain::traits::types::ibc::HasIbcChainTypes<Counterparty> + $crate::core::traits::error::HasErrorType,
+            Counterparty: $crate::chain::traits::types::ibc::HasIbcChainTypes<Chain> + $crate::chain::traits::client_upgrade::HasClientUpgradeTypes<Chain>,
+            $source: $crate::chain::traits::queries::consensus_state::ConsensusStateQuerier<Chain, Counterparty>,
+        {
+            async fn query_consensus_state(
+                chain: &Chain,
+                client_id: &Chain::ClientId,
+                height: &Counterparty::Height,
+            ) -> Result<Option<Counterparty::ConsensusState>, Chain::Error> {
+                <$source as $crate::chain::traits::queries::consensus_state::ConsensusStateQuerier<Chain, Counterparty>>::query_consensus_state(chain, client_id, height).await
+            }
+        }
+    };
+}
@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::chain::traits::types::packet::HasIbcPacketTypes;
+use crate::core::traits::error::HasErrorType;
+use crate::std_prelude::*;
+
+#[async_trait]
+pub trait PacketCommitmentsQuerier<Chain, Counterparty>
+where
+    Chain: HasIbcChainTypes<Counterparty> + HasErrorType,
+    Counterparty: HasIbcChainTypes<Chain>,
+{
+    /// Queries the sequence numbers still committed on `Chain` for the given
+    /// channel, i.e. the packets sent but not yet acknowledged or timed out.
+    async fn query_packet_commitments(
+        &self,
+        channel_id: &Chain::ChannelId,
+        port_id: &Chain::PortId,
+    ) -> Result<(Vec<Chain::Sequence>, Chain::Height), Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanQueryPacketCommitments<Counterparty>:
+    HasIbcChainTypes<Counterparty> + HasErrorType
+where
+    Counterparty: HasIbcChainTypes<Self>,
+{
+    async fn query_packet_commitments(
+        &self,
+        channel_id: &Self::ChannelId,
+        port_id: &Self::PortId,
+    ) -> Result<(Vec<Self::Sequence>, Self::Height), Self::Error>;
+}
+
+#[async_trait]
+pub trait PacketAcknowledgementsQuerier<Chain, Counterparty>
+where
+    Chain: HasIbcChainTypes<Counterparty> + HasErrorType,
+    Counterparty: HasIbcChainTypes<Chain>,
+{
+    /// Queries the sequence numbers on `Chain` for which a `WriteAcknowledgement`
+    /// has been committed, restricted to the subset of `sequences` still of
+    /// interest (typically the commitments the counterparty reports as
+    /// outstanding).
+    async fn query_packet_acknowledgements(
+        &self,
+        channel_id: &Chain::ChannelId,
+        port_id: &Chain::PortId,
+        sequences: &[Chain::Sequence],
+    ) -> Result<(Vec<Chain::Sequence>, Chain::Height), Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanQueryPacketAcknowledgements<Counterparty>:
+    HasIbcChainTypes<Counterparty> + HasErrorType
+where
+    Counterparty: HasIbcChainTypes<Self>,
+{
+    async fn query_packet_acknowledgements(
+        &self,
+        channel_id: &Self::ChannelId,
+        port_id: &Self::PortId,
+        sequences: &[Self::Sequence],
+    ) -> Result<(Vec<Self::Sequence>, Self::Height), Self::Error>;
+}
+
+#[async_trait]
+pub trait WriteAckEventsQuerier<Chain, Counterparty>
+where
+    Chain: HasIbcChainTypes<Counterparty> + HasIbcPacketTypes<Counterparty> + HasErrorType,
+    Counterparty: HasIbcChainTypes<Chain>,
+{
+    /// Fetches the `WriteAcknowledgement` payloads `Chain` committed for the
+    /// given sequences, so they can be handed to the counterparty's
+    /// ack-packet relaying step without first having to observe the event.
+    async fn query_write_ack_events(
+        &self,
+        channel_id: &Chain::ChannelId,
+        port_id: &Chain::PortId,
+        sequences: &[Chain::Sequence],
+        height: &Chain::Height,
+    ) -> Result<Vec<Chain::OutgoingPacket>, Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanQueryWriteAckEvents<Counterparty>:
+    HasIbcChainTypes<Counterparty> + HasIbcPacketTypes<Counterparty> + HasErrorType
+where
+    Counterparty: HasIbcChainTypes<Self>,
+{
+    async fn query_write_ack_events(
+        &self,
+        channel_id: &Self::ChannelId,
+        port_id: &Self::PortId,
+        sequences: &[Self::Sequence],
+        height: &Self::Height,
+    ) -> Result<Vec<Self::OutgoingPacket>, Self::Error>;
+}
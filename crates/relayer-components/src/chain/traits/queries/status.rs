@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::core::traits::error::HasErrorType;
+use crate::std_prelude::*;
+
+#[async_trait]
+pub trait ChainStatusQuerier<Chain, Counterparty>
+where
+    Chain: HasIbcChainTypes<Counterparty> + HasErrorType,
+{
+    /// Returns `Chain`'s current height, i.e. the proof height an `UpdateClient` message must
+    /// bring a counterparty's client at least up to before later messages proven at that height
+    /// can be verified.
+    async fn query_chain_status(chain: &Chain) -> Result<Chain::Height, Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanQueryChainStatus<Counterparty>: HasIbcChainTypes<Counterparty> + HasErrorType {
+    async fn query_chain_status(&self) -> Result<Self::Height, Self::Error>;
+}
+
+/// Implements `ChainStatusQuerier<Chain, Counterparty>` for a components marker type by
+/// delegating to `$source`, mirroring `derive_timeout_packet_relayer!`.
+#[macro_export]
+macro_rules! derive_chain_status_querier {
+    ( $target:ident < $( $param:ident ),* $(,)? >, $source:ty $(,)? ) => {
+        #[async_trait::async_trait]
+        impl<$( $param, )* Chain, Counterparty> $crate::chain::traits::queries::status::ChainStatusQuerier<Chain, Counterparty>
+            for $target<$( $param, )*>
+        where
+            Chain: $crate::chain::traits::types::ibc::HasIbcChainTypes<Counterparty> + $crate::core::traits::error::HasErrorType,
+            $source: $crate::chain::traits::queries::status::ChainStatusQuerier<Chain, Counterparty>,
+        {
+            async fn query_chain_status(chain: &Chain) -> Result<Chain::Height, Chain::Error> {
+                <$source as $crate::chain::traits::queries::status::ChainStatusQuerier<Chain, Counterparty>>::query_chain_status(chain).await
+            }
+        }
+    };
+}
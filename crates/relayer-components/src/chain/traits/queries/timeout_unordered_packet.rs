@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::message_sender::CanSendMessages;
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::chain::traits::types::packet::HasIbcPacketTypes;
+use crate::core::traits::error::HasErrorType;
+use crate::std_prelude::*;
+
+#[async_trait]
+pub trait TimeoutUnorderedPacketMessageBuilder<Chain, Counterparty>
+where
+    Chain: HasIbcChainTypes<Counterparty> + CanSendMessages + HasErrorType,
+    Counterparty: HasIbcChainTypes<Chain> + HasIbcPacketTypes<Chain>,
+{
+    /// Builds the `MsgTimeout` (or, for a channel already closed on the
+    /// destination side, `MsgTimeoutOnClose`) proving that `Chain` never
+    /// received `packet`, which `Counterparty` sent, before its
+    /// `timeout_height`/`timeout_timestamp` elapsed. `TimeoutUnorderedPacketRelayer`
+    /// submits the message to the packet's source chain.
+    async fn build_timeout_unordered_packet_message(
+        &self,
+        packet: &Counterparty::OutgoingPacket,
+    ) -> Result<Chain::Message, Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanBuildTimeoutUnorderedPacketMessage<Counterparty>:
+    HasIbcChainTypes<Counterparty> + CanSendMessages + HasErrorType
+where
+    Counterparty: HasIbcChainTypes<Self> + HasIbcPacketTypes<Self>,
+{
+    async fn build_timeout_unordered_packet_message(
+        &self,
+        packet: &Counterparty::OutgoingPacket,
+    ) -> Result<Self::Message, Self::Error>;
+}
@@ -66,3 +66,35 @@ where
         height: &Self::Height,
     ) -> Result<Vec<Self::OutgoingPacket>, Self::Error>;
 }
+
+#[async_trait]
+pub trait UnreceivedAcksQuerier<Chain, Counterparty>
+where
+    Chain: HasIbcChainTypes<Counterparty> + HasErrorType,
+    Counterparty: HasIbcChainTypes<Chain>,
+{
+    /// Filters `sequences` (packet commitments outstanding on the counterparty)
+    /// down to those for which `Chain` has not yet observed an acknowledgement.
+    /// Pairs with [`CanQueryPacketAcknowledgements`](crate::chain::traits::queries::packet_commitments::CanQueryPacketAcknowledgements),
+    /// which reports the acks `Chain` already has -- together they let a caller compute the
+    /// pending-ack set the same way commitment querying does for unreceived packets.
+    async fn query_unreceived_ack_sequences(
+        &self,
+        channel_id: &Chain::ChannelId,
+        port_id: &Chain::PortId,
+        sequences: &[Chain::Sequence],
+    ) -> Result<(Vec<Chain::Sequence>, Chain::Height), Chain::Error>;
+}
+
+#[async_trait]
+pub trait CanQueryUnreceivedAcks<Counterparty>: HasIbcChainTypes<Counterparty> + HasErrorType
+where
+    Counterparty: HasIbcChainTypes<Self>,
+{
+    async fn query_unreceived_ack_sequences(
+        &self,
+        channel_id: &Self::ChannelId,
+        port_id: &Self::PortId,
+        sequences: &[Self::Sequence],
+    ) -> Result<(Vec<Self::Sequence>, Self::Height), Self::Error>;
+}
@@ -11,6 +11,7 @@ use crate::relay::impls::packet_relayers::general::lock::LockPacketRelayer;
 use crate::relay::impls::packet_relayers::general::log::LoggerRelayer;
 use crate::relay::impls::packet_relayers::receive::base_receive_packet::BaseReceivePacketRelayer;
 use crate::relay::impls::packet_relayers::receive::skip_received_packet::SkipReceivedPacketRelayer;
+use crate::relay::impls::packet_relayers::timeout::timeout_unordered::TimeoutUnorderedPacketRelayer;
 use crate::std_prelude::*;
 
 pub struct DefaultComponents<BaseComponents>(pub PhantomData<BaseComponents>);
@@ -34,6 +35,11 @@ crate::derive_receive_packet_relayer!(
     SkipReceivedPacketRelayer<BaseReceivePacketRelayer>,
 );
 
+crate::derive_timeout_packet_relayer!(
+    DefaultComponents<BaseComponents>,
+    TimeoutUnorderedPacketRelayer,
+);
+
 crate::derive_auto_relayer!(
     DefaultComponents<BaseComponents>,
     ConcurrentBidirectionalRelayer<ConcurrentEventSubscriptionRelayer>,
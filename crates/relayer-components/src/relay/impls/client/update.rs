@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::message_builders::update_client::CanBuildUpdateClientMessage;
+use crate::chain::traits::message_sender::CanSendMessages;
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::relay::traits::chains::HasRelayChains;
+use crate::relay::traits::client_update::UpdateClientMessageBuilder;
+use crate::relay::traits::target::ChainTarget;
+use crate::std_prelude::*;
+
+/// The base update-client message builder: always queries the per-chain-type
+/// `CanBuildUpdateClientMessage` impl for a fresh `UpdateClient` message, with no attempt to
+/// avoid a redundant one. Meant to sit at the bottom of a decorator stack such as
+/// `SkipUpdateClient<WaitUpdateClient<BuildUpdateClientMessages>>`.
+pub struct BuildUpdateClientMessages;
+
+#[async_trait]
+impl<Relay, Target, TargetChain, CounterpartyChain>
+    UpdateClientMessageBuilder<Relay, Target, TargetChain, CounterpartyChain>
+    for BuildUpdateClientMessages
+where
+    Relay: HasRelayChains,
+    Target: ChainTarget<Relay, TargetChain = TargetChain, CounterpartyChain = CounterpartyChain>,
+    TargetChain: HasIbcChainTypes<CounterpartyChain>
+        + CanSendMessages
+        + CanBuildUpdateClientMessage<CounterpartyChain>,
+    CounterpartyChain: HasIbcChainTypes<TargetChain>,
+{
+    async fn build_update_client_messages(
+        relay: &Relay,
+        height: &CounterpartyChain::Height,
+    ) -> Result<Vec<TargetChain::Message>, Relay::Error> {
+        let target_chain = Target::target_chain(relay);
+        let client_id = Target::target_client_id(relay);
+
+        target_chain
+            .build_update_client_message(client_id, height)
+            .await
+            .map_err(Target::target_chain_error)
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::client_upgrade::{
+    CanBuildClientUpgradeMessage, CanBuildClientUpgradePayload, HasClientUpgradeTypes,
+};
+use crate::chain::traits::message_sender::CanSendMessages;
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::relay::traits::chains::HasRelayChains;
+use crate::relay::traits::client_upgrade::ClientUpgradeRelayer;
+use crate::std_prelude::*;
+
+pub struct RelayClientUpgrade;
+
+/// Wires the `AnyUpgradeOptions`/`ClientState::upgrade` machinery into an
+/// operator-triggerable flow, mirroring how Hermes's `client-upgrade` command
+/// works: fetch the upgraded client/consensus state the source chain
+/// published for `upgrade_height` along with their Merkle proofs, then
+/// submit them to the destination chain so it can upgrade the client it
+/// holds for the source.
+///
+/// The local `ClientState::upgrade` sanity-check that Hermes runs before
+/// submitting is not repeated here: `Chain::ClientState` is an opaque
+/// associated type at this layer, so it can't be downcast to call a
+/// concrete `ClientState` method without coupling this chain-agnostic crate
+/// to a specific light-client implementation. `build_client_upgrade_message`
+/// is expected to perform that check itself before constructing the message.
+#[async_trait]
+impl<Relay> ClientUpgradeRelayer<Relay> for RelayClientUpgrade
+where
+    Relay: HasRelayChains,
+    Relay::SrcChain: HasIbcChainTypes<Relay::DstChain>
+        + HasClientUpgradeTypes<Relay::DstChain>
+        + CanBuildClientUpgradePayload<Relay::DstChain>,
+    Relay::DstChain: HasIbcChainTypes<Relay::SrcChain>
+        + CanSendMessages
+        + CanBuildClientUpgradeMessage<Relay::SrcChain>,
+{
+    async fn relay_client_upgrade(
+        relay: &Relay,
+        dst_client_id: &<Relay::DstChain as HasIbcChainTypes<Relay::SrcChain>>::ClientId,
+        upgrade_height: &<Relay::SrcChain as HasIbcChainTypes<Relay::DstChain>>::Height,
+    ) -> Result<(), Relay::Error> {
+        let src_chain = relay.src_chain();
+        let dst_chain = relay.dst_chain();
+
+        let payload = src_chain
+            .build_client_upgrade_payload(upgrade_height)
+            .await
+            .map_err(Relay::src_chain_error)?;
+
+        let upgrade_message = dst_chain
+            .build_client_upgrade_message(dst_client_id, payload)
+            .await
+            .map_err(Relay::dst_chain_error)?;
+
+        dst_chain
+            .send_messages(vec![upgrade_message])
+            .await
+            .map_err(Relay::dst_chain_error)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use core::marker::PhantomData;
+
+use async_trait::async_trait;
+
+use crate::chain::traits::client_upgrade::HasClientUpgradeTypes;
+use crate::chain::traits::message_sender::CanSendMessages;
+use crate::chain::traits::queries::consensus_state::CanQueryConsensusState;
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::relay::traits::chains::HasRelayChains;
+use crate::relay::traits::client_update::UpdateClientMessageBuilder;
+use crate::relay::traits::target::ChainTarget;
+use crate::std_prelude::*;
+
+/// Skips the inner builder entirely when `Target`'s client already has a consensus state at
+/// `height`, since in that case an `UpdateClient` message would only spend gas re-proving
+/// something the chain has already observed.
+pub struct SkipUpdateClient<InBuilder>(PhantomData<InBuilder>);
+
+#[async_trait]
+impl<Relay, Target, TargetChain, CounterpartyChain, InBuilder>
+    UpdateClientMessageBuilder<Relay, Target, TargetChain, CounterpartyChain>
+    for SkipUpdateClient<InBuilder>
+where
+    Relay: HasRelayChains,
+    Target: ChainTarget<Relay, TargetChain = TargetChain, CounterpartyChain = CounterpartyChain>,
+    TargetChain: HasIbcChainTypes<CounterpartyChain>
+        + CanSendMessages
+        + CanQueryConsensusState<CounterpartyChain>,
+    CounterpartyChain: HasIbcChainTypes<TargetChain> + HasClientUpgradeTypes<TargetChain>,
+    InBuilder: UpdateClientMessageBuilder<Relay, Target, TargetChain, CounterpartyChain>,
+{
+    async fn build_update_client_messages(
+        relay: &Relay,
+        height: &CounterpartyChain::Height,
+    ) -> Result<Vec<TargetChain::Message>, Relay::Error> {
+        let target_chain = Target::target_chain(relay);
+        let client_id = Target::target_client_id(relay);
+
+        let existing_consensus_state = target_chain
+            .query_consensus_state(client_id, height)
+            .await
+            .map_err(Target::target_chain_error)?;
+
+        if existing_consensus_state.is_some() {
+            return Ok(Vec::new());
+        }
+
+        InBuilder::build_update_client_messages(relay, height).await
+    }
+}
@@ -0,0 +1,35 @@
+use core::marker::PhantomData;
+
+use async_trait::async_trait;
+
+use crate::chain::traits::message_sender::CanSendMessages;
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::relay::traits::chains::HasRelayChains;
+use crate::relay::traits::client_update::UpdateClientMessageBuilder;
+use crate::relay::traits::target::ChainTarget;
+use crate::std_prelude::*;
+
+/// Forwards to `InBuilder` as-is. A placeholder for a future strategy that pauses until the
+/// counterparty height the client is being updated to has settled (e.g. past any soft-confirmation
+/// window) before a header for it is requested, rather than racing a header query against a
+/// height that might still reorg.
+pub struct WaitUpdateClient<InBuilder>(PhantomData<InBuilder>);
+
+#[async_trait]
+impl<Relay, Target, TargetChain, CounterpartyChain, InBuilder>
+    UpdateClientMessageBuilder<Relay, Target, TargetChain, CounterpartyChain>
+    for WaitUpdateClient<InBuilder>
+where
+    Relay: HasRelayChains,
+    Target: ChainTarget<Relay, TargetChain = TargetChain, CounterpartyChain = CounterpartyChain>,
+    TargetChain: HasIbcChainTypes<CounterpartyChain> + CanSendMessages,
+    CounterpartyChain: HasIbcChainTypes<TargetChain>,
+    InBuilder: UpdateClientMessageBuilder<Relay, Target, TargetChain, CounterpartyChain>,
+{
+    async fn build_update_client_messages(
+        relay: &Relay,
+        height: &CounterpartyChain::Height,
+    ) -> Result<Vec<TargetChain::Message>, Relay::Error> {
+        InBuilder::build_update_client_messages(relay, height).await
+    }
+}
@@ -0,0 +1 @@
+pub mod timeout_unordered;
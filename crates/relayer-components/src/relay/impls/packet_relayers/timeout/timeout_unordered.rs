@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::message_sender::CanSendMessages;
+use crate::chain::traits::queries::timeout_unordered_packet::CanBuildTimeoutUnorderedPacketMessage;
+use crate::relay::traits::chains::HasRelayChains;
+use crate::relay::traits::packet::HasRelayPacket;
+use crate::relay::traits::packet_relayers::timeout::TimeoutPacketRelayer;
+use crate::std_prelude::*;
+
+/// Relays the timeout of an ordinary (unordered-channel) packet: builds the `MsgTimeout` proving
+/// non-receipt on the destination chain, and submits it back to the source chain so the packet
+/// commitment held there is released. Left to the caller (the packet-clearing path, or the
+/// `send_packet`-event-triggered relayer) to only invoke once the packet's timeout height or
+/// timestamp has actually elapsed on the destination chain.
+pub struct TimeoutUnorderedPacketRelayer;
+
+#[async_trait]
+impl<Relay> TimeoutPacketRelayer<Relay> for TimeoutUnorderedPacketRelayer
+where
+    Relay: HasRelayPacket + HasRelayChains,
+    Relay::SrcChain: CanSendMessages,
+    Relay::DstChain: CanBuildTimeoutUnorderedPacketMessage<Relay::SrcChain>,
+{
+    async fn relay_timeout_packet(relay: &Relay, packet: &Relay::Packet) -> Result<(), Relay::Error> {
+        let dst_chain = relay.dst_chain();
+        let src_chain = relay.src_chain();
+
+        let timeout_message = dst_chain
+            .build_timeout_unordered_packet_message(packet)
+            .await
+            .map_err(Relay::dst_chain_error)?;
+
+        src_chain
+            .send_messages(vec![timeout_message])
+            .await
+            .map_err(Relay::src_chain_error)?;
+
+        Ok(())
+    }
+}
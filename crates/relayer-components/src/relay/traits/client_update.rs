@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::message_sender::CanSendMessages;
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::relay::traits::chains::HasRelayChains;
+use crate::relay::traits::target::ChainTarget;
+use crate::std_prelude::*;
+
+/// A pluggable strategy for producing the `UpdateClient` message(s), if any, a relay should
+/// prepend to an outgoing message batch so `Target`'s client is caught up to `height` on the
+/// counterparty before the batch's other messages -- proven at that height -- are submitted.
+#[async_trait]
+pub trait UpdateClientMessageBuilder<Relay, Target, TargetChain, CounterpartyChain>
+where
+    Relay: HasRelayChains,
+    Target: ChainTarget<Relay, TargetChain = TargetChain, CounterpartyChain = CounterpartyChain>,
+    TargetChain: HasIbcChainTypes<CounterpartyChain> + CanSendMessages,
+    CounterpartyChain: HasIbcChainTypes<TargetChain>,
+{
+    async fn build_update_client_messages(
+        relay: &Relay,
+        height: &CounterpartyChain::Height,
+    ) -> Result<Vec<TargetChain::Message>, Relay::Error>;
+}
+
+/// Implements `UpdateClientMessageBuilder<Relay, Target, TargetChain, CounterpartyChain>` for a
+/// components marker type by delegating to `$source`, mirroring `derive_timeout_packet_relayer!`.
+#[macro_export]
+macro_rules! derive_update_client_message_builder {
+    ( $target:ident < $( $param:ident ),* $(,)? >, $source:ty $(,)? ) => {
+        #[async_trait::async_trait]
+        impl<$( $param, )* Relay, Target, TargetChain, CounterpartyChain>
+            $crate::relay::traits::client_update::UpdateClientMessageBuilder<Relay, Target, TargetChain, CounterpartyChain>
+            for $target<$( $param, )*>
+        where
+            Relay: $crate::relay::traits::chains::HasRelayChains,
+            Target: $crate::relay::traits::target::ChainTarget<Relay, TargetChain = TargetChain, CounterpartyChain = CounterpartyChain>,
+            TargetChain: $crate::chain::traits::types::ibc::HasIbcChainTypes<CounterpartyChain> + $crate::chain::traits::message_sender::CanSendMessages,
+            CounterpartyChain: $crate::chain::traits::types::ibc::HasIbcChainTypes<TargetChain>,
+            $source: $crate::relay::traits::client_update::UpdateClientMessageBuilder<Relay, Target, TargetChain, CounterpartyChain>,
+        {
+            async fn build_update_client_messages(
+                relay: &Relay,
+                height: &CounterpartyChain::Height,
+            ) -> Result<Vec<TargetChain::Message>, Relay::Error> {
+                <$source as $crate::relay::traits::client_update::UpdateClientMessageBuilder<Relay, Target, TargetChain, CounterpartyChain>>::build_update_client_messages(relay, height).await
+            }
+        }
+    };
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::chain::traits::client_upgrade::HasClientUpgradeTypes;
+use crate::chain::traits::types::ibc::HasIbcChainTypes;
+use crate::relay::traits::chains::HasRelayChains;
+use crate::std_prelude::*;
+
+#[async_trait]
+pub trait ClientUpgradeRelayer<Relay>
+where
+    Relay: HasRelayChains,
+    Relay::SrcChain:
+        HasIbcChainTypes<Relay::DstChain> + HasClientUpgradeTypes<Relay::DstChain>,
+{
+    /// Upgrades `dst_client_id`, the client on the destination chain that
+    /// tracks the source chain, once the source chain's own upgrade has
+    /// taken effect at `upgrade_height`.
+    async fn relay_client_upgrade(
+        relay: &Relay,
+        dst_client_id: &<Relay::DstChain as HasIbcChainTypes<Relay::SrcChain>>::ClientId,
+        upgrade_height: &<Relay::SrcChain as HasIbcChainTypes<Relay::DstChain>>::Height,
+    ) -> Result<(), Relay::Error>;
+}
+
+#[async_trait]
+pub trait CanUpgradeClient: HasRelayChains
+where
+    Self::SrcChain: HasIbcChainTypes<Self::DstChain> + HasClientUpgradeTypes<Self::DstChain>,
+    Self::DstChain: HasIbcChainTypes<Self::SrcChain>,
+{
+    async fn upgrade_client(
+        &self,
+        dst_client_id: &<Self::DstChain as HasIbcChainTypes<Self::SrcChain>>::ClientId,
+        upgrade_height: &<Self::SrcChain as HasIbcChainTypes<Self::DstChain>>::Height,
+    ) -> Result<(), Self::Error>;
+}
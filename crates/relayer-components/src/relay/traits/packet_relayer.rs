@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+
+use crate::relay::traits::packet::HasRelayPacket;
+use crate::std_prelude::*;
+
+#[async_trait]
+pub trait CanRelayPacket: HasRelayPacket {
+    async fn relay_packet(&self, packet: &Self::Packet) -> Result<(), Self::Error>;
+}
+
+#[async_trait]
+pub trait CanRelayAckPacket: HasRelayPacket {
+    /// Relays the acknowledgement for a packet whose `WriteAcknowledgement`
+    /// has already been committed on the destination chain, without needing
+    /// to observe the event firsthand. Used by the packet-clearing path to
+    /// flush ack backlogs left behind by a relayer restart.
+    async fn relay_ack_packet(&self, packet: &Self::Packet) -> Result<(), Self::Error>;
+}
+
+#[async_trait]
+pub trait CanRelayTimeoutPacket: HasRelayPacket {
+    /// Relays a timeout for a packet the destination will never receive.
+    /// Expected to no-op if `packet`'s timeout height/timestamp has not
+    /// actually elapsed yet, since the packet-clearing path does not filter
+    /// for that itself.
+    async fn relay_timeout_packet(&self, packet: &Self::Packet) -> Result<(), Self::Error>;
+}
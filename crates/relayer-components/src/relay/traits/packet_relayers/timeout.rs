@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::relay::traits::packet::HasRelayPacket;
+use crate::std_prelude::*;
+
+/// A pluggable strategy for relaying the timeout of a single packet, composed into a chain's
+/// `Components` and dispatched to by the end-user-facing `CanRelayTimeoutPacket` impl.
+#[async_trait]
+pub trait TimeoutPacketRelayer<Relay>
+where
+    Relay: HasRelayPacket,
+{
+    async fn relay_timeout_packet(relay: &Relay, packet: &Relay::Packet) -> Result<(), Relay::Error>;
+}
+
+/// Implements `TimeoutPacketRelayer<Relay>` for a components marker type by delegating to
+/// `$source`, mirroring `derive_receive_packet_relayer!`.
+#[macro_export]
+macro_rules! derive_timeout_packet_relayer {
+    ( $target:ident < $( $param:ident ),* $(,)? >, $source:ty $(,)? ) => {
+        #[async_trait::async_trait]
+        impl<$( $param, )* Relay> $crate::relay::traits::packet_relayers::timeout::TimeoutPacketRelayer<Relay>
+            for $target<$( $param, )*>
+        where
+            Relay: $crate::relay::traits::packet::HasRelayPacket,
+            $source: $crate::relay::traits::packet_relayers::timeout::TimeoutPacketRelayer<Relay>,
+        {
+            async fn relay_timeout_packet(
+                relay: &Relay,
+                packet: &Relay::Packet,
+            ) -> Result<(), Relay::Error> {
+                <$source as $crate::relay::traits::packet_relayers::timeout::TimeoutPacketRelayer<Relay>>::relay_timeout_packet(relay, packet).await
+            }
+        }
+    };
+}
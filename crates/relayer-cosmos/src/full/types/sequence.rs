@@ -0,0 +1,121 @@
+//! Coordinates the account sequence number used to sign and broadcast batched Cosmos
+//! transactions, so that concurrent producers pushing onto a `CosmosBatchSender` don't race each
+//! other into an `account sequence mismatch` error: each batch is assigned a sequence number up
+//! front, and batches are only handed to the broadcaster in that order.
+
+use std::collections::BTreeMap;
+
+use tokio::sync::Mutex;
+
+use crate::base::error::Error;
+use crate::full::types::batch::CosmosBatchPayload;
+
+/// Per-signing-key account sequence bookkeeping. Chain I/O (querying the current sequence,
+/// broadcasting a transaction) is left to the caller; this type only owns the
+/// assign-then-release scheduling.
+pub struct AccountSequenceScheduler {
+    /// The sequence number that will be assigned to the next batch, once known. `None` until the
+    /// first `assign` call queries the chain for it.
+    next_sequence: Mutex<Option<u64>>,
+
+    /// The lowest assigned sequence number not yet broadcast. A batch assigned a later sequence
+    /// must wait here until every earlier one has gone out.
+    next_to_broadcast: Mutex<u64>,
+
+    /// Batches that were assigned a sequence number but are still waiting their turn to be
+    /// broadcast, keyed by that sequence number.
+    pending: Mutex<BTreeMap<u64, CosmosBatchPayload>>,
+}
+
+impl AccountSequenceScheduler {
+    pub fn new() -> Self {
+        Self {
+            next_sequence: Mutex::new(None),
+            next_to_broadcast: Mutex::new(0),
+            pending: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Reserves the next account sequence number, querying the chain for the current value via
+    /// `query_sequence` the first time this scheduler is used (or after a `reset`). Returns the
+    /// sequence number the caller must sign its transaction with.
+    pub async fn assign<F, Fut>(&self, query_sequence: F) -> Result<u64, Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<u64, Error>>,
+    {
+        let mut next_sequence = self.next_sequence.lock().await;
+
+        let sequence = match *next_sequence {
+            Some(sequence) => sequence,
+            None => {
+                let queried = query_sequence().await?;
+                *self.next_to_broadcast.lock().await = queried;
+                queried
+            }
+        };
+
+        *next_sequence = Some(sequence + 1);
+
+        Ok(sequence)
+    }
+
+    /// Called once `payload` has been assigned `sequence` by `assign`. Returns `payload` back to
+    /// the caller if it is already its turn to broadcast (every lower sequence has cleared);
+    /// otherwise buffers it and returns `None`.
+    pub async fn wait_for_turn(
+        &self,
+        sequence: u64,
+        payload: CosmosBatchPayload,
+    ) -> Option<CosmosBatchPayload> {
+        // The cursor lock is held while buffering, so a concurrent `advance` either sees this
+        // batch in `pending` or has already moved the cursor before it is compared.
+        let next_to_broadcast = self.next_to_broadcast.lock().await;
+
+        if sequence == *next_to_broadcast {
+            Some(payload)
+        } else {
+            self.pending.lock().await.insert(sequence, payload);
+            None
+        }
+    }
+
+    /// Called once the transaction signed with `sequence` has been broadcast (successfully, or
+    /// having failed for a reason unrelated to its sequence number). Advances the broadcast
+    /// cursor past `sequence` and returns any now-ready buffered batches, in the order they
+    /// should be broadcast next.
+    pub async fn advance(&self, sequence: u64) -> Vec<(u64, CosmosBatchPayload)> {
+        let mut next_to_broadcast = self.next_to_broadcast.lock().await;
+        *next_to_broadcast = sequence + 1;
+
+        let mut pending = self.pending.lock().await;
+        let mut ready = Vec::new();
+
+        while let Some(payload) = pending.remove(&next_to_broadcast) {
+            ready.push((*next_to_broadcast, payload));
+            *next_to_broadcast += 1;
+        }
+
+        ready
+    }
+
+    /// Called on an `account sequence mismatch` broadcast error: forgets the locally tracked
+    /// sequence number so the next `assign` call re-queries the chain for the authoritative
+    /// value, and drains every buffered batch (their assigned sequence numbers are now stale) so
+    /// the caller can requeue them for resubmission.
+    pub async fn reset(&self) -> Vec<CosmosBatchPayload> {
+        *self.next_sequence.lock().await = None;
+        self.pending
+            .lock()
+            .await
+            .split_off(&0)
+            .into_values()
+            .collect()
+    }
+}
+
+impl Default for AccountSequenceScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,11 +1,23 @@
+use std::collections::BTreeMap;
+
 use crate::relayer_mock::base::types::height::Height;
 use crate::relayer_mock::base::types::state::State;
 
+/// Identifies a packet in the mock chain's send/receive/ack bookkeeping by the channel it
+/// travelled on and its sequence number.
+pub type PacketKey = (String, String, u64);
+
 #[derive(Clone, Debug)]
 pub struct MockChainStatus {
     pub height: Height,
     pub timestamp: Height,
     pub state: State,
+    /// Commitments for packets this chain has sent, keyed by `(port_id, channel_id, sequence)`.
+    pub sent_packets: BTreeMap<PacketKey, Vec<u8>>,
+    /// Receipts for packets this chain has received, keyed the same way.
+    pub received_packets: BTreeMap<PacketKey, Vec<u8>>,
+    /// Acknowledgement bytes this chain has written for packets it received.
+    pub acknowledgements: BTreeMap<PacketKey, Vec<u8>>,
 }
 
 impl MockChainStatus {
@@ -14,12 +26,52 @@ impl MockChainStatus {
             height,
             timestamp,
             state,
+            sent_packets: BTreeMap::new(),
+            received_packets: BTreeMap::new(),
+            acknowledgements: BTreeMap::new(),
         }
     }
+
+    /// Records a trivially-valid mock commitment for the packet this chain just sent.
+    pub fn write_send_packet_commitment(&mut self, key: PacketKey, commitment: Vec<u8>) {
+        self.sent_packets.insert(key, commitment);
+    }
+
+    /// Records that this chain has received the packet identified by `key`.
+    pub fn write_receive_packet_receipt(&mut self, key: PacketKey) {
+        self.received_packets.insert(key, vec![1]);
+    }
+
+    /// Records the acknowledgement bytes this chain wrote for the packet identified by `key`.
+    pub fn write_acknowledgement(&mut self, key: PacketKey, acknowledgement: Vec<u8>) {
+        self.acknowledgements.insert(key, acknowledgement);
+    }
+
+    /// A trivially-valid mock "proof" that a commitment for `key` exists on this chain.
+    pub fn query_packet_commitment_proof(&self, key: &PacketKey) -> Option<Vec<u8>> {
+        self.sent_packets.get(key).cloned()
+    }
+
+    /// A trivially-valid mock "proof" that the packet identified by `key` has been received.
+    pub fn query_packet_receipt_proof(&self, key: &PacketKey) -> Option<Vec<u8>> {
+        self.received_packets.get(key).cloned()
+    }
+
+    /// A trivially-valid mock "proof" of the acknowledgement written for `key`.
+    pub fn query_packet_acknowledgement_proof(&self, key: &PacketKey) -> Option<Vec<u8>> {
+        self.acknowledgements.get(key).cloned()
+    }
+
+    /// Whether this chain's current height/timestamp has passed `timeout_height`/
+    /// `timeout_timestamp`, letting the timeout-on-close edge case be simulated: a timeout can be
+    /// relayed once this returns `true` for a packet that has no entry in `received_packets`.
+    pub fn has_timed_out(&self, timeout_height: &Height, timeout_timestamp: &Height) -> bool {
+        self.height >= *timeout_height || self.timestamp >= *timeout_timestamp
+    }
 }
 
 impl From<(Height, State)> for MockChainStatus {
     fn from(s: (Height, State)) -> Self {
-        MockChainStatus { height: s.0.clone(), timestamp: s.0, state: s.1 }
+        MockChainStatus::new(s.0.clone(), s.0, s.1)
     }
 }
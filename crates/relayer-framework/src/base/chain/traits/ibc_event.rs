@@ -0,0 +1,21 @@
+use crate::base::chain::traits::types::{HasEventType, HasIbcPacketTypes};
+use crate::base::core::traits::sync::Async;
+
+/// Lets a chain's raw `Event` type be decoded into the specific IBC packet events a relayer
+/// needs to react to, without the relayer having to know how each chain backend encodes them.
+pub trait HasIbcEvents<Counterparty>: HasEventType + HasIbcPacketTypes<Counterparty>
+where
+    Counterparty: HasIbcPacketTypes<Self>,
+{
+    type WriteAcknowledgementEvent: Async;
+
+    /// Extracts the `WriteAcknowledgement` payload from `event`, if it is one.
+    fn try_extract_write_acknowledgement_event(
+        event: Self::Event,
+    ) -> Option<Self::WriteAcknowledgementEvent>;
+
+    /// Extracts the packet a `SendPacket` event committed, if `event` is one. The packet is
+    /// returned fully decoded (port/channel/sequence/data/timeout) rather than as a raw event
+    /// payload, since that's all an event-driven relay worker needs to act on it.
+    fn try_extract_send_packet_event(event: Self::Event) -> Option<Self::OutgoingPacket>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::base::chain::traits::types::HasIbcPacketTypes;
+use crate::std_prelude::*;
+
+#[async_trait]
+pub trait CanBuildReceivePacketMessage<Counterparty>: HasIbcPacketTypes<Counterparty>
+where
+    Counterparty: HasIbcPacketTypes<
+        Self,
+        IncomingPacket = Self::OutgoingPacket,
+        OutgoingPacket = Self::IncomingPacket,
+    >,
+{
+    /// Builds the `MsgRecvPacket` proving `packet`'s commitment on `Counterparty` at `height`,
+    /// ready for submission to `Self`.
+    async fn build_receive_packet_message(
+        &self,
+        height: &Counterparty::Height,
+        packet: &Self::IncomingPacket,
+    ) -> Result<Self::Message, Self::Error>;
+}
@@ -0,0 +1,63 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use async_trait::async_trait;
+
+use crate::base::chain::traits::types::HasIbcPacketTypes;
+use crate::std_prelude::*;
+
+/// Which variant of timeout message a packet timeout resolves to, mirroring the three ways ibc-go
+/// lets a packet be timed out. Exposed alongside the built message so callers can log/branch on
+/// it without having to re-derive it from the packet and channel state.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PacketMsgType {
+    /// The packet travelled on an unordered channel: timeout is proven by the absence of a
+    /// receipt for its sequence on the destination chain.
+    TimeoutUnordered,
+    /// The packet travelled on an ordered channel: timeout is proven by the destination's
+    /// `next_sequence_recv` having advanced past the packet's sequence without ever receiving it.
+    TimeoutOrdered,
+    /// The destination channel has already been closed, so the packet times out on that basis
+    /// regardless of height/timestamp expiry.
+    TimeoutOnClose,
+}
+
+impl Display for PacketMsgType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            PacketMsgType::TimeoutUnordered => write!(f, "timeout unordered"),
+            PacketMsgType::TimeoutOrdered => write!(f, "timeout ordered"),
+            PacketMsgType::TimeoutOnClose => write!(f, "timeout on close"),
+        }
+    }
+}
+
+#[async_trait]
+pub trait CanBuildPacketTimeoutMessage<Counterparty>: HasIbcPacketTypes<Counterparty>
+where
+    Counterparty: HasIbcPacketTypes<
+        Self,
+        IncomingPacket = Self::OutgoingPacket,
+        OutgoingPacket = Self::IncomingPacket,
+    >,
+    Counterparty::Height: PartialOrd,
+    Counterparty::Timestamp: PartialOrd,
+{
+    /// Builds the message that times `packet` out on `Self`, given `Counterparty`'s current
+    /// consensus height/time, or returns `None` if the packet has not yet expired.
+    ///
+    /// `dst_channel_closed` takes priority over the height/timestamp check: once the destination
+    /// channel has closed, the packet can be timed out immediately (`PacketMsgType::TimeoutOnClose`)
+    /// whether or not its own timeout has elapsed yet. Otherwise, `ordered` selects between the two
+    /// ordinary timeout proofs -- an ordered channel's timeout must carry `next_sequence_recv` so
+    /// the receiving chain can verify the sequence was skipped, while an unordered channel's proves
+    /// the absence of a receipt instead.
+    async fn build_packet_timeout_message(
+        &self,
+        packet: &Self::OutgoingPacket,
+        counterparty_height: &Counterparty::Height,
+        counterparty_timestamp: &Counterparty::Timestamp,
+        ordered: bool,
+        dst_channel_closed: bool,
+        next_sequence_recv: Counterparty::Sequence,
+    ) -> Result<Option<(Self::Message, PacketMsgType)>, Self::Error>;
+}
@@ -155,4 +155,8 @@ where
     ) -> Option<Self::WriteAcknowledgementEvent> {
         Chain::try_extract_write_acknowledgement_event(event)
     }
+
+    fn try_extract_send_packet_event(event: Self::Event) -> Option<Self::OutgoingPacket> {
+        Chain::try_extract_send_packet_event(event)
+    }
 }
@@ -0,0 +1,148 @@
+use core::time::Duration;
+
+use crate::base::chain::traits::ibc_event::HasIbcEvents;
+use crate::base::chain::traits::message::ack_packet::CanBuildAckPacketMessage;
+use crate::base::chain::traits::message::receive_packet::CanBuildReceivePacketMessage;
+use crate::base::chain::traits::message_sender::CanSendMessages;
+use crate::base::chain::traits::queries::status::CanQueryChainHeight;
+use crate::base::chain::traits::types::HasEventType;
+use crate::base::one_for_all::traits::relay::OfaBaseRelay;
+use crate::base::one_for_all::traits::runtime::OfaRuntime;
+use crate::std_prelude::*;
+
+/// How long the relay task pauses after a packet it couldn't yet prove (typically because the
+/// destination's client hasn't caught up to the height the event was observed at). The packet
+/// itself is not retried by this worker.
+const PROOF_LAG_BACKOFF: Duration = Duration::from_millis(500);
+
+/// The work items an event-ingest task hands to the relay task: either a freshly sent packet
+/// that needs a `MsgRecvPacket` on the destination, or an ack the source chain already wrote
+/// that needs a `MsgAcknowledgement` carried back to the destination (the packet's original
+/// sender).
+enum RelayTask<Relay>
+where
+    Relay: OfaBaseRelay,
+    Relay::SrcChain: HasIbcEvents<Relay::DstChain>,
+{
+    Recv(<Relay::SrcChain as HasIbcEvents<Relay::DstChain>>::OutgoingPacket),
+    Ack(
+        <Relay::SrcChain as HasIbcEvents<Relay::DstChain>>::IncomingPacket,
+        <Relay::SrcChain as HasIbcEvents<Relay::DstChain>>::WriteAcknowledgementEvent,
+    ),
+}
+
+/// Subscribes to a source chain's IBC events and automatically relays the packets they describe
+/// to the destination chain, replacing naive polling with a reactive strategy: a `SendPacket`
+/// becomes a `MsgRecvPacket` on the destination. `WriteAcknowledgement` events are recognised
+/// but not relayed: the ingest task never produces a `RelayTask::Ack`, so the relay task's
+/// `MsgAcknowledgement` path is only reached by a task queued some other way.
+///
+/// Built on the `OfaRuntime` abstraction: an event-ingest task (`spawn`ed) decodes raw events
+/// into `RelayTask`s and forwards them over an internal `new_channel` queue to the relay task,
+/// which drains it with `receive` and uses `sleep` to back off when a packet isn't provable yet.
+pub struct EventRelayer<Relay>
+where
+    Relay: OfaBaseRelay,
+{
+    relay: Relay,
+}
+
+impl<Relay> EventRelayer<Relay>
+where
+    Relay: OfaBaseRelay,
+    Relay::SrcChain: HasIbcEvents<Relay::DstChain>
+        + CanBuildAckPacketMessage<Relay::DstChain>
+        + CanQueryChainHeight,
+    Relay::DstChain: CanBuildReceivePacketMessage<Relay::SrcChain> + CanSendMessages,
+{
+    pub fn new(relay: Relay) -> Self {
+        Self { relay }
+    }
+
+    /// Spawns the event-ingest task and runs the relay loop, forwarding every `SendPacket` the
+    /// source chain emits to the destination until `events` closes.
+    pub async fn run(
+        &self,
+        events: <Relay::Runtime as OfaRuntime>::Receiver<<Relay::SrcChain as HasEventType>::Event>,
+    ) -> Result<(), Relay::Error> {
+        let runtime = self.relay.runtime();
+        let (task_sender, task_receiver) = Relay::Runtime::new_channel();
+
+        runtime.spawn(Self::ingest_events(events, task_sender));
+
+        loop {
+            let task = match Relay::Runtime::receive(task_receiver).await {
+                Ok(task) => task,
+                // The ingest task exited, meaning the event subscription closed; nothing left
+                // to relay.
+                Err(_) => return Ok(()),
+            };
+
+            self.relay_task(task).await?;
+        }
+    }
+
+    async fn ingest_events(
+        events: <Relay::Runtime as OfaRuntime>::Receiver<<Relay::SrcChain as HasEventType>::Event>,
+        task_sender: <Relay::Runtime as OfaRuntime>::Sender<RelayTask<Relay>>,
+    ) {
+        while let Ok(event) = Relay::Runtime::receive(events).await {
+            if let Some(packet) = Relay::SrcChain::try_extract_send_packet_event(event.clone()) {
+                let _ = Relay::Runtime::send(task_sender.clone(), RelayTask::Recv(packet));
+            } else if let Some(_ack_event) =
+                Relay::SrcChain::try_extract_write_acknowledgement_event(event)
+            {
+                // The acked packet is not decoded out of the `WriteAcknowledgement` payload
+                // here, so no `RelayTask::Ack` is queued and the event is dropped.
+            }
+        }
+    }
+
+    async fn relay_task(&self, task: RelayTask<Relay>) -> Result<(), Relay::Error> {
+        let src_chain = self.relay.src_chain();
+        let dst_chain = self.relay.dst_chain();
+
+        match task {
+            RelayTask::Recv(packet) => {
+                let height = src_chain
+                    .query_chain_height()
+                    .await
+                    .map_err(Relay::src_chain_error)?;
+
+                match dst_chain
+                    .build_receive_packet_message(&height, &packet)
+                    .await
+                {
+                    Ok(message) => dst_chain
+                        .send_messages(vec![message])
+                        .await
+                        .map(|_| ())
+                        .map_err(Relay::dst_chain_error),
+                    Err(_) => {
+                        // The destination's client is likely behind `height`; back off. The
+                        // packet is dropped here and is not requeued.
+                        self.relay.runtime().sleep(PROOF_LAG_BACKOFF).await;
+                        Ok(())
+                    }
+                }
+            }
+            RelayTask::Ack(packet, ack) => {
+                let height = src_chain
+                    .query_chain_height()
+                    .await
+                    .map_err(Relay::src_chain_error)?;
+
+                let message = src_chain
+                    .build_ack_packet_message(&height, &packet, &ack)
+                    .await
+                    .map_err(Relay::src_chain_error)?;
+
+                dst_chain
+                    .send_messages(vec![message])
+                    .await
+                    .map(|_| ())
+                    .map_err(Relay::dst_chain_error)
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use core::future::Future;
+use core::time::Duration;
+
+use rand::Rng;
+
+use crate::full::one_for_all::traits::relay::OfaFullRelay;
+use crate::std_prelude::*;
+
+/// Exponential-backoff-with-jitter policy driving [`retry_with_backoff`].
+///
+/// On each retryable failure, the delay before the next attempt is
+/// `min(max_delay, initial_delay * multiplier^attempt)`, perturbed by a uniformly random factor
+/// in `[1 - jitter_fraction, 1 + jitter_fraction]`. The jitter keeps many packets that fail at
+/// once against the same congested chain from all retrying in lockstep.
+#[derive(Copy, Clone, Debug)]
+pub struct RetryConfig {
+    pub max_retries: u32,
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+    pub jitter_fraction: f64,
+}
+
+impl RetryConfig {
+    fn delay_for(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_delay
+            .mul_f64(self.multiplier.powi(attempt as i32))
+            .min(self.max_delay);
+
+        let jitter = rand::thread_rng()
+            .gen_range((1.0 - self.jitter_fraction)..=(1.0 + self.jitter_fraction));
+
+        backoff.mul_f64(jitter.max(0.0))
+    }
+}
+
+/// Drives `op` to completion, retrying on any error for which `Relay::is_retryable_error` returns
+/// `true` according to `config`'s exponential-backoff-with-jitter policy.
+///
+/// Returns immediately on a non-retryable error. Once `config.max_retries` retries have all
+/// failed, returns `Relay::max_retry_exceeded_error` wrapping the last error instead of retrying
+/// further. `sleep` is injected rather than read off `Relay` so this driver doesn't need to commit
+/// to how a particular relay wrapper exposes its runtime.
+pub async fn retry_with_backoff<Relay, Op, Fut, T, Sleep, SleepFut>(
+    config: &RetryConfig,
+    sleep: Sleep,
+    mut op: Op,
+) -> Result<T, Relay::Error>
+where
+    Relay: OfaFullRelay,
+    Op: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Relay::Error>>,
+    Sleep: Fn(Duration) -> SleepFut,
+    SleepFut: Future<Output = ()>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                if !Relay::is_retryable_error(&e) {
+                    return Err(e);
+                }
+
+                if attempt >= config.max_retries {
+                    return Err(Relay::max_retry_exceeded_error(e));
+                }
+
+                sleep(config.delay_for(attempt)).await;
+                attempt += 1;
+            }
+        }
+    }
+}
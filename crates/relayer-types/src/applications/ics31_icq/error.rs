@@ -0,0 +1,24 @@
+use crate::prelude::*;
+
+use thiserror::Error;
+
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::error::ValidationError;
+
+#[derive(Clone, Debug, Error)]
+pub enum QueryPacketError {
+    #[error("missing event attribute: {event}")]
+    EventAttributeNotFound { event: String },
+
+    #[error("invalid connection identifier")]
+    Ics24Error(ValidationError),
+
+    #[error("invalid height")]
+    InvalidHeight(Ics02Error),
+
+    #[error("interchain query height must be non-zero")]
+    ZeroHeight,
+
+    #[error("interchain query proof verification failed")]
+    ProofVerificationFailed,
+}
@@ -1,4 +1,8 @@
 use crate::prelude::*;
+use crate::core::ics02_client::height::Height;
+use crate::core::ics23_commitment::commitment::{CommitmentProofBytes, CommitmentRoot};
+use crate::core::ics23_commitment::merkle::{MerklePath, MerkleProof};
+use crate::core::ics23_commitment::specs::ProofSpecs;
 use crate::core::ics24_host::identifier::{ChainId, ConnectionId};
 
 use super::error::QueryPacketError;
@@ -89,4 +93,92 @@ impl TryFrom<Vec<Tag>> for CrossChainQueryPacket {
             }
         )
     }
+}
+
+/// The answer a host chain returns for a previously-submitted `CrossChainQueryPacket`. `query_type`
+/// and `request` are carried over from that packet so the proof can be checked without needing the
+/// original packet on hand; `result` is the queried value (empty if it does not exist), proven at
+/// `height` by `proof`.
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq)]
+pub struct CrossChainQueryResult {
+    pub query_id: String,
+    pub query_type: String,
+    pub request: String,
+    pub result: Vec<u8>,
+    pub height: Height,
+    pub proof: CommitmentProofBytes,
+}
+
+impl CrossChainQueryResult {
+    /// Verifies that `result` is committed (or, if empty, proven absent) at the store path named
+    /// by `query_type`, under key `request`, against `consensus_state_root`.
+    pub fn verify(&self, consensus_state_root: &CommitmentRoot) -> Result<(), QueryPacketError> {
+        if self.height.is_zero() {
+            return Err(QueryPacketError::ZeroHeight);
+        }
+
+        let merkle_proof = MerkleProof::try_from(self.proof.clone())
+            .map_err(|_| QueryPacketError::ProofVerificationFailed)?;
+        let keys = MerklePath {
+            key_path: vec![self.query_type.clone(), self.request.clone()],
+        };
+
+        if self.result.is_empty() {
+            merkle_proof
+                .verify_non_membership(&ProofSpecs::cosmos(), consensus_state_root.clone(), keys)
+                .map_err(|_| QueryPacketError::ProofVerificationFailed)
+        } else {
+            merkle_proof
+                .verify_membership(
+                    &ProofSpecs::cosmos(),
+                    consensus_state_root.clone(),
+                    keys,
+                    self.result.clone(),
+                    0,
+                )
+                .map_err(|_| QueryPacketError::ProofVerificationFailed)
+        }
+    }
+}
+
+impl From<CrossChainQueryResult> for AbciEvent {
+    fn from(result: CrossChainQueryResult) -> Self {
+        let attributes: Vec<Tag> = vec![
+            new_tag("query_id", result.query_id.as_str()),
+            new_tag("type", result.query_type.as_str()),
+            new_tag("request", result.request.as_str()),
+            new_tag("result", &String::from_utf8_lossy(&result.result)),
+            new_tag("height", &result.height.to_string()),
+            new_tag("proof", &String::from_utf8_lossy(result.proof.as_bytes())),
+        ];
+
+        AbciEvent {
+            type_str: String::from("message"),
+            attributes,
+        }
+    }
+}
+
+impl TryFrom<Vec<Tag>> for CrossChainQueryResult {
+    type Error = QueryPacketError;
+
+    fn try_from(entries: Vec<Tag>) -> Result<Self, Self::Error> {
+        let query_id = find_value("query_id", &entries)?;
+        let query_type = find_value("type", &entries)?;
+        let request = find_value("request", &entries)?;
+        let result = find_value("result", &entries)?.into_bytes();
+        let height_str = find_value("height", &entries)?;
+        let proof = find_value("proof", &entries)?.into_bytes();
+
+        let height = Height::from_str(&height_str).map_err(QueryPacketError::InvalidHeight)?;
+
+        Ok(Self {
+            query_id,
+            query_type,
+            request,
+            result,
+            height,
+            proof: proof.into(),
+        })
+    }
 }
\ No newline at end of file
@@ -0,0 +1,200 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use crate::core::ics04_channel::version::Version;
+use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+
+/// Whether a channel delivers packets in the order they were sent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Ordering {
+    #[default]
+    None = 0,
+    Unordered = 1,
+    Ordered = 2,
+}
+
+impl Ordering {
+    pub fn as_string(&self) -> &'static str {
+        match self {
+            Self::None => "ORDER_NONE_UNSPECIFIED",
+            Self::Unordered => "ORDER_UNORDERED",
+            Self::Ordered => "ORDER_ORDERED",
+        }
+    }
+
+    pub fn from_i32(nr: i32) -> Self {
+        match nr {
+            1 => Self::Unordered,
+            2 => Self::Ordered,
+            _ => Self::None,
+        }
+    }
+}
+
+impl Display for Ordering {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+/// The lifecycle of a channel end, including the states a channel passes through while an
+/// upgrade is in flight.
+///
+/// Variants are ordered (`Uninitialized < Init < TryOpen < Open < Flushing < FlushComplete <
+/// Closed`) so callers can ask whether a channel has progressed past a given point in either
+/// the open or the upgrade handshake without matching on every combination by hand.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum State {
+    #[default]
+    Uninitialized = 0,
+    Init = 1,
+    TryOpen = 2,
+    Open = 3,
+    /// Entered once an upgrade has been proposed (INIT) or accepted (TRY) by this end, while
+    /// this side still has packets in flight that must drain before the upgrade can proceed.
+    Flushing = 4,
+    /// Entered once this side has no more in-flight packets for the channel being upgraded;
+    /// once both ends reach `FlushComplete` the channel reopens with the new fields.
+    FlushComplete = 5,
+    Closed = 6,
+}
+
+impl State {
+    pub fn as_string(&self) -> &'static str {
+        match self {
+            Self::Uninitialized => "STATE_UNINITIALIZED_UNSPECIFIED",
+            Self::Init => "STATE_INIT",
+            Self::TryOpen => "STATE_TRYOPEN",
+            Self::Open => "STATE_OPEN",
+            Self::Flushing => "STATE_FLUSHING",
+            Self::FlushComplete => "STATE_FLUSHCOMPLETE",
+            Self::Closed => "STATE_CLOSED",
+        }
+    }
+
+    pub fn from_i32(nr: i32) -> Self {
+        match nr {
+            1 => Self::Init,
+            2 => Self::TryOpen,
+            3 => Self::Open,
+            4 => Self::Flushing,
+            5 => Self::FlushComplete,
+            6 => Self::Closed,
+            _ => Self::Uninitialized,
+        }
+    }
+
+    /// Whether a channel end in this state still has an upgrade in flight.
+    pub fn is_flushing(&self) -> bool {
+        matches!(self, Self::Flushing | Self::FlushComplete)
+    }
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.as_string())
+    }
+}
+
+/// The counterparty port/channel a channel end is connected to. `channel_id` is `None` until
+/// the counterparty has picked an identifier for its own end (i.e. before TRY has run there).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Counterparty {
+    pub port_id: PortId,
+    pub channel_id: Option<ChannelId>,
+}
+
+impl Counterparty {
+    pub fn new(port_id: PortId, channel_id: Option<ChannelId>) -> Self {
+        Self {
+            port_id,
+            channel_id,
+        }
+    }
+
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> Option<&ChannelId> {
+        self.channel_id.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChannelEnd {
+    pub state: State,
+    pub ordering: Ordering,
+    pub remote: Counterparty,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+    /// Incremented every time this end proposes a new upgrade via INIT; used by the
+    /// counterparty to tell a stale upgrade attempt apart from the current one when verifying
+    /// proofs and `ErrorReceipt`s.
+    pub upgrade_sequence: u64,
+}
+
+impl ChannelEnd {
+    pub fn new(
+        state: State,
+        ordering: Ordering,
+        remote: Counterparty,
+        connection_hops: Vec<ConnectionId>,
+        version: Version,
+        upgrade_sequence: u64,
+    ) -> Self {
+        Self {
+            state,
+            ordering,
+            remote,
+            connection_hops,
+            version,
+            upgrade_sequence,
+        }
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    pub fn state_matches(&self, state: &State) -> bool {
+        &self.state == state
+    }
+
+    pub fn ordering(&self) -> &Ordering {
+        &self.ordering
+    }
+
+    pub fn counterparty(&self) -> &Counterparty {
+        &self.remote
+    }
+
+    pub fn connection_hops(&self) -> &Vec<ConnectionId> {
+        &self.connection_hops
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    pub fn upgrade_sequence(&self) -> u64 {
+        self.upgrade_sequence
+    }
+}
+
+/// A [`ChannelEnd`] together with the port/channel identifiers it is stored under.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifiedChannelEnd {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub channel_end: ChannelEnd,
+}
+
+impl IdentifiedChannelEnd {
+    pub fn new(port_id: PortId, channel_id: ChannelId, channel_end: ChannelEnd) -> Self {
+        Self {
+            port_id,
+            channel_id,
+            channel_end,
+        }
+    }
+}
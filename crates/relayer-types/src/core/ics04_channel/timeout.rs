@@ -187,6 +187,96 @@ impl<'de> Deserialize<'de> for TimeoutHeight {
     }
 }
 
+/// Indicates a timestamp on the destination chain after which the packet will no longer be
+/// processed, and will instead count as having timed-out. Symmetric to `TimeoutHeight`: a packet's
+/// timeout timestamp is encoded on the wire as nanoseconds since the Unix epoch, where `0` means
+/// "no timeout" rather than the Unix epoch itself, so this must be parsed specially just like
+/// `TimeoutHeight` parses a zero `RawHeight` as `Never` instead of a real height.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+pub enum TimeoutTimestamp {
+    Never,
+    At(Timestamp),
+}
+
+impl TimeoutTimestamp {
+    pub fn no_timeout() -> Self {
+        Self::Never
+    }
+
+    /// Check if `now` is *at or past* the timeout timestamp, and thus is deemed expired.
+    pub fn has_expired(&self, now: Timestamp) -> bool {
+        match self {
+            Self::At(timeout_timestamp) => now.nanoseconds() >= timeout_timestamp.nanoseconds(),
+            // When there's no timeout, the timestamp never expires
+            Self::Never => false,
+        }
+    }
+}
+
+impl Default for TimeoutTimestamp {
+    fn default() -> Self {
+        Self::Never
+    }
+}
+
+impl TryFrom<u64> for TimeoutTimestamp {
+    type Error = ChannelError;
+
+    fn try_from(nanoseconds: u64) -> Result<Self, Self::Error> {
+        if nanoseconds == 0 {
+            Ok(TimeoutTimestamp::Never)
+        } else {
+            let timestamp = Timestamp::from_nanoseconds(nanoseconds)
+                .map_err(|_| ChannelError::invalid_timeout_timestamp())?;
+
+            Ok(TimeoutTimestamp::At(timestamp))
+        }
+    }
+}
+
+impl From<TimeoutTimestamp> for u64 {
+    fn from(timeout_timestamp: TimeoutTimestamp) -> Self {
+        match timeout_timestamp {
+            TimeoutTimestamp::At(timestamp) => timestamp.nanoseconds(),
+            TimeoutTimestamp::Never => 0,
+        }
+    }
+}
+
+impl Display for TimeoutTimestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            TimeoutTimestamp::At(timeout_timestamp) => write!(f, "{timeout_timestamp}"),
+            TimeoutTimestamp::Never => write!(f, "no timeout"),
+        }
+    }
+}
+
+impl Serialize for TimeoutTimestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // `0` is reserved to mean "no timeout", so both variants serialize through the same u64
+        // encoding used on the wire, with no separate bespoke representation needed.
+        let nanoseconds: u64 = (*self).into();
+        nanoseconds.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeoutTimestamp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let nanoseconds = u64::deserialize(deserializer)?;
+
+        // A `0` nanosecond count round-trips back to `Never` rather than a real, invalid
+        // timestamp.
+        Ok(TimeoutTimestamp::try_from(nanoseconds).unwrap_or(TimeoutTimestamp::Never))
+    }
+}
+
 /// A composite of timeout height and timeout timestamp types, useful for when
 /// performing a channel upgrade handshake, as there are cases when only timeout
 /// height is set, only timeout timestamp is set, or both are set.
@@ -223,6 +313,22 @@ impl UpgradeTimeout {
             UpgradeTimeout::Both(height, timestamp) => (Some(height), Some(timestamp)),
         }
     }
+
+    /// Checks whether this upgrade timeout has passed as of `height`/`timestamp`. Mirrors
+    /// `TimeoutHeight::has_expired`, except that a `Both` bound expires as soon as *either* the
+    /// height or the timestamp bound is exceeded, matching ibc-go's abort semantics for an
+    /// in-flight channel upgrade handshake.
+    pub fn has_expired(&self, height: Height, timestamp: Timestamp) -> bool {
+        match self {
+            UpgradeTimeout::Height(timeout_height) => height > *timeout_height,
+            UpgradeTimeout::Timestamp(timeout_timestamp) => {
+                timestamp.nanoseconds() >= timeout_timestamp.nanoseconds()
+            }
+            UpgradeTimeout::Both(timeout_height, timeout_timestamp) => {
+                height > *timeout_height || timestamp.nanoseconds() >= timeout_timestamp.nanoseconds()
+            }
+        }
+    }
 }
 
 impl Protobuf<RawUpgradeTimeout> for UpgradeTimeout {}
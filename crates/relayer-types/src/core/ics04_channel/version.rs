@@ -0,0 +1,37 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+/// The application-negotiated version string carried by a [`ChannelEnd`](super::channel::ChannelEnd).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Version(String);
+
+impl Version {
+    pub fn new(version: String) -> Self {
+        Self(version)
+    }
+
+    /// The version used by channels that haven't negotiated one yet.
+    pub fn empty() -> Self {
+        Self::new(String::new())
+    }
+
+    /// The ICS20 fungible-token-transfer version augmented with ICS29 fee middleware support.
+    pub fn ics20_with_fee() -> Self {
+        Self::new("{\"fee_version\":\"ics29-1\",\"app_version\":\"ics20-1\"}".to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for Version {
+    fn from(version: String) -> Self {
+        Self::new(version)
+    }
+}
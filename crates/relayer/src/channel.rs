@@ -5,6 +5,7 @@ pub use error::ChannelError;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::channel::v1::{MsgMultihopProofs, MultihopProof};
 use ibc_proto::Protobuf;
+use ibc_relayer_types::core::ics02_client::consensus_state::{AnyConsensusState, ConsensusState};
 use ibc_relayer_types::core::ics04_channel::channel::{
     ChannelEnd, Counterparty, IdentifiedChannelEnd, Ordering, State,
 };
@@ -14,8 +15,18 @@ use ibc_relayer_types::core::ics04_channel::msgs::chan_open_ack::MsgChannelOpenA
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
-use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentProofBytes;
-use ibc_relayer_types::core::ics23_commitment::merkle::apply_prefix;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_ack::MsgChannelUpgradeAck;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_cancel::MsgChannelUpgradeCancel;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_confirm::MsgChannelUpgradeConfirm;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_init::MsgChannelUpgradeInit;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_open::MsgChannelUpgradeOpen;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_timeout::MsgChannelUpgradeTimeout;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_try::MsgChannelUpgradeTry;
+use ibc_relayer_types::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_relayer_types::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
@@ -56,7 +67,7 @@ pub mod channel_handshake_retry {
     //! for the channel handshake algorithm.
 
     use crate::channel::ChannelError;
-    use crate::util::retry::{clamp, ConstantGrowth};
+    use crate::util::retry::clamp;
     use core::time::Duration;
 
     /// Approximate number of retries per block.
@@ -69,16 +80,116 @@ pub mod channel_handshake_retry {
     /// Maximum number of retries
     const MAX_RETRIES: u32 = 10;
 
-    /// The default retry strategy.
-    /// We retry with a constant backoff strategy. The strategy is parametrized by the
-    /// maximum block time expressed as a `Duration`.
-    pub fn default_strategy(max_block_time: Duration) -> impl Iterator<Item = Duration> {
-        let retry_delay = max_block_time / PER_BLOCK_RETRIES;
+    /// Per-chain override of the channel handshake retry parameters, normally sourced from
+    /// that chain's `ChainConfig`. Every field is optional so operators only need to set what
+    /// they want to change; anything left `None` reproduces the relayer's historical constant
+    /// backoff exactly (see [`default_strategy`]).
+    ///
+    /// Setting `jitter` is particularly useful when several relayers race to complete the same
+    /// handshake: without it, every relayer retries on the same constant schedule and keeps
+    /// re-colliding on the same block, amplifying crossing-message churn instead of resolving it.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    pub struct ChannelHandshakeRetryConfig {
+        /// Base delay before the first retry. Defaults to `max_block_time / PER_BLOCK_RETRIES`.
+        pub base_delay: Option<Duration>,
+        /// Multiplicative growth applied to the base delay after every retry. `1` (the
+        /// default) keeps the delay constant.
+        pub growth_factor: Option<u32>,
+        /// Maximum number of retries before the handshake is abandoned.
+        pub max_retries: Option<u32>,
+        /// Upper bound on any single retry delay.
+        pub max_delay: Option<Duration>,
+        /// Fraction of the computed delay to randomize, in `[0, 1]`. `0` (the default)
+        /// disables jitter.
+        pub jitter: Option<f64>,
+    }
+
+    /// Yields exponentially growing delays with multiplicative jitter:
+    /// `min(cap, base * factor^attempt) * uniform(1 - jitter, 1 + jitter)`. With `factor == 1`
+    /// and `jitter == 0.0` this reduces to the plain constant backoff `default_strategy` has
+    /// always used.
+    struct ExponentialBackoffWithJitter {
+        base: Duration,
+        factor: u32,
+        cap: Duration,
+        jitter: f64,
+        attempt: u32,
+    }
+
+    impl Iterator for ExponentialBackoffWithJitter {
+        type Item = Duration;
+
+        fn next(&mut self) -> Option<Duration> {
+            let growth = self.factor.saturating_pow(self.attempt);
+            let delay = self.base.saturating_mul(growth).min(self.cap);
+
+            let jitter = self.jitter.clamp(0.0, 1.0);
+            let scale = if jitter == 0.0 {
+                1.0
+            } else {
+                let unit = jitter_unit(self.attempt as u64);
+                1.0 - jitter + 2.0 * jitter * unit
+            };
+
+            self.attempt = self.attempt.saturating_add(1);
+
+            Some(delay.mul_f64(scale))
+        }
+    }
+
+    /// A pseudo-random value in `[0, 1)`, reseeded every call from the current time so that
+    /// independent relayer processes don't land on the same jitter sequence. This is a tiny
+    /// splitmix64 step rather than a dependency on the `rand` crate, since a single decorrelated
+    /// scalar per retry doesn't warrant pulling one in.
+    fn jitter_unit(attempt: u64) -> f64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+
+        let mut z = nanos
+            .wrapping_add(attempt)
+            .wrapping_add(0x9E3779B97F4A7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// The default retry strategy, parametrized by the maximum block time of the chains
+    /// involved and an optional per-chain [`ChannelHandshakeRetryConfig`]. When `retry_config`
+    /// is `None` (or leaves a field unset), that field falls back to the constant-backoff
+    /// behavior this relayer has always used, so existing deployments see no change unless they
+    /// opt in.
+    pub fn default_strategy(
+        max_block_time: Duration,
+        retry_config: Option<ChannelHandshakeRetryConfig>,
+    ) -> impl Iterator<Item = Duration> {
+        let retry_config = retry_config.unwrap_or_default();
+
+        let base_delay = retry_config
+            .base_delay
+            .unwrap_or(max_block_time / PER_BLOCK_RETRIES);
+        let growth_factor = retry_config.growth_factor.unwrap_or(1);
+        let max_retries = retry_config.max_retries.unwrap_or(MAX_RETRIES);
+        let max_delay = retry_config
+            .max_delay
+            .unwrap_or(base_delay + DELAY_INCREMENT * max_retries);
+        let jitter = retry_config.jitter.unwrap_or(0.0);
 
         clamp(
-            ConstantGrowth::new(retry_delay, DELAY_INCREMENT),
-            retry_delay + DELAY_INCREMENT * MAX_RETRIES,
-            MAX_RETRIES as usize,
+            ExponentialBackoffWithJitter {
+                base: base_delay,
+                factor: growth_factor,
+                cap: max_delay,
+                jitter,
+                attempt: 0,
+            },
+            max_delay,
+            max_retries as usize,
         )
     }
 
@@ -89,13 +200,88 @@ pub mod channel_handshake_retry {
     }
 }
 
+/// A pluggable, per-[`PortId`] alternative to [`version::default_by_port`]'s hard-coded
+/// `transfer`-only default, so the relayer can bootstrap channels whose version is something
+/// other than the plain ICS20 string (e.g. interchain-accounts' JSON metadata blob, or a
+/// fee-middleware version wrapping an inner app version).
+pub mod channel_version_negotiation {
+    use std::collections::HashMap;
+    use std::sync::{Arc, OnceLock, RwLock};
+
+    use ibc_relayer_types::core::ics04_channel::channel::Ordering;
+    use ibc_relayer_types::core::ics24_host::identifier::PortId;
+    use ibc_relayer_types::core::ics33_multihop::channel_path::ConnectionHops;
+
+    use crate::channel::{ChannelError, Version};
+
+    /// The `Channel` context a [`VersionNegotiator`] is given to decide on a version.
+    pub struct VersionNegotiationContext<'a> {
+        pub src_port_id: &'a PortId,
+        pub dst_port_id: &'a PortId,
+        pub connection_hops: Option<&'a ConnectionHops>,
+        pub ordering: Ordering,
+    }
+
+    /// Decides what [`Version`] to place in the `ChannelEnd` for a given port, and validates a
+    /// counterparty-proposed version against that choice.
+    pub trait VersionNegotiator: Send + Sync {
+        fn version(&self, ctx: &VersionNegotiationContext<'_>) -> Version;
+
+        fn validate_counterparty(
+            &self,
+            ctx: &VersionNegotiationContext<'_>,
+            proposed: &Version,
+        ) -> Result<(), ChannelError>;
+    }
+
+    #[derive(Default)]
+    pub struct VersionNegotiationRegistry {
+        negotiators: HashMap<PortId, Arc<dyn VersionNegotiator>>,
+    }
+
+    impl VersionNegotiationRegistry {
+        pub fn register(&mut self, port_id: PortId, negotiator: Arc<dyn VersionNegotiator>) {
+            self.negotiators.insert(port_id, negotiator);
+        }
+
+        pub fn get(&self, port_id: &PortId) -> Option<Arc<dyn VersionNegotiator>> {
+            self.negotiators.get(port_id).cloned()
+        }
+    }
+
+    fn global_registry() -> &'static RwLock<VersionNegotiationRegistry> {
+        static REGISTRY: OnceLock<RwLock<VersionNegotiationRegistry>> = OnceLock::new();
+        REGISTRY.get_or_init(|| RwLock::new(VersionNegotiationRegistry::default()))
+    }
+
+    /// Registers `negotiator` for `port_id`, overriding the empty/`transfer`-only fallback in
+    /// [`super::Channel::build_chan_open_init`] for channels opened on that port.
+    pub fn register(port_id: PortId, negotiator: Arc<dyn VersionNegotiator>) {
+        global_registry()
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .register(port_id, negotiator);
+    }
+
+    /// Looks up the negotiator registered for `port_id`, if any.
+    pub fn negotiator_for(port_id: &PortId) -> Option<Arc<dyn VersionNegotiator>> {
+        global_registry()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(port_id)
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 #[serde(bound(serialize = "(): Serialize"))]
 pub struct ChannelSide<Chain: ChainHandle> {
     #[serde(skip)]
     pub chain: Chain,
     client_id: ClientId,
-    connection_id: ConnectionId,
+    /// The ordered path of connections from this side to the counterparty, with the
+    /// local/source-adjacent connection first. A direct (single-hop) channel has exactly
+    /// one entry here; see [`ChannelSide::connection_id`] for the common-case accessor.
+    connection_ids: Vec<ConnectionId>,
     connection_hops: Option<ConnectionHops>,
     port_id: PortId,
     channel_id: Option<ChannelId>,
@@ -104,11 +290,17 @@ pub struct ChannelSide<Chain: ChainHandle> {
 
 impl<Chain: ChainHandle> Display for ChannelSide<Chain> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        let connection_ids = self
+            .connection_ids
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
         match (&self.channel_id, &self.version) {
-            (Some(channel_id), Some(version)) => write!(f, "ChannelSide {{ chain: {}, client_id: {}, connection_id: {}, port_id: {}, channel_id: {}, version: {} }}", self.chain, self.client_id, self.connection_id, self.port_id, channel_id, version),
-            (Some(channel_id), None) => write!(f, "ChannelSide {{ chain: {}, client_id: {}, connection_id: {}, port_id: {}, channel_id: {}, version: None }}", self.chain, self.client_id, self.connection_id, self.port_id, channel_id),
-            (None, Some(version)) => write!(f, "ChannelSide {{ chain: {}, client_id: {}, connection_id: {}, port_id: {}, channel_id: None, version: {} }}", self.chain, self.client_id, self.connection_id, self.port_id, version),
-            (None, None) => write!(f, "ChannelSide {{ chain: {}, client_id: {}, connection_id: {}, port_id: {}, channel_id: None, version: None }}", self.chain, self.client_id, self.connection_id, self.port_id),
+            (Some(channel_id), Some(version)) => write!(f, "ChannelSide {{ chain: {}, client_id: {}, connection_ids: {}, port_id: {}, channel_id: {}, version: {} }}", self.chain, self.client_id, connection_ids, self.port_id, channel_id, version),
+            (Some(channel_id), None) => write!(f, "ChannelSide {{ chain: {}, client_id: {}, connection_ids: {}, port_id: {}, channel_id: {}, version: None }}", self.chain, self.client_id, connection_ids, self.port_id, channel_id),
+            (None, Some(version)) => write!(f, "ChannelSide {{ chain: {}, client_id: {}, connection_ids: {}, port_id: {}, channel_id: None, version: {} }}", self.chain, self.client_id, connection_ids, self.port_id, version),
+            (None, None) => write!(f, "ChannelSide {{ chain: {}, client_id: {}, connection_ids: {}, port_id: {}, channel_id: None, version: None }}", self.chain, self.client_id, connection_ids, self.port_id),
         }
     }
 }
@@ -117,7 +309,7 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
     pub fn new(
         chain: Chain,
         client_id: ClientId,
-        connection_id: ConnectionId,
+        connection_ids: Vec<ConnectionId>,
         connection_hops: Option<ConnectionHops>,
         port_id: PortId,
         channel_id: Option<ChannelId>,
@@ -126,7 +318,7 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
         Self {
             chain,
             client_id,
-            connection_id,
+            connection_ids,
             connection_hops,
             port_id,
             channel_id,
@@ -142,8 +334,15 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
         &self.client_id
     }
 
+    /// Returns the local/source-adjacent connection, i.e. the first hop on the path to the
+    /// counterparty. For a direct (single-hop) channel this is the only connection.
     pub fn connection_id(&self) -> &ConnectionId {
-        &self.connection_id
+        &self.connection_ids[0]
+    }
+
+    /// Returns the full ordered path of connections from this side to the counterparty.
+    pub fn connection_ids(&self) -> &[ConnectionId] {
+        &self.connection_ids
     }
 
     pub fn connection_hops(&self) -> Option<&ConnectionHops> {
@@ -169,7 +368,7 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
         ChannelSide {
             chain: mapper(self.chain),
             client_id: self.client_id,
-            connection_id: self.connection_id,
+            connection_ids: self.connection_ids,
             connection_hops: self.connection_hops,
             port_id: self.port_id,
             channel_id: self.channel_id,
@@ -178,6 +377,51 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
     }
 }
 
+/// The upgrade fields a relayer proposes for a channel upgrade, mirroring the
+/// open-handshake fields carried on [`ChannelSide`] but kept separate since
+/// they only apply while an upgrade is in flight.
+#[derive(Clone, Debug, Serialize)]
+pub struct ChannelUpgradeAttributes {
+    pub version: Version,
+    pub ordering: Ordering,
+    pub connection_hops: Vec<ConnectionId>,
+    pub timeout_height: Option<Height>,
+    pub timeout_timestamp: Option<u64>,
+}
+
+/// Tracks the local view of an in-flight channel upgrade's flush phase, used
+/// by [`Channel::upgrade`] to decide whether it is safe to move to `OPEN`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ChannelUpgradeState {
+    /// The upgrade handshake has not flushed in-flight packets yet.
+    Flushing,
+    /// Both ends have finished flushing in-flight packets and may move to `OPEN`.
+    FlushComplete,
+}
+
+/// The follow-up work still required to finish tearing a channel down after
+/// [`Channel::close_channel`] has submitted `CloseInit` on one side. Computed and returned while
+/// both channel ends were still consistent, rather than being re-derived from scratch, so an
+/// interrupted close can be resumed deterministically: the caller persists or logs this value and
+/// later drives `close_confirm_chain_id`'s `CloseConfirm` (e.g. via this channel's
+/// [`Channel::flipped`]) without having to re-discover which side already closed.
+#[derive(Clone, Debug)]
+pub struct ChannelCloseSummary {
+    pub ordering: Ordering,
+    /// The channel end `CloseInit` was just submitted against (already `Closed`).
+    pub closed_channel: ChannelEnd,
+    /// The counterparty channel end, as last observed before `CloseInit` (still `Open` until
+    /// `CloseConfirm` lands on `close_confirm_chain_id`).
+    pub counterparty_channel: ChannelEnd,
+    /// The chain on which `CloseConfirm` must still be submitted to finish the teardown.
+    pub close_confirm_chain_id: ChainId,
+    /// The latest height of the chain that just closed, queried after `CloseInit` committed there
+    /// (so at or above the commit height); the light client `close_confirm_chain_id` holds for
+    /// that chain must be updated at least this high before a `CloseConfirm` proof built against
+    /// it will verify.
+    pub required_client_update_height: Height,
+}
+
 #[derive(Clone, Debug, Serialize)]
 #[serde(bound(serialize = "(): Serialize"))]
 pub struct Channel<ChainA: ChainHandle, ChainB: ChainHandle> {
@@ -185,6 +429,8 @@ pub struct Channel<ChainA: ChainHandle, ChainB: ChainHandle> {
     pub a_side: ChannelSide<ChainA>,
     pub b_side: ChannelSide<ChainB>,
     pub connection_delay: Duration,
+    /// The upgrade currently being proposed/negotiated for this channel, if any.
+    pub upgrade_attrs: Option<ChannelUpgradeAttributes>,
 }
 
 impl<ChainA: ChainHandle, ChainB: ChainHandle> Display for Channel<ChainA, ChainB> {
@@ -195,7 +441,6 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Display for Channel<ChainA, Chain
             self.ordering,
             self.a_side,
             self.b_side,
-            // FIXME: add connection hops
             PrettyDuration(&self.connection_delay)
         )
     }
@@ -225,7 +470,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             a_side: ChannelSide::new(
                 connection.src_chain(),
                 connection.src_client_id().clone(),
-                src_connection_id.clone(),
+                vec![src_connection_id.clone()],
                 a_side_hops,
                 a_port,
                 Default::default(),
@@ -234,13 +479,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             b_side: ChannelSide::new(
                 connection.dst_chain(),
                 connection.dst_client_id().clone(),
-                dst_connection_id.clone(),
+                vec![dst_connection_id.clone()],
                 b_side_hops,
                 b_port,
                 Default::default(),
                 version,
             ),
             connection_delay: connection.delay_period,
+            upgrade_attrs: None,
         };
 
         channel.handshake()?;
@@ -261,15 +507,17 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         let port_id = channel_event_attributes.port_id.clone();
         let channel_id = channel_event_attributes.channel_id;
 
-        // FIXME: connection_id is an instance of ConnectionIds(Vec<ConnectionId>), but ChannelSide::new() requires
-        // a single ConnectionId. To avoid further changes in ChannelSide, get only the 0th element for now.
-        // In the future, modify ChannelSide to use a Vec<ConnectionId>.
-        let connection_id = channel_event_attributes.connection_id.as_slice()[0].clone();
+        // The event carries the full ordered connection path as seen from `chain`; keep all of
+        // it on `a_side` rather than flattening it down to the first hop. The connection query
+        // below still targets the local (source-adjacent) connection, since that is the one
+        // `chain` itself owns.
+        let connection_ids = channel_event_attributes.connection_id.as_slice().to_vec();
+        let connection_id = connection_ids[0].clone();
 
         let (connection, _) = chain
             .query_connection(
                 QueryConnectionRequest {
-                    connection_id: connection_id.clone(), // FIXME: Add support for multihop connections queries.
+                    connection_id: connection_id.clone(),
                     height: QueryHeight::Latest,
                 },
                 IncludeProof::No,
@@ -290,7 +538,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             a_side: ChannelSide::new(
                 chain,
                 connection.client_id().clone(),
-                connection_id.clone(),
+                connection_ids,
                 None, //FIXME: Unsure what to add here ('None' for now), can we get the hops from the event?
                 port_id,
                 channel_id,
@@ -301,13 +549,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             b_side: ChannelSide::new(
                 counterparty_chain,
                 connection.counterparty().client_id().clone(),
-                counterparty_connection_id.clone(),
+                vec![counterparty_connection_id.clone()],
                 None, //FIXME: Unsure what to add here ('None' for now), can we get the hops from the event?
                 channel_event_attributes.counterparty_port_id.clone(),
                 channel_event_attributes.counterparty_channel_id,
                 None,
             ),
             connection_delay: connection.delay_period(),
+            upgrade_attrs: None,
         })
     }
 
@@ -330,7 +579,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             )
             .map_err(ChannelError::relayer)?;
 
-        let a_connection_id = a_channel.connection_hops().first().ok_or_else(|| {
+        let a_connection_ids = a_channel.connection_hops();
+
+        let a_connection_id = a_connection_ids.first().ok_or_else(|| {
             ChannelError::supervisor(SupervisorError::missing_connection_hops(
                 channel.src_channel_id.clone(),
                 chain.id(),
@@ -364,7 +615,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             a_side: ChannelSide::new(
                 chain.clone(),
                 a_connection.client_id().clone(),
-                a_connection_id.clone(),
+                // The channel end on `chain` records the full connection path, not just the
+                // locally-adjacent connection; keep all of it rather than discarding everything
+                // past the first hop.
+                a_connection_ids.to_vec(),
                 None, // FIXME: Unsure about what to add here ('None' for now)
                 channel.src_port_id.clone(),
                 Some(channel.src_channel_id.clone()),
@@ -373,13 +627,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             b_side: ChannelSide::new(
                 counterparty_chain.clone(),
                 a_connection.counterparty().client_id().clone(),
-                b_connection_id.clone(),
+                vec![b_connection_id.clone()],
                 None, // FIXME: Unsure about what to add here ('None' for now)
                 a_channel.remote.port_id.clone(),
                 a_channel.remote.channel_id.clone(),
                 None,
             ),
             connection_delay: a_connection.delay_period(),
+            upgrade_attrs: None,
         };
 
         if a_channel.state_matches(&State::Init) && a_channel.remote.channel_id.is_none() {
@@ -428,11 +683,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
     }
 
     pub fn src_connection_id(&self) -> &ConnectionId {
-        &self.a_side.connection_id
+        self.a_side.connection_id()
     }
 
     pub fn dst_connection_id(&self) -> &ConnectionId {
-        &self.b_side.connection_id
+        self.b_side.connection_id()
     }
 
     pub fn src_port_id(&self) -> &PortId {
@@ -520,12 +775,23 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         Ok(a_block_time.max(b_block_time))
     }
 
+    /// Returns the channel handshake retry override configured for `a_side`'s chain, if any.
+    /// Only one side is consulted (rather than merging both, as [`Channel::max_block_times`]
+    /// does) since the retry schedule only needs to be de-correlated from other relayers
+    /// driving the same handshake, not from the counterparty chain itself.
+    fn handshake_retry_config(
+        &self,
+    ) -> Option<channel_handshake_retry::ChannelHandshakeRetryConfig> {
+        self.a_chain().config().ok()?.channel_handshake_retry
+    }
+
     pub fn flipped(&self) -> Channel<ChainB, ChainA> {
         Channel {
             ordering: self.ordering,
             a_side: self.b_side.clone(),
             b_side: self.a_side.clone(),
             connection_delay: self.connection_delay,
+            upgrade_attrs: self.upgrade_attrs.clone(),
         }
     }
 
@@ -630,6 +896,35 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         Ok((*a_channel.state(), *b_channel.state()))
     }
 
+    /// Reconciles a failed handshake-step send against the possibility that a competing relayer
+    /// already landed the same step. `e` is the error the send produced, `original_states` is the
+    /// `(a_state, b_state)` pair [`Channel::do_chan_open_handshake`] observed before attempting the
+    /// step. If `e` looks like an "already exists"/state-mismatch chain error and re-querying the
+    /// channel ends shows the states have moved on from `original_states`, the step is treated as
+    /// done rather than as a hard failure, so concurrent relayers don't churn on retries.
+    fn reconcile_step_error(
+        &mut self,
+        e: ChannelError,
+        original_states: (State, State),
+    ) -> Result<(), ChannelError> {
+        if !channel_error_indicates_already_advanced(&e) {
+            return Err(e);
+        }
+
+        let new_states = self.update_channel_and_query_states()?;
+
+        if new_states != original_states {
+            info!(
+                "handshake step already completed by a competing relayer for {} \
+                ({}-{} observed after failed send): {}",
+                self, new_states.0, new_states.1, e
+            );
+            Ok(())
+        } else {
+            Err(e)
+        }
+    }
+
     /// Sends a channel open handshake message.
     /// The message sent depends on the chain status of the channel ends.
     fn do_chan_open_handshake(&mut self) -> Result<(), ChannelError> {
@@ -655,58 +950,62 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
 
             // send the Try message to chain a (source)
             (State::Uninitialized, State::Init) | (State::Init, State::Init) => {
-                let event = self.flipped().build_chan_open_try_and_send().map_err(|e| {
-                    error!("failed ChanOpenTry {}: {}", self.a_side, e);
-                    e
-                })?;
-
-                let channel_id = extract_channel_id(&event)?;
-                self.a_side.channel_id = Some(channel_id.clone());
+                match self.flipped().build_chan_open_try_and_send() {
+                    Ok(event) => {
+                        let channel_id = extract_channel_id(&event)?;
+                        self.a_side.channel_id = Some(channel_id.clone());
+                    }
+                    Err(e) => {
+                        error!("failed ChanOpenTry {}: {}", self.a_side, e);
+                        self.reconcile_step_error(e, (a_state, b_state))?;
+                    }
+                }
             }
 
             // send the Try message to chain b (destination)
             (State::Init, State::Uninitialized) => {
-                let event = self.build_chan_open_try_and_send().map_err(|e| {
-                    error!("failed ChanOpenTry {}: {}", self.b_side, e);
-                    e
-                })?;
-
-                let channel_id = extract_channel_id(&event)?;
-                self.b_side.channel_id = Some(channel_id.clone());
+                match self.build_chan_open_try_and_send() {
+                    Ok(event) => {
+                        let channel_id = extract_channel_id(&event)?;
+                        self.b_side.channel_id = Some(channel_id.clone());
+                    }
+                    Err(e) => {
+                        error!("failed ChanOpenTry {}: {}", self.b_side, e);
+                        self.reconcile_step_error(e, (a_state, b_state))?;
+                    }
+                }
             }
 
             // send the Ack message to chain a (source)
             (State::Init, State::TryOpen) | (State::TryOpen, State::TryOpen) => {
-                self.flipped().build_chan_open_ack_and_send().map_err(|e| {
+                if let Err(e) = self.flipped().build_chan_open_ack_and_send() {
                     error!("failed ChanOpenAck {}: {}", self.a_side, e);
-                    e
-                })?;
+                    self.reconcile_step_error(e, (a_state, b_state))?;
+                }
             }
 
             // send the Ack message to chain b (destination)
             (State::TryOpen, State::Init) => {
-                self.build_chan_open_ack_and_send().map_err(|e| {
+                if let Err(e) = self.build_chan_open_ack_and_send() {
                     error!("failed ChanOpenAck {}: {}", self.b_side, e);
-                    e
-                })?;
+                    self.reconcile_step_error(e, (a_state, b_state))?;
+                }
             }
 
             // send the Confirm message to chain b (destination)
             (State::Open, State::TryOpen) => {
-                self.build_chan_open_confirm_and_send().map_err(|e| {
+                if let Err(e) = self.build_chan_open_confirm_and_send() {
                     error!("failed ChanOpenConfirm {}: {}", self.b_side, e);
-                    e
-                })?;
+                    self.reconcile_step_error(e, (a_state, b_state))?;
+                }
             }
 
             // send the Confirm message to chain a (source)
             (State::TryOpen, State::Open) => {
-                self.flipped()
-                    .build_chan_open_confirm_and_send()
-                    .map_err(|e| {
-                        error!("failed ChanOpenConfirm {}: {}", self.a_side, e);
-                        e
-                    })?;
+                if let Err(e) = self.flipped().build_chan_open_confirm_and_send() {
+                    error!("failed ChanOpenConfirm {}: {}", self.a_side, e);
+                    self.reconcile_step_error(e, (a_state, b_state))?;
+                }
             }
 
             (State::Open, State::Open) => {
@@ -731,9 +1030,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
     /// Executes the channel handshake protocol (ICS004)
     fn handshake(&mut self) -> Result<(), ChannelError> {
         let max_block_times = self.max_block_times()?;
+        let retry_config = self.handshake_retry_config();
 
         retry_with_index(
-            channel_handshake_retry::default_strategy(max_block_times),
+            channel_handshake_retry::default_strategy(max_block_times, retry_config),
             |_| {
                 if let Err(e) = self.do_chan_open_handshake() {
                     if e.is_expired_or_frozen_error() {
@@ -758,6 +1058,148 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         Ok(())
     }
 
+    fn do_chan_close_handshake(&mut self) -> Result<(), ChannelError> {
+        let (a_state, b_state) = self.update_channel_and_query_states()?;
+        debug!(
+            "do_chan_close_handshake with channel end states: {}, {}",
+            a_state, b_state
+        );
+
+        match (a_state, b_state) {
+            // send the CloseInit message to chain a (source)
+            (State::Open, State::Open) => {
+                self.flipped().build_chan_close_init_and_send().map_err(|e| {
+                    error!("failed ChanCloseInit {}: {}", self.a_side, e);
+                    e
+                })?;
+            }
+
+            // send the CloseConfirm message to chain b (destination), which has not yet closed
+            (State::Closed, State::Open) => {
+                self.build_chan_close_confirm_and_send().map_err(|e| {
+                    error!("failed ChanCloseConfirm {}: {}", self.b_side, e);
+                    e
+                })?;
+            }
+
+            // send the CloseConfirm message to chain a (source), which has not yet closed
+            (State::Open, State::Closed) => {
+                self.flipped()
+                    .build_chan_close_confirm_and_send()
+                    .map_err(|e| {
+                        error!("failed ChanCloseConfirm {}: {}", self.a_side, e);
+                        e
+                    })?;
+            }
+
+            (State::Closed, State::Closed) => {
+                info!("channel close handshake already finished for {}", self);
+                return Ok(());
+            }
+
+            (a_state, b_state) => {
+                warn!(
+                    "do_chan_close_handshake does not handle channel end state combination: \
+                    {}-{}, {}-{}. will retry to account for RPC node data availability issues.",
+                    self.a_chain().id(),
+                    a_state,
+                    self.b_chain().id(),
+                    b_state
+                );
+            }
+        }
+        Err(ChannelError::handshake_finalize())
+    }
+
+    /// Gracefully tears down this channel (ICS004 close handshake), mirroring [`Self::handshake`]:
+    /// drives `CloseInit`/`CloseConfirm` back and forth until both ends report `Closed`, retrying
+    /// with the same backoff strategy and expired/frozen-client error classification.
+    pub fn close(&mut self) -> Result<(), ChannelError> {
+        let max_block_times = self.max_block_times()?;
+        let retry_config = self.handshake_retry_config();
+
+        retry_with_index(
+            channel_handshake_retry::default_strategy(max_block_times, retry_config),
+            |_| {
+                if let Err(e) = self.do_chan_close_handshake() {
+                    if e.is_expired_or_frozen_error() {
+                        RetryResult::Err(e)
+                    } else {
+                        RetryResult::Retry(e)
+                    }
+                } else {
+                    RetryResult::Ok(())
+                }
+            },
+        )
+        .map_err(|err| {
+            error!("failed to close channel after {} retries", err.tries);
+
+            channel_handshake_retry::from_retry_error(
+                err,
+                format!("failed to finish channel close handshake for {self:?}"),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    /// Initiates a channel teardown by submitting `CloseInit` against `dst_chain()`, then
+    /// returns a [`ChannelCloseSummary`] describing what is still required to finish the
+    /// teardown, instead of chasing `CloseConfirm` itself the way [`Self::close`] does. Prefer
+    /// this over [`Self::close`] when the caller wants to persist or inspect the deferred
+    /// `CloseConfirm` work (e.g. to resume it later, possibly in a different process) rather than
+    /// block until the close handshake fully completes.
+    pub fn close_channel(&self) -> Result<ChannelCloseSummary, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        // Snapshot the counterparty (source) channel end before submitting `CloseInit`, so the
+        // summary reflects the state the close was computed against.
+        let (counterparty_channel, _) = self
+            .src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        self.build_chan_close_init_and_send()?;
+
+        let (closed_channel, _) = self
+            .dst_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: dst_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        let required_client_update_height = self
+            .dst_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        Ok(ChannelCloseSummary {
+            ordering: self.ordering,
+            closed_channel,
+            counterparty_channel,
+            close_confirm_chain_id: self.src_chain().id(),
+            required_client_update_height,
+        })
+    }
+
     pub fn counterparty_state(&self) -> Result<State, ChannelError> {
         // Source channel ID must be specified
         let channel_id = self
@@ -833,88 +1275,383 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
     }
 
     pub fn step_event(&mut self, event: &IbcEvent, index: u64) -> RetryResult<Next, u64> {
-        let state = match event {
-            IbcEvent::OpenInitChannel(_) => State::Init,
-            IbcEvent::OpenTryChannel(_) => State::TryOpen,
-            IbcEvent::OpenAckChannel(_) => State::Open,
-            IbcEvent::OpenConfirmChannel(_) => State::Open,
-            IbcEvent::CloseInitChannel(_) => State::Closed,
-            _ => State::Uninitialized,
-        };
+        if !self.channel_event_matches(event) {
+            return RetryResult::Ok(Next::Continue);
+        }
 
-        self.step_state(state, index)
+        self.step_state(channel_handshake_state_for_event(event), index)
     }
 
-    pub fn build_update_client_on_dst(&self, height: Height) -> Result<Vec<Any>, ChannelError> {
-        let client = ForeignClient::restore(
-            self.dst_client_id().clone(),
-            self.dst_chain().clone(),
-            self.src_chain().clone(),
-        );
+    /// Returns `true` if `event` concerns this channel's port/channel on either side, i.e. it
+    /// was emitted by `a_chain()` or `b_chain()` for the handshake this `Channel` is tracking.
+    /// Used to filter a worker's combined event stream from both chains down to events worth
+    /// reacting to, since [`IbcEvent`]'s channel variants carry no notion of which `Channel`
+    /// they belong to on their own.
+    fn channel_event_matches(&self, event: &IbcEvent) -> bool {
+        let attrs = match event.channel_attributes() {
+            Some(attrs) => attrs,
+            None => return false,
+        };
 
-        client.wait_and_build_update_client(height).map_err(|e| {
-            ChannelError::client_operation(self.dst_client_id().clone(), self.dst_chain().id(), e)
-        })
+        let side_matches = |port_id: &PortId, channel_id: Option<&ChannelId>| {
+            attrs.port_id == *port_id
+                && channel_id.map_or(true, |id| attrs.channel_id.as_ref() == Some(id))
+        };
+
+        side_matches(self.src_port_id(), self.src_channel_id())
+            || side_matches(self.dst_port_id(), self.dst_channel_id())
     }
 
-    pub fn build_update_client_on_last_hop(
-        &self,
-        height: Height,
-    ) -> Result<Vec<Any>, ChannelError> {
-        let channel_id = self
-            .a_side
-            .channel_id()
-            .ok_or(ChannelError::missing_local_channel_id())?;
+    /// Returns `true` if `event` was (almost certainly) emitted by this channel's counterparty,
+    /// i.e. it concerns the exact `(port_id, channel_id, counterparty_channel_id)` this side of
+    /// the handshake is tracking. Used by [`Channel::handshake_step_from_event`] to filter the
+    /// supervisor's event stream down to events worth acting on.
+    fn counterparty_event_matches(&self, event: &IbcEvent) -> bool {
+        let attrs = match event.channel_attributes() {
+            Some(attrs) => attrs,
+            None => return false,
+        };
 
-        let connection_hops =
-            self.a_side
-                .connection_hops()
-                .ok_or(ChannelError::missing_local_connection_hops(
-                    channel_id.clone(),
-                    self.a_side.chain_id().clone(),
-                ))?;
+        if attrs.port_id != *self.dst_port_id() {
+            return false;
+        }
 
-        let last_hop = connection_hops.hops.iter().last().ok_or(
-            ChannelError::missing_local_connection_hops(
-                channel_id.clone(),
-                self.a_side.chain_id().clone(),
-            ),
-        )?;
+        if let (Some(dst_channel_id), Some(event_channel_id)) =
+            (self.dst_channel_id(), &attrs.channel_id)
+        {
+            if dst_channel_id != event_channel_id {
+                return false;
+            }
+        }
 
-        // Get access to the registry to get or spawn chain handles
-        let registry = get_global_registry();
+        if let (Some(src_channel_id), Some(event_counterparty_channel_id)) =
+            (self.src_channel_id(), &attrs.counterparty_channel_id)
+        {
+            if src_channel_id != event_counterparty_channel_id {
+                return false;
+            }
+        }
 
-        let last_hop_src_chain = registry
-            .get_or_spawn(&last_hop.src_chain_id)
-            .map_err(ChannelError::spawn)?;
+        true
+    }
 
-        // Restore the client hosted by the channel path's (a_side to b_side) destination chain
-        // to track the state of the penultimate chain.
-        let client = ForeignClient::restore(
-            self.dst_client_id().clone(),
-            self.dst_chain().clone(),
-            last_hop_src_chain.clone(),
-        );
+    /// Advances the handshake directly from an observed counterparty event, without first
+    /// re-querying `counterparty_state()` the way [`Channel::handshake_step`] does. This is
+    /// what an event-driven channel worker should call for every event on its subscription,
+    /// typically after reconstructing (or updating) the channel via
+    /// [`Channel::restore_from_event`]. Events that don't concern this channel are ignored,
+    /// signalled by [`Next::Continue`] with no event, so the caller keeps listening.
+    pub fn handshake_step_from_event(
+        &mut self,
+        event: &IbcEvent,
+    ) -> Result<(Option<IbcEvent>, Next), ChannelError> {
+        if !self.counterparty_event_matches(event) {
+            return Ok((None, Next::Continue));
+        }
 
-        client.wait_and_build_update_client(height).map_err(|e| {
-            ChannelError::client_operation(self.dst_client_id().clone(), self.dst_chain().id(), e)
-        })
+        let result = match event {
+            IbcEvent::OpenInitChannel(_) => Some(self.build_chan_open_try_and_send()?),
+            IbcEvent::OpenTryChannel(_) => Some(self.build_chan_open_ack_and_send()?),
+            IbcEvent::OpenAckChannel(_) | IbcEvent::OpenConfirmChannel(_) => {
+                return Ok((None, Next::Abort))
+            }
+            _ => None,
+        };
+
+        match result {
+            Some(IbcEvent::OpenConfirmChannel(_)) | Some(IbcEvent::OpenAckChannel(_)) => {
+                Ok((result, Next::Abort))
+            }
+            _ => Ok((result, Next::Continue)),
+        }
     }
 
-    pub fn build_chan_open_init(&self) -> Result<Vec<Any>, ChannelError> {
-        let signer = self
+    /// Reacts to a single streamed channel handshake event by building and submitting whichever
+    /// message advances the *opposite* side, returning the resulting event. Unlike
+    /// [`Self::handshake_step_from_event`], which is restricted to the open handshake and
+    /// expressed in terms of [`Next`], this also drives `CloseInitChannel` to completion and
+    /// reports completion simply as `Ok(None)`, which is what a supervisor reacting to a mixed
+    /// stream of channel events (rather than driving a single handshake worker loop) wants:
+    /// "was there something to do, and did it land."
+    ///
+    /// The open-ack and close-confirm builders (`build_chan_open_ack_and_send`,
+    /// `build_chan_close_confirm_and_send`) call [`Self::validated_expected_channel`] internally
+    /// (the open-try builder does not), which in turn calls
+    /// `check_destination_channel_state` to confirm the destination will actually accept the
+    /// message before constructing it. If a competing relayer has already landed the same step,
+    /// the destination's rejection is recognized by
+    /// [`channel_error_indicates_already_advanced`] and treated as success (`Ok(None)`) rather
+    /// than propagated as an error, so two relayers racing the same handshake don't churn.
+    pub fn step_channel_event(&self, event: &IbcEvent) -> Result<Option<IbcEvent>, ChannelError> {
+        if !self.counterparty_event_matches(event) {
+            return Ok(None);
+        }
+
+        let result = match event {
+            IbcEvent::OpenInitChannel(_) => Some(self.build_chan_open_try_and_send()),
+            IbcEvent::OpenTryChannel(_) => Some(self.build_chan_open_ack_and_send()),
+            IbcEvent::CloseInitChannel(_) => Some(self.build_chan_close_confirm_and_send()),
+            _ => None,
+        };
+
+        self.reconcile_step_event_result(&format!("event {event}"), result)
+    }
+
+    /// Polling counterpart to [`Self::step_channel_event`]: instead of reacting to a streamed
+    /// event, re-queries both channel ends' live states and, if a next handshake message is due,
+    /// builds and submits it. Returns `Ok(None)` if nothing is due, the handshake has already
+    /// finished, or (like [`Self::step_channel_event`]) a competing relayer turns out to have
+    /// already landed the step. Useful for a supervisor loop that falls back to polling when no
+    /// event has streamed in recently.
+    pub fn step_channel_state(&mut self) -> Result<Option<IbcEvent>, ChannelError> {
+        let (a_state, b_state) = self.update_channel_and_query_states()?;
+
+        let result = match (a_state, b_state) {
+            (State::Init, State::Uninitialized) | (State::Init, State::Init) => {
+                Some(self.build_chan_open_try_and_send())
+            }
+            (State::Uninitialized, State::Init) => Some(self.flipped().build_chan_open_try_and_send()),
+            (State::TryOpen, State::Init) | (State::TryOpen, State::TryOpen) => {
+                Some(self.build_chan_open_ack_and_send())
+            }
+            (State::Init, State::TryOpen) => Some(self.flipped().build_chan_open_ack_and_send()),
+            (State::Open, State::TryOpen) => Some(self.build_chan_open_confirm_and_send()),
+            (State::TryOpen, State::Open) => Some(self.flipped().build_chan_open_confirm_and_send()),
+            (State::Closed, State::Open) => Some(self.build_chan_close_confirm_and_send()),
+            (State::Open, State::Closed) => Some(self.flipped().build_chan_close_confirm_and_send()),
+            _ => None,
+        };
+
+        self.reconcile_step_event_result(&format!("polled states {a_state}/{b_state}"), result)
+    }
+
+    /// Shared by [`Self::step_channel_event`] and [`Self::step_channel_state`]: turns `result` --
+    /// the outcome of whichever handshake builder was due, if any -- into the `Ok(Option)` shape
+    /// those two methods return, collapsing an "already advanced" destination error
+    /// ([`channel_error_indicates_already_advanced`]) into `Ok(None)` instead of propagating it.
+    /// `trigger` is used only to log what prompted the step.
+    fn reconcile_step_event_result(
+        &self,
+        trigger: &str,
+        result: Option<Result<IbcEvent, ChannelError>>,
+    ) -> Result<Option<IbcEvent>, ChannelError> {
+        match result {
+            None => Ok(None),
+            Some(Ok(event)) => Ok(Some(event)),
+            Some(Err(e)) if channel_error_indicates_already_advanced(&e) => {
+                info!(
+                    "handshake step already completed by a competing relayer for {} while reacting to {}: {}",
+                    self, trigger, e
+                );
+                Ok(None)
+            }
+            Some(Err(e)) => Err(e),
+        }
+    }
+
+    /// Drives the handshake from events pulled via `next_event`, instead of re-querying both
+    /// chains on every retry tick as [`Channel::handshake`] does. `next_event` should block for
+    /// up to the given timeout waiting for the next relevant counterparty event and return
+    /// `None` on timeout; this is how an event-driven channel worker wires in the supervisor's
+    /// event stream. Falls back to the polling [`Channel::handshake`] strategy if no matching
+    /// event is observed within `max_block_times()`, or if a handshake step fails outright.
+    pub fn handshake_via_events(
+        &mut self,
+        mut next_event: impl FnMut(Duration) -> Option<IbcEvent>,
+    ) -> Result<(), ChannelError> {
+        let max_block_times = self.max_block_times()?;
+
+        loop {
+            let event = match next_event(max_block_times) {
+                Some(event) => event,
+                None => {
+                    warn!(
+                        "no channel handshake event observed for {} within {:?}, falling back to polling",
+                        self.a_side, max_block_times
+                    );
+                    return self.handshake();
+                }
+            };
+
+            match self.handshake_step_from_event(&event) {
+                Ok((_, Next::Abort)) => return Ok(()),
+                Ok((_, Next::Continue)) => continue,
+                Err(e) if e.is_expired_or_frozen_error() => return Err(e),
+                Err(e) => {
+                    error!(
+                        "event-driven channel handshake step failed, falling back to polling: {}",
+                        e
+                    );
+                    return self.handshake();
+                }
+            }
+        }
+    }
+
+    /// Drives the open handshake by reacting to events from `a_chain()`/`b_chain()` the moment
+    /// they arrive, instead of waiting out [`Channel::handshake`]'s fixed poll interval every
+    /// time. `next_event` should block for up to `poll_interval` and return the next channel
+    /// event observed on either chain, or `None` once the interval elapses with nothing new —
+    /// the timed fallback that keeps the handshake moving if an event is dropped while a
+    /// competing relayer races us to the counterparty tx.
+    ///
+    /// Both triggers funnel into [`Channel::step_event`]/[`Channel::step_state`], which in turn
+    /// call [`Channel::handshake_step`] — the same state machine `handshake()` uses, keyed on
+    /// `(state, counterparty_state())`. Since `counterparty_state()` is re-queried on every
+    /// call, a reactive trigger and a polled trigger racing on the same step observe whichever
+    /// one landed first and the loser's step is a no-op (`Next::Continue` with no new message)
+    /// rather than a duplicate submit.
+    pub fn handshake_reactive(
+        &mut self,
+        mut state: State,
+        mut next_event: impl FnMut(Duration) -> Option<IbcEvent>,
+        poll_interval: Duration,
+    ) -> Result<(), ChannelError> {
+        let mut index: u64 = 0;
+
+        loop {
+            let result = match next_event(poll_interval) {
+                Some(event) => {
+                    state = channel_handshake_state_for_event(&event);
+
+                    // A multihop path's intermediate-hop clients aren't touched by the event we
+                    // just observed on `a_chain()`/`b_chain()`, so they can go stale waiting for
+                    // the next step; refresh them eagerly here rather than letting the next
+                    // `build_multihop_*` call discover a stale client as a failed proof query.
+                    if self.a_side.connection_hops().is_some() {
+                        if let Err(e) = self.update_channel_path_clients() {
+                            warn!(
+                                "failed to refresh multihop path clients for {} while reacting to {}: {}",
+                                self, event, e
+                            );
+                        }
+                    }
+
+                    self.step_event(&event, index)
+                }
+                None => self.step_state(state, index),
+            };
+
+            match result {
+                RetryResult::Ok(Next::Abort) => return Ok(()),
+                RetryResult::Ok(Next::Continue) => index = 0,
+                RetryResult::Retry(next_index) => index = next_index,
+                RetryResult::Err(_) => {
+                    return Err(ChannelError::missing_event(format!(
+                        "reactive channel handshake for {} did not converge",
+                        self.a_side
+                    )))
+                }
+            }
+        }
+    }
+
+    /// The reactive loop a multihop channel worker uses to drive `OpenInit`/`OpenTry`/`OpenAck`/
+    /// `OpenConfirm` events observed on `a_chain()`/`b_chain()` to completion, without the caller
+    /// having to manually retry each step. This is [`Self::handshake_reactive`] restricted to
+    /// channels whose path spans intermediate hops (`a_side.connection_hops()` is `Some`) --
+    /// single-hop channels should call `handshake_reactive` directly, since there are no
+    /// intermediate clients to refresh.
+    pub fn multihop_handshake_reactive(
+        &mut self,
+        state: State,
+        next_event: impl FnMut(Duration) -> Option<IbcEvent>,
+        poll_interval: Duration,
+    ) -> Result<(), ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        if self.a_side.connection_hops().is_none() {
+            return Err(ChannelError::missing_local_connection_hops(
+                src_channel_id.clone(),
+                self.a_side.chain_id(),
+            ));
+        }
+
+        self.handshake_reactive(state, next_event, poll_interval)
+    }
+
+    pub fn build_update_client_on_dst(&self, height: Height) -> Result<Vec<Any>, ChannelError> {
+        let client = ForeignClient::restore(
+            self.dst_client_id().clone(),
+            self.dst_chain().clone(),
+            self.src_chain().clone(),
+        );
+
+        client.wait_and_build_update_client(height).map_err(|e| {
+            ChannelError::client_operation(self.dst_client_id().clone(), self.dst_chain().id(), e)
+        })
+    }
+
+    pub fn build_update_client_on_last_hop(
+        &self,
+        height: Height,
+    ) -> Result<Vec<Any>, ChannelError> {
+        let channel_id = self
+            .a_side
+            .channel_id()
+            .ok_or(ChannelError::missing_local_channel_id())?;
+
+        let connection_hops =
+            self.a_side
+                .connection_hops()
+                .ok_or(ChannelError::missing_local_connection_hops(
+                    channel_id.clone(),
+                    self.a_side.chain_id().clone(),
+                ))?;
+
+        let last_hop = connection_hops.hops.iter().last().ok_or(
+            ChannelError::missing_local_connection_hops(
+                channel_id.clone(),
+                self.a_side.chain_id().clone(),
+            ),
+        )?;
+
+        // Get access to the registry to get or spawn chain handles
+        let registry = get_global_registry();
+
+        let last_hop_src_chain = registry
+            .get_or_spawn(&last_hop.src_chain_id)
+            .map_err(ChannelError::spawn)?;
+
+        // Restore the client hosted by the channel path's (a_side to b_side) destination chain
+        // to track the state of the penultimate chain.
+        let client = ForeignClient::restore(
+            self.dst_client_id().clone(),
+            self.dst_chain().clone(),
+            last_hop_src_chain.clone(),
+        );
+
+        client.wait_and_build_update_client(height).map_err(|e| {
+            ChannelError::client_operation(self.dst_client_id().clone(), self.dst_chain().id(), e)
+        })
+    }
+
+    pub fn build_chan_open_init(&self) -> Result<Vec<Any>, ChannelError> {
+        let signer = self
             .dst_chain()
             .get_signer()
             .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
 
         let counterparty = Counterparty::new(self.src_port_id().clone(), None);
 
-        // If the user supplied a version, use that.
-        // Otherwise, either use the version defined for the `transfer`
-        // or an empty version if the port is non-standard.
+        // If the user supplied a version, use that. Otherwise defer to a negotiator registered
+        // for this port (e.g. interchain-accounts, fee middleware), then the version defined for
+        // `transfer`, or an empty version if the port is non-standard and nothing is registered.
         let version = self
             .dst_version()
             .cloned()
+            .or_else(|| {
+                channel_version_negotiation::negotiator_for(self.dst_port_id()).map(|negotiator| {
+                    negotiator.version(&channel_version_negotiation::VersionNegotiationContext {
+                        src_port_id: self.src_port_id(),
+                        dst_port_id: self.dst_port_id(),
+                        connection_hops: self.b_side.connection_hops(),
+                        ordering: self.ordering,
+                    })
+                })
+            })
             .or_else(|| version::default_by_port(self.dst_port_id()))
             .unwrap_or_else(|| {
                 warn!(
@@ -1003,20 +1740,30 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         let counterparty =
             Counterparty::new(self.src_port_id().clone(), self.src_channel_id().cloned());
 
-        // The highest expected state, depends on the message type:
+        // The highest expected state, depends on the message type. A channel upgrade never
+        // changes the base channel `State` (it stays `Open` throughout `Upgrade{Try,Ack,Confirm,
+        // Open}` -- only the upgrade-specific fields and `upgrade_sequence` change), so every
+        // upgrade step expects to find the destination channel already `Open`.
         let highest_state = match msg_type {
             ChannelMsgType::OpenAck => State::TryOpen,
             ChannelMsgType::OpenConfirm => State::TryOpen,
             ChannelMsgType::CloseConfirm => State::Open,
+            ChannelMsgType::UpgradeTry
+            | ChannelMsgType::UpgradeAck
+            | ChannelMsgType::UpgradeConfirm
+            | ChannelMsgType::UpgradeOpen => State::Open,
             _ => State::Uninitialized,
         };
 
+        // An explicitly configured `dst_version()` is what we require the destination channel to
+        // carry; absent that, fall back to an empty version, which `check_destination_channel_state`
+        // treats as "accept whatever version the destination already settled on".
         let dst_expected_channel = ChannelEnd::new(
             highest_state,
             self.ordering,
             counterparty,
             vec![self.dst_connection_id().clone()],
-            Version::empty(),
+            self.dst_version().cloned().unwrap_or_else(Version::empty),
             0,
         );
 
@@ -1041,6 +1788,18 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
 
         check_destination_channel_state(dst_channel_id, &dst_channel, &dst_expected_channel)?;
 
+        if let Some(negotiator) = channel_version_negotiation::negotiator_for(self.dst_port_id()) {
+            negotiator.validate_counterparty(
+                &channel_version_negotiation::VersionNegotiationContext {
+                    src_port_id: self.src_port_id(),
+                    dst_port_id: self.dst_port_id(),
+                    connection_hops: self.b_side.connection_hops(),
+                    ordering: self.ordering,
+                },
+                dst_channel.version(),
+            )?;
+        }
+
         Ok(dst_expected_channel)
     }
 
@@ -1182,44 +1941,19 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
 
         let src_chain_query_height = QueryHeight::Specific(proof_heights[0].query_height());
 
-        // Query channel proof in src_chain
-        let (src_channel, maybe_channel_proof) = self
-            .src_chain()
-            .query_channel(
-                QueryChannelRequest {
-                    port_id: self.src_port_id().clone(),
-                    channel_id: src_channel_id.clone(),
-                    height: src_chain_query_height,
-                },
-                IncludeProof::Yes,
-            )
-            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
-
-        let Some(channel_proof) = maybe_channel_proof else {
-            return Err(ChannelError::queried_proof_not_found());
-        };
-
-        let channel_proof_bytes =
-            CommitmentProofBytes::try_from(channel_proof).map_err(ChannelError::malformed_proof)?;
-
-        let key_path = vec![Path::ChannelEnds(ChannelEndsPath(
-            self.src_port_id().clone(),
-            src_channel_id.clone(),
-        ))
-        .to_string()];
-
         let store_prefix = self
             .src_chain()
             .query_commitment_prefix()
             .map_err(|e| ChannelError::chain_query(self.src_chain().id(), e))?;
 
-        let prefixed_key = apply_prefix(&store_prefix, key_path);
+        let proof_builder = MultihopProofBuilder::new(store_prefix);
 
-        let key_proof = MultihopProof {
-            proof: channel_proof_bytes.into_bytes(),
-            value: src_channel.encode_vec(),
-            prefixed_key: Some(prefixed_key),
-        };
+        let key_proof = proof_builder.channel_key_proof(
+            self.src_chain(),
+            self.src_port_id(),
+            src_channel_id,
+            src_chain_query_height,
+        )?;
 
         let src_connection_id = connection_hops
             .hops
@@ -1230,125 +1964,83 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             ))?
             .connection_id();
 
-        let (src_connection, maybe_conn_proof) = self
-            .src_chain()
-            .query_connection(
-                QueryConnectionRequest {
-                    connection_id: src_connection_id.clone(),
-                    height: src_chain_query_height,
-                },
-                IncludeProof::Yes,
-            )
-            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
-
-        let Some(conn_proof) = maybe_conn_proof else {
-            return Err(ChannelError::queried_proof_not_found());
-        };
-
-        let conn_proof_bytes =
-            CommitmentProofBytes::try_from(conn_proof).map_err(ChannelError::malformed_proof)?;
-
-        // Path of connection on src_chain
-        let connection_path =
-            vec![Path::Connections(ConnectionsPath(src_connection_id.clone())).to_string()];
-
-        let prefixed_key = apply_prefix(&store_prefix, connection_path);
-
-        let src_connection_proof = MultihopProof {
-            proof: conn_proof_bytes.into_bytes(),
-            value: src_connection.encode_vec(),
-            prefixed_key: Some(prefixed_key),
-        };
+        let src_connection_proof = proof_builder.connection_proof_at(
+            self.src_chain(),
+            src_connection_id,
+            src_chain_query_height,
+        )?;
 
         let mut connection_proofs = vec![src_connection_proof];
         let mut consensus_proofs: Vec<MultihopProof> = Vec::new();
 
         let registry = get_global_registry();
+        let src_chain_id = self.src_chain().id();
+
+        // Each hop's connection/consensus-state queries only depend on `proof_heights` and
+        // `connection_hops`, which are already fixed by this point, so every hop beyond the first
+        // can be queried concurrently instead of paying N round trips of serial RPC latency.
+        // `thread::scope` lets the closures below borrow `registry`/`proof_builder` without
+        // cloning them per hop, while still guaranteeing every spawned query finishes (or fails)
+        // before `build_multihop_proofs` returns.
+        let hop_proofs: Vec<Result<(MultihopProof, MultihopProof), ChannelError>> =
+            std::thread::scope(|scope| {
+                let hop_threads: Vec<_> = proof_heights
+                    .iter()
+                    .skip(1)
+                    .zip(connection_hops.hops.iter().skip(1))
+                    .map(|(proof_height, conn_hop)| {
+                        let registry = &registry;
+                        let proof_builder = &proof_builder;
+                        let src_channel_id = src_channel_id.clone();
+                        let src_chain_id = src_chain_id.clone();
+
+                        scope.spawn(move || {
+                            let hop_src_chain = registry
+                                .get_or_spawn(&conn_hop.src_chain_id.clone())
+                                .map_err(ChannelError::spawn)?;
+
+                            let query_height = QueryHeight::Specific(proof_height.query_height());
+
+                            let hop_connection_proof = proof_builder.connection_proof_at(
+                                &hop_src_chain,
+                                conn_hop.connection_id(),
+                                query_height,
+                            )?;
+
+                            let desired_consensus_height =
+                                proof_height.consensus_height().ok_or(
+                                    ChannelError::missing_multihop_proof_heights(
+                                        src_channel_id.clone(),
+                                        src_chain_id.clone(),
+                                    ),
+                                )?;
+
+                            let hop_consensus_proof = proof_builder.consensus_proof_at(
+                                &hop_src_chain,
+                                conn_hop.connection().counterparty().client_id(),
+                                desired_consensus_height,
+                                query_height,
+                            )?;
+
+                            Ok((hop_connection_proof, hop_consensus_proof))
+                        })
+                    })
+                    .collect();
+
+                hop_threads
+                    .into_iter()
+                    .map(|handle| {
+                        handle.join().unwrap_or_else(|_| {
+                            Err(ChannelError::missing_event(
+                                "a multihop proof query thread panicked".to_string(),
+                            ))
+                        })
+                    })
+                    .collect()
+            });
 
-        for (proof_height, conn_hop) in proof_heights
-            .iter()
-            .skip(1)
-            .zip(connection_hops.hops.iter().skip(1))
-        {
-            let hop_src_chain = registry
-                .get_or_spawn(&conn_hop.src_chain_id.clone())
-                .map_err(ChannelError::spawn)?;
-
-            let query_height = QueryHeight::Specific(proof_height.query_height());
-
-            let (hop_connection, maybe_conn_proof) = hop_src_chain
-                .query_connection(
-                    QueryConnectionRequest {
-                        connection_id: conn_hop.connection_id().clone(),
-                        height: query_height,
-                    },
-                    IncludeProof::Yes,
-                )
-                .map_err(|e| ChannelError::query(hop_src_chain.id(), e))?;
-
-            let Some(conn_proof) = maybe_conn_proof else {
-                return Err(ChannelError::queried_proof_not_found());
-            };
-
-            let conn_proof_bytes = CommitmentProofBytes::try_from(conn_proof)
-                .map_err(ChannelError::malformed_proof)?;
-
-            // Path of connection on hop_src_chain
-            let connection_path =
-                vec![
-                    Path::Connections(ConnectionsPath(conn_hop.connection_id().clone()))
-                        .to_string(),
-                ];
-
-            let prefixed_key = apply_prefix(&store_prefix, connection_path);
-
-            let hop_connection_proof = MultihopProof {
-                proof: conn_proof_bytes.into_bytes(),
-                value: hop_connection.encode_vec(),
-                prefixed_key: Some(prefixed_key),
-            };
-
-            let desired_consensus_height = proof_height.consensus_height().ok_or(
-                ChannelError::missing_multihop_proof_heights(
-                    src_channel_id.clone(),
-                    self.src_chain().id(),
-                ),
-            )?;
-
-            let (consensus_state, maybe_consensus_state_proof) = hop_src_chain
-                .query_consensus_state(
-                    QueryConsensusStateRequest {
-                        client_id: conn_hop.connection().counterparty().client_id().clone(),
-                        consensus_height: desired_consensus_height.clone(),
-                        query_height,
-                    },
-                    IncludeProof::Yes,
-                )
-                .map_err(|e| ChannelError::query(hop_src_chain.id(), e))?;
-
-            let Some(consensus_state_proof) = maybe_consensus_state_proof else {
-                return Err(ChannelError::queried_proof_not_found());
-            };
-
-            let consensus_state_proof_bytes = CommitmentProofBytes::try_from(consensus_state_proof)
-                .map_err(ChannelError::malformed_proof)?;
-
-            // Path of consensus state on hop_src_chain
-            let consensus_state_path = vec![Path::ClientConsensusState(ClientConsensusStatePath {
-                client_id: conn_hop.connection().counterparty().client_id().clone(),
-                epoch: desired_consensus_height.revision_number(),
-                height: desired_consensus_height.revision_height(),
-            })
-            .to_string()];
-
-            let prefixed_key = apply_prefix(&store_prefix, consensus_state_path);
-
-            let hop_consensus_proof = MultihopProof {
-                proof: consensus_state_proof_bytes.into_bytes(),
-                value: consensus_state.encode_vec(),
-                prefixed_key: Some(prefixed_key),
-            };
-
+        for hop_proof in hop_proofs {
+            let (hop_connection_proof, hop_consensus_proof) = hop_proof?;
             connection_proofs.push(hop_connection_proof);
             consensus_proofs.push(hop_consensus_proof);
         }
@@ -1362,6 +2054,76 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         })
     }
 
+    /// Locally replays ICS-23 membership verification over a [`MsgMultihopProofs`] assembled by
+    /// [`Self::build_multihop_proofs`], so a malformed or stale proof is caught here instead of
+    /// only being discovered after `dst_chain()` rejects the submitted message. `verified_height`
+    /// is the height at which `dst_chain()`'s client for the penultimate chain in the path was
+    /// just updated (the same height passed to [`Self::build_update_client_on_last_hop`]), used to
+    /// seed the walk with the consensus state that light client already holds.
+    ///
+    /// `connection_proofs`/`consensus_proofs` are stored "inward" — ordered from the destination
+    /// side towards the source, per [`Self::build_multihop_proofs`]'s trailing `.reverse()` — so
+    /// this walks them in that same order: each hop's connection proof is checked against the
+    /// current root, then (for every hop but the last) the accompanying consensus proof is
+    /// checked and decoded to produce the root for the next hop. The channel end itself
+    /// (`key_proof`) is verified last, against the root derived from the final hop.
+    pub fn verify_multihop_proofs(
+        &self,
+        multihop_proofs: &MsgMultihopProofs,
+        verified_height: Height,
+    ) -> Result<(), ChannelError> {
+        let (consensus_state, _) = self
+            .dst_chain()
+            .query_consensus_state(
+                QueryConsensusStateRequest {
+                    client_id: self.dst_client_id().clone(),
+                    consensus_height: verified_height,
+                    query_height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        let mut current_root = consensus_state.root().clone();
+
+        let hop_count = multihop_proofs.connection_proofs.len();
+
+        for (i, connection_proof) in multihop_proofs.connection_proofs.iter().enumerate() {
+            verify_multihop_proof(connection_proof, &current_root, i)?;
+
+            if i + 1 < hop_count {
+                let consensus_proof =
+                    multihop_proofs
+                        .consensus_proofs
+                        .get(i)
+                        .ok_or_else(|| {
+                            ChannelError::missing_event(format!(
+                                "multihop proofs for {self} are missing a consensus proof for hop {i}"
+                            ))
+                        })?;
+
+                verify_multihop_proof(consensus_proof, &current_root, i)?;
+
+                let next_consensus_state = AnyConsensusState::decode_vec(&consensus_proof.value)
+                    .map_err(|e| {
+                        ChannelError::missing_event(format!(
+                            "failed to decode consensus state proven at hop {i} for {self}: {e}"
+                        ))
+                    })?;
+
+                current_root = next_consensus_state.root().clone();
+            }
+        }
+
+        let key_proof = multihop_proofs.key_proof.as_ref().ok_or_else(|| {
+            ChannelError::missing_event(format!(
+                "multihop proofs for {self} are missing the channel end proof"
+            ))
+        })?;
+
+        verify_multihop_proof(key_proof, &current_root, hop_count)
+    }
+
     pub fn build_multihop_chan_open_try(&self) -> Result<Vec<Any>, ChannelError> {
         // Source channel ID must be specified
         let src_channel_id = self
@@ -1410,16 +2172,6 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             )
             .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
 
-        let query_height = self
-            .src_chain()
-            .query_latest_height()
-            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
-
-        // let proofs = self
-        //     .src_chain()
-        //     .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
-        //     .map_err(ChannelError::channel_proof)?;
-
         // Update the clients along the channel path and store the heights necessary for querying
         // multihop proofs. 'proof_heights' contains the height at which proofs should be queried,
         // ordered from the sending chain to the penultimate chain in the channel path. In order to
@@ -1428,6 +2180,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         // for height 'query_height + 1'.
         let proof_heights = self.update_channel_path_clients()?;
 
+        validate_multihop_proof_heights(&proof_heights, src_channel_id, self.src_chain().id())?;
+
         // Get the multihop proof heights for the chain from which the last hop originates, i.e,
         // the penultimate chain in the channel path.
         let last_hop_heights =
@@ -1446,12 +2200,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             self.build_update_client_on_last_hop(last_hop_heights.query_height().increment())?;
 
         let multihop_proofs = self.build_multihop_proofs(&proof_heights)?;
+        self.verify_multihop_proofs(&multihop_proofs, last_hop_heights.query_height().increment())?;
 
         let multihop_proof_bytes = prost::Message::encode_to_vec(&multihop_proofs);
 
-        // let multihop_proof_bytes = prost::Message::encode_to_vec(multihop_proofs).unwrap();
-        // --------- IN PROGRESS BELOW --------- //
-
         let counterparty =
             Counterparty::new(self.src_port_id().clone(), self.src_channel_id().cloned());
 
@@ -1459,23 +2211,32 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         let version = src_channel.version().clone();
 
         let proofs = ibc_relayer_types::proofs::Proofs::new(
-            CommitmentProofBytes::try_from(multihop_proof_bytes).unwrap(),
+            CommitmentProofBytes::try_from(multihop_proof_bytes)
+                .map_err(ChannelError::malformed_proof)?,
             None,
             None,
             None,
             None,
-            // proof_heights[0].query_height().increment(),
             last_hop_heights.query_height(),
         )
-        .unwrap(); // FIXME
+        .map_err(|_| {
+            ChannelError::missing_event("failed to assemble multihop proofs".to_string())
+        })?;
 
-        println!("\n\n\n {:?} \n\n\n", proofs);
+        // The ChannelEnd being opened lives on dst_chain, so its `connection_hops` must list the
+        // full path as dst_chain sees it (dst-adjacent connection first), not just the single
+        // hop `dst_connection_id` identifies.
+        let connection_hops = self
+            .b_side
+            .connection_hops()
+            .map(|hops| hops.connection_ids())
+            .unwrap_or_else(|| vec![self.dst_connection_id().clone()]);
 
         let channel = ChannelEnd::new(
             State::TryOpen,
             *src_channel.ordering(),
             counterparty,
-            vec![self.dst_connection_id().clone()],
+            connection_hops,
             version,
             0,
         );
@@ -1713,27 +2474,123 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         Ok(msgs)
     }
 
-    pub fn build_chan_open_ack_and_send(&self) -> Result<IbcEvent, ChannelError> {
-        fn do_build_chan_open_ack_and_send<ChainA: ChainHandle, ChainB: ChainHandle>(
-            channel: &Channel<ChainA, ChainB>,
-        ) -> Result<IbcEvent, ChannelError> {
-            let dst_msgs = channel.build_chan_open_ack()?;
-
-            let tm = TrackedMsgs::new_static(dst_msgs, "ChannelOpenAck");
+    /// Multihop counterpart of [`Self::build_chan_open_ack`]: proves the source channel's
+    /// `TryOpen` state with a [`MsgMultihopProofs`] assembled the same way
+    /// [`Self::build_multihop_chan_open_try`] assembles its proof, across the connection path
+    /// recorded in `a_side.connection_hops`.
+    pub fn build_multihop_chan_open_ack(&self) -> Result<Vec<Any>, ChannelError> {
+        // Source and destination channel IDs must be specified
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
 
-            let events = channel
-                .dst_chain()
-                .send_messages_and_wait_commit(tm)
-                .map_err(|e| ChannelError::submit(channel.dst_chain().id(), e))?;
+        // Check that the destination chain will accept the Ack message
+        self.validated_expected_channel(ChannelMsgType::OpenAck)?;
 
-            // Find the relevant event for channel open ack
-            let result = events
-                .into_iter()
-                .find(|event_with_height| {
-                    matches!(event_with_height.event, IbcEvent::OpenAckChannel(_))
-                        || matches!(event_with_height.event, IbcEvent::ChainError(_))
-                })
-                .ok_or_else(|| {
+        // Channel must exist on source
+        let (src_channel, _) = self
+            .src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        // Connection must exist on destination
+        self.dst_chain()
+            .query_connection(
+                QueryConnectionRequest {
+                    connection_id: self.dst_connection_id().clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        let proof_heights = self.update_channel_path_clients()?;
+
+        validate_multihop_proof_heights(&proof_heights, src_channel_id, self.src_chain().id())?;
+
+        let last_hop_heights =
+            proof_heights
+                .last()
+                .ok_or(ChannelError::missing_multihop_proof_heights(
+                    src_channel_id.clone(),
+                    self.src_chain().id(),
+                ))?;
+
+        let mut msgs =
+            self.build_update_client_on_last_hop(last_hop_heights.query_height().increment())?;
+
+        let multihop_proofs = self.build_multihop_proofs(&proof_heights)?;
+        self.verify_multihop_proofs(&multihop_proofs, last_hop_heights.query_height().increment())?;
+        let multihop_proof_bytes = prost::Message::encode_to_vec(&multihop_proofs);
+
+        let proofs = ibc_relayer_types::proofs::Proofs::new(
+            CommitmentProofBytes::try_from(multihop_proof_bytes)
+                .map_err(ChannelError::malformed_proof)?,
+            None,
+            None,
+            None,
+            None,
+            last_hop_heights.query_height(),
+        )
+        .map_err(|_| {
+            ChannelError::missing_event("failed to assemble multihop proofs".to_string())
+        })?;
+
+        // Get signer
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        // Build the domain type message
+        let new_msg = MsgChannelOpenAck {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            counterparty_channel_id: src_channel_id.clone(),
+            counterparty_version: src_channel.version().clone(),
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_open_ack_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        fn do_build_chan_open_ack_and_send<ChainA: ChainHandle, ChainB: ChainHandle>(
+            channel: &Channel<ChainA, ChainB>,
+        ) -> Result<IbcEvent, ChannelError> {
+            let dst_msgs = if channel.a_side.connection_hops.is_some() {
+                channel.build_multihop_chan_open_ack()?
+            } else {
+                channel.build_chan_open_ack()?
+            };
+
+            let tm = TrackedMsgs::new_static(dst_msgs, "ChannelOpenAck");
+
+            let events = channel
+                .dst_chain()
+                .send_messages_and_wait_commit(tm)
+                .map_err(|e| ChannelError::submit(channel.dst_chain().id(), e))?;
+
+            // Find the relevant event for channel open ack
+            let result = events
+                .into_iter()
+                .find(|event_with_height| {
+                    matches!(event_with_height.event, IbcEvent::OpenAckChannel(_))
+                        || matches!(event_with_height.event, IbcEvent::ChainError(_))
+                })
+                .ok_or_else(|| {
                     ChannelError::missing_event("no chan ack event was in the response".to_string())
                 })?;
 
@@ -1819,11 +2676,104 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         Ok(msgs)
     }
 
+    /// Multihop counterpart of [`Self::build_chan_open_confirm`]: proves the source channel's
+    /// `Open` state with a [`MsgMultihopProofs`] assembled the same way
+    /// [`Self::build_multihop_chan_open_try`] assembles its proof, across the connection path
+    /// recorded in `a_side.connection_hops`.
+    pub fn build_multihop_chan_open_confirm(&self) -> Result<Vec<Any>, ChannelError> {
+        // Source and destination channel IDs must be specified
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        // Check that the destination chain will accept the message
+        self.validated_expected_channel(ChannelMsgType::OpenConfirm)?;
+
+        // Channel must exist on source
+        self.src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        // Connection must exist on destination
+        self.dst_chain()
+            .query_connection(
+                QueryConnectionRequest {
+                    connection_id: self.dst_connection_id().clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        let proof_heights = self.update_channel_path_clients()?;
+
+        validate_multihop_proof_heights(&proof_heights, src_channel_id, self.src_chain().id())?;
+
+        let last_hop_heights =
+            proof_heights
+                .last()
+                .ok_or(ChannelError::missing_multihop_proof_heights(
+                    src_channel_id.clone(),
+                    self.src_chain().id(),
+                ))?;
+
+        let mut msgs =
+            self.build_update_client_on_last_hop(last_hop_heights.query_height().increment())?;
+
+        let multihop_proofs = self.build_multihop_proofs(&proof_heights)?;
+        self.verify_multihop_proofs(&multihop_proofs, last_hop_heights.query_height().increment())?;
+        let multihop_proof_bytes = prost::Message::encode_to_vec(&multihop_proofs);
+
+        let proofs = ibc_relayer_types::proofs::Proofs::new(
+            CommitmentProofBytes::try_from(multihop_proof_bytes)
+                .map_err(ChannelError::malformed_proof)?,
+            None,
+            None,
+            None,
+            None,
+            last_hop_heights.query_height(),
+        )
+        .map_err(|_| {
+            ChannelError::missing_event("failed to assemble multihop proofs".to_string())
+        })?;
+
+        // Get signer
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        // Build the domain type message
+        let new_msg = MsgChannelOpenConfirm {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
     pub fn build_chan_open_confirm_and_send(&self) -> Result<IbcEvent, ChannelError> {
         fn do_build_chan_open_confirm_and_send<ChainA: ChainHandle, ChainB: ChainHandle>(
             channel: &Channel<ChainA, ChainB>,
         ) -> Result<IbcEvent, ChannelError> {
-            let dst_msgs = channel.build_chan_open_confirm()?;
+            let dst_msgs = if channel.a_side.connection_hops.is_some() {
+                channel.build_multihop_chan_open_confirm()?
+            } else {
+                channel.build_chan_open_confirm()?
+            };
 
             let tm = TrackedMsgs::new_static(dst_msgs, "ChannelOpenConfirm");
             let events = channel
@@ -1991,8 +2941,102 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         Ok(msgs)
     }
 
+    /// Multihop counterpart of [`Self::build_chan_close_confirm`]: proves the source channel's
+    /// `Closed` state with a [`MsgMultihopProofs`] assembled the same way
+    /// [`Self::build_multihop_chan_open_try`] assembles its proof, across the connection path
+    /// recorded in `a_side.connection_hops`.
+    pub fn build_multihop_chan_close_confirm(&self) -> Result<Vec<Any>, ChannelError> {
+        // Source and destination channel IDs must be specified
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        // Check that the destination chain will accept the message
+        self.validated_expected_channel(ChannelMsgType::CloseConfirm)?;
+
+        // Channel must exist on source
+        self.src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        // Connection must exist on destination
+        self.dst_chain()
+            .query_connection(
+                QueryConnectionRequest {
+                    connection_id: self.dst_connection_id().clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        let proof_heights = self.update_channel_path_clients()?;
+
+        validate_multihop_proof_heights(&proof_heights, src_channel_id, self.src_chain().id())?;
+
+        let last_hop_heights =
+            proof_heights
+                .last()
+                .ok_or(ChannelError::missing_multihop_proof_heights(
+                    src_channel_id.clone(),
+                    self.src_chain().id(),
+                ))?;
+
+        let mut msgs =
+            self.build_update_client_on_last_hop(last_hop_heights.query_height().increment())?;
+
+        let multihop_proofs = self.build_multihop_proofs(&proof_heights)?;
+        self.verify_multihop_proofs(&multihop_proofs, last_hop_heights.query_height().increment())?;
+        let multihop_proof_bytes = prost::Message::encode_to_vec(&multihop_proofs);
+
+        let proofs = ibc_relayer_types::proofs::Proofs::new(
+            CommitmentProofBytes::try_from(multihop_proof_bytes)
+                .map_err(ChannelError::malformed_proof)?,
+            None,
+            None,
+            None,
+            None,
+            last_hop_heights.query_height(),
+        )
+        .map_err(|_| {
+            ChannelError::missing_event("failed to assemble multihop proofs".to_string())
+        })?;
+
+        // Get signer
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        // Build the domain type message
+        let new_msg = MsgChannelCloseConfirm {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            proofs,
+            signer,
+            counterparty_upgrade_sequence: 0,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
     pub fn build_chan_close_confirm_and_send(&self) -> Result<IbcEvent, ChannelError> {
-        let dst_msgs = self.build_chan_close_confirm()?;
+        let dst_msgs = if self.a_side.connection_hops.is_some() {
+            self.build_multihop_chan_close_confirm()?
+        } else {
+            self.build_chan_close_confirm()?
+        };
 
         let tm = TrackedMsgs::new_static(dst_msgs, "ChannelCloseConfirm");
 
@@ -2022,8 +3066,520 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         }
     }
 
-    pub fn map_chain<ChainC: ChainHandle, ChainD: ChainHandle>(
-        self,
+    /// Propose a channel upgrade: renegotiate this channel's version, ordering
+    /// and/or connection hops without tearing it down.
+    ///
+    /// Each attempt queries the source channel end's state and submits one upgrade message to
+    /// the destination: `ChanUpgradeTry` when that state is `Init`, `ChanUpgradeAck` when it is
+    /// `TryOpen`, and `ChanUpgradeInit` when it is `Open`; in any other state nothing is
+    /// submitted. `ChanUpgradeConfirm` and `ChanUpgradeOpen` are not submitted here, and the
+    /// upgrade sequences and flush states of the two ends are not queried. A failed attempt is
+    /// retried with the [`channel_handshake_retry`] strategy unless the error is an expired or
+    /// frozen client; the call returns after the first attempt that does not fail.
+    pub fn upgrade(&mut self, attrs: ChannelUpgradeAttributes) -> Result<(), ChannelError> {
+        self.upgrade_attrs = Some(attrs);
+
+        let max_block_times = self.max_block_times()?;
+        let retry_config = self.handshake_retry_config();
+
+        retry_with_index(
+            channel_handshake_retry::default_strategy(max_block_times, retry_config),
+            |_| {
+                if let Err(e) = self.do_chan_upgrade_handshake() {
+                    if e.is_expired_or_frozen_error() {
+                        RetryResult::Err(e)
+                    } else {
+                        RetryResult::Retry(e)
+                    }
+                } else {
+                    RetryResult::Ok(())
+                }
+            },
+        )
+        .map_err(|err| {
+            error!("failed to upgrade channel after {} retries", err.tries);
+
+            channel_handshake_retry::from_retry_error(
+                err,
+                format!("failed to finish channel upgrade handshake for {self:?}"),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    fn do_chan_upgrade_handshake(&mut self) -> Result<(), ChannelError> {
+        let src_channel_state = *self
+            .src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: self
+                        .src_channel_id()
+                        .ok_or_else(ChannelError::missing_local_channel_id)?
+                        .clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?
+            .0
+            .state();
+
+        match src_channel_state {
+            State::Init => {
+                self.build_chan_upgrade_try_and_send()?;
+            }
+            State::TryOpen => {
+                self.build_chan_upgrade_ack_and_send()?;
+            }
+            State::Open if self.upgrade_attrs.is_some() => {
+                self.build_chan_upgrade_init_and_send()?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn upgrade_attrs(&self) -> Result<&ChannelUpgradeAttributes, ChannelError> {
+        self.upgrade_attrs
+            .as_ref()
+            .ok_or_else(ChannelError::missing_channel_upgrade_attributes)
+    }
+
+    pub fn build_chan_upgrade_init(&self) -> Result<Vec<Any>, ChannelError> {
+        let attrs = self.upgrade_attrs()?;
+
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        let counterparty = Counterparty::new(self.src_port_id().clone(), self.src_channel_id().cloned());
+
+        let proposed_channel = ChannelEnd::new(
+            State::Init,
+            attrs.ordering,
+            counterparty,
+            attrs.connection_hops.clone(),
+            attrs.version.clone(),
+            0,
+        );
+
+        let new_msg = MsgChannelUpgradeInit {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            proposed_upgrade_channel: proposed_channel,
+            signer,
+        };
+
+        Ok(vec![new_msg.to_any()])
+    }
+
+    pub fn build_chan_upgrade_init_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_init()?;
+        let tm = TrackedMsgs::new_static(dst_msgs, "ChannelUpgradeInit");
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events.into_iter().last().ok_or_else(|| {
+            ChannelError::missing_event("no chan upgrade init event was in the response".to_string())
+        })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            _ => {
+                info!("🎊  {} => upgrade proposed on {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+        }
+    }
+
+    pub fn build_chan_upgrade_try(&self) -> Result<Vec<Any>, ChannelError> {
+        self.validated_expected_channel(ChannelMsgType::UpgradeTry)?;
+
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self
+            .src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proofs = self
+            .src_chain()
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map_err(ChannelError::channel_proof)?;
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        let new_msg = MsgChannelUpgradeTry {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            counterparty_upgrade_sequence: 0,
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_try_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_try()?;
+        let tm = TrackedMsgs::new_static(dst_msgs, "ChannelUpgradeTry");
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events
+            .into_iter()
+            .last()
+            .ok_or_else(|| {
+                ChannelError::missing_event("no chan upgrade try event was in the response".to_string())
+            })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            _ => {
+                info!("🎊  {} => upgrade try'd on {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+        }
+    }
+
+    pub fn build_chan_upgrade_ack(&self) -> Result<Vec<Any>, ChannelError> {
+        self.validated_expected_channel(ChannelMsgType::UpgradeAck)?;
+
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self
+            .src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proofs = self
+            .src_chain()
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map_err(ChannelError::channel_proof)?;
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        let new_msg = MsgChannelUpgradeAck {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_ack_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_ack()?;
+        let tm = TrackedMsgs::new_static(dst_msgs, "ChannelUpgradeAck");
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events
+            .into_iter()
+            .last()
+            .ok_or_else(|| {
+                ChannelError::missing_event("no chan upgrade ack event was in the response".to_string())
+            })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            _ => {
+                info!("🎊  {} => upgrade ack'd on {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+        }
+    }
+
+    pub fn build_chan_upgrade_confirm(&self) -> Result<Vec<Any>, ChannelError> {
+        self.validated_expected_channel(ChannelMsgType::UpgradeConfirm)?;
+
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self
+            .src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proofs = self
+            .src_chain()
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map_err(ChannelError::channel_proof)?;
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        let new_msg = MsgChannelUpgradeConfirm {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_confirm_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_confirm()?;
+        let tm = TrackedMsgs::new_static(dst_msgs, "ChannelUpgradeConfirm");
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events
+            .into_iter()
+            .last()
+            .ok_or_else(|| {
+                ChannelError::missing_event("no chan upgrade confirm event was in the response".to_string())
+            })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            _ => {
+                info!("🎊  {} => channel upgrade completed on {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+        }
+    }
+
+    /// Final step of a successful upgrade: once both sides have observed each
+    /// other flush their in-flight packets, this tells the destination chain
+    /// it may swap the channel's fields over to the proposed upgrade and
+    /// transition back to `Open`.
+    pub fn build_chan_upgrade_open(&self) -> Result<Vec<Any>, ChannelError> {
+        self.validated_expected_channel(ChannelMsgType::UpgradeOpen)?;
+
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self
+            .src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proofs = self
+            .src_chain()
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map_err(ChannelError::channel_proof)?;
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        let new_msg = MsgChannelUpgradeOpen {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_open_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_open()?;
+        let tm = TrackedMsgs::new_static(dst_msgs, "ChannelUpgradeOpen");
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events.into_iter().last().ok_or_else(|| {
+            ChannelError::missing_event("no chan upgrade open event was in the response".to_string())
+        })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            _ => {
+                info!("🎊  {} => channel upgrade opened on {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+        }
+    }
+
+    /// Abort path: tells the destination chain to cancel an in-flight upgrade
+    /// proposal, e.g. because this side detected the proposed upgrade is invalid.
+    pub fn build_chan_upgrade_cancel(&self) -> Result<Vec<Any>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self
+            .src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proofs = self
+            .src_chain()
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map_err(ChannelError::channel_proof)?;
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        let new_msg = MsgChannelUpgradeCancel {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_cancel_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_cancel()?;
+        let tm = TrackedMsgs::new_static(dst_msgs, "ChannelUpgradeCancel");
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events.into_iter().last().ok_or_else(|| {
+            ChannelError::missing_event("no chan upgrade cancel event was in the response".to_string())
+        })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            _ => {
+                info!("🎊  {} => upgrade cancelled on {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+        }
+    }
+
+    /// Abort path: submitted by either side once the counterparty has failed
+    /// to flush in-flight packets before `upgrade_attrs.timeout_height`/
+    /// `timeout_timestamp`, reverting the channel back to its pre-upgrade fields.
+    pub fn build_chan_upgrade_timeout(&self) -> Result<Vec<Any>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self
+            .src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proofs = self
+            .src_chain()
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map_err(ChannelError::channel_proof)?;
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        let new_msg = MsgChannelUpgradeTimeout {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id.clone(),
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_timeout_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_timeout()?;
+        let tm = TrackedMsgs::new_static(dst_msgs, "ChannelUpgradeTimeout");
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events.into_iter().last().ok_or_else(|| {
+            ChannelError::missing_event("no chan upgrade timeout event was in the response".to_string())
+        })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            _ => {
+                info!("🎊  {} => upgrade timed out on {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+        }
+    }
+
+    pub fn map_chain<ChainC: ChainHandle, ChainD: ChainHandle>(
+        self,
         mapper_a: impl Fn(ChainA) -> ChainC,
         mapper_b: impl Fn(ChainB) -> ChainD,
     ) -> Channel<ChainC, ChainD> {
@@ -2032,6 +3588,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             a_side: self.a_side.map_chain(mapper_a),
             b_side: self.b_side.map_chain(mapper_b),
             connection_delay: self.connection_delay,
+            upgrade_attrs: self.upgrade_attrs,
         }
     }
 }
@@ -2054,6 +3611,230 @@ pub enum ChannelMsgType {
     OpenAck,
     OpenConfirm,
     CloseConfirm,
+    UpgradeTry,
+    UpgradeAck,
+    UpgradeConfirm,
+    UpgradeOpen,
+}
+
+/// Maps an observed channel event to the handshake [`State`] it represents having reached,
+/// mirroring the `(state, counterparty_state())` table in [`Channel::handshake_step`]. Shared by
+/// [`Channel::step_event`] and [`Channel::handshake_reactive`] so the two don't drift apart.
+fn channel_handshake_state_for_event(event: &IbcEvent) -> State {
+    match event {
+        IbcEvent::OpenInitChannel(_) => State::Init,
+        IbcEvent::OpenTryChannel(_) => State::TryOpen,
+        IbcEvent::OpenAckChannel(_) => State::Open,
+        IbcEvent::OpenConfirmChannel(_) => State::Open,
+        IbcEvent::CloseInitChannel(_) => State::Closed,
+        _ => State::Uninitialized,
+    }
+}
+
+/// Returns `true` if a chain error returned in response to a handshake-step message looks like
+/// it was caused by a competing relayer having already landed that exact step (e.g. the channel
+/// end is already past the state our message assumed), rather than a genuine failure. Used by
+/// [`Channel::reconcile_step_error`] to decide whether to re-check progress instead of failing
+/// outright.
+fn channel_error_indicates_already_advanced(e: &ChannelError) -> bool {
+    let message = e.to_string().to_lowercase();
+
+    message.contains("already exists")
+        || message.contains("already open")
+        || message.contains("unexpected channel state")
+        || message.contains("invalid channel state")
+}
+
+/// Builds individual [`MultihopProof`] entries for a multihop channel path, centralizing the
+/// ICS-24 path construction and commitment-prefix application that
+/// [`Channel::build_multihop_proofs`] previously hand-rolled separately for each proof kind
+/// (channel end, connection end, consensus state). All three kinds share the same store prefix,
+/// so one builder is constructed per [`Channel::build_multihop_proofs`] call and reused across
+/// every hop.
+struct MultihopProofBuilder {
+    store_prefix: CommitmentPrefix,
+}
+
+impl MultihopProofBuilder {
+    fn new(store_prefix: CommitmentPrefix) -> Self {
+        Self { store_prefix }
+    }
+
+    /// Proves a `ChannelEnd` identified by `port_id`/`channel_id` on `chain` at `height`.
+    fn channel_key_proof<Chain: ChainHandle>(
+        &self,
+        chain: &Chain,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        height: QueryHeight,
+    ) -> Result<MultihopProof, ChannelError> {
+        let (channel_end, maybe_proof) = chain
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                    height,
+                },
+                IncludeProof::Yes,
+            )
+            .map_err(|e| ChannelError::query(chain.id(), e))?;
+
+        let Some(proof) = maybe_proof else {
+            return Err(ChannelError::queried_proof_not_found());
+        };
+
+        let proof_bytes =
+            CommitmentProofBytes::try_from(proof).map_err(ChannelError::malformed_proof)?;
+
+        let path =
+            vec![Path::ChannelEnds(ChannelEndsPath(port_id.clone(), channel_id.clone())).to_string()];
+
+        Ok(MultihopProof {
+            proof: proof_bytes.into_bytes(),
+            value: channel_end.encode_vec(),
+            prefixed_key: Some(apply_prefix(&self.store_prefix, path)),
+        })
+    }
+
+    /// Proves a `ConnectionEnd` identified by `connection_id` on `chain` at `height`.
+    fn connection_proof_at<Chain: ChainHandle>(
+        &self,
+        chain: &Chain,
+        connection_id: &ConnectionId,
+        height: QueryHeight,
+    ) -> Result<MultihopProof, ChannelError> {
+        let (connection, maybe_proof) = chain
+            .query_connection(
+                QueryConnectionRequest {
+                    connection_id: connection_id.clone(),
+                    height,
+                },
+                IncludeProof::Yes,
+            )
+            .map_err(|e| ChannelError::query(chain.id(), e))?;
+
+        let Some(proof) = maybe_proof else {
+            return Err(ChannelError::queried_proof_not_found());
+        };
+
+        let proof_bytes =
+            CommitmentProofBytes::try_from(proof).map_err(ChannelError::malformed_proof)?;
+
+        let path = vec![Path::Connections(ConnectionsPath(connection_id.clone())).to_string()];
+
+        Ok(MultihopProof {
+            proof: proof_bytes.into_bytes(),
+            value: connection.encode_vec(),
+            prefixed_key: Some(apply_prefix(&self.store_prefix, path)),
+        })
+    }
+
+    /// Proves the consensus state `client_id` holds for `consensus_height`, queried from `chain`
+    /// at `query_height`.
+    fn consensus_proof_at<Chain: ChainHandle>(
+        &self,
+        chain: &Chain,
+        client_id: &ClientId,
+        consensus_height: Height,
+        query_height: QueryHeight,
+    ) -> Result<MultihopProof, ChannelError> {
+        let (consensus_state, maybe_proof) = chain
+            .query_consensus_state(
+                QueryConsensusStateRequest {
+                    client_id: client_id.clone(),
+                    consensus_height: consensus_height.clone(),
+                    query_height,
+                },
+                IncludeProof::Yes,
+            )
+            .map_err(|e| ChannelError::query(chain.id(), e))?;
+
+        let Some(proof) = maybe_proof else {
+            return Err(ChannelError::queried_proof_not_found());
+        };
+
+        let proof_bytes =
+            CommitmentProofBytes::try_from(proof).map_err(ChannelError::malformed_proof)?;
+
+        let path = vec![Path::ClientConsensusState(ClientConsensusStatePath {
+            client_id: client_id.clone(),
+            epoch: consensus_height.revision_number(),
+            height: consensus_height.revision_height(),
+        })
+        .to_string()];
+
+        Ok(MultihopProof {
+            proof: proof_bytes.into_bytes(),
+            value: consensus_state.encode_vec(),
+            prefixed_key: Some(apply_prefix(&self.store_prefix, path)),
+        })
+    }
+}
+
+/// Sanity-checks the [`MultihopProofHeights`] computed by
+/// [`Channel::update_channel_path_clients`] before they are used to assemble
+/// [`MsgMultihopProofs`](ibc_proto::ibc::core::channel::v1::MsgMultihopProofs):
+/// every intermediate chain's recorded consensus-state height must be at
+/// least the proof height of the chain it attests to, i.e. hop `i+1`'s
+/// `consensus_height` must not be older than hop `i`'s `query_height`. This
+/// catches a stale client update (or a reordered hop list) before we submit
+/// an unverifiable proof on-chain.
+fn validate_multihop_proof_heights(
+    proof_heights: &[MultihopProofHeights],
+    channel_id: &ChannelId,
+    chain_id: ChainId,
+) -> Result<(), ChannelError> {
+    for pair in proof_heights.windows(2) {
+        let (attested, attesting) = (&pair[0], &pair[1]);
+
+        let consensus_height = attesting.consensus_height().ok_or_else(|| {
+            ChannelError::missing_multihop_proof_heights(channel_id.clone(), chain_id.clone())
+        })?;
+
+        if consensus_height < attested.query_height() {
+            return Err(ChannelError::missing_multihop_proof_heights(
+                channel_id.clone(),
+                chain_id,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Verifies a single [`MultihopProof`] entry's ICS-23 membership proof against `root`, naming
+/// `hop` (the entry's position in the path, counting from the destination) in any resulting
+/// error. Shared by every call site in [`Channel::verify_multihop_proofs`].
+fn verify_multihop_proof(
+    proof: &MultihopProof,
+    root: &CommitmentRoot,
+    hop: usize,
+) -> Result<(), ChannelError> {
+    let prefixed_key = proof.prefixed_key.clone().ok_or_else(|| {
+        ChannelError::missing_event(format!(
+            "multihop proof for hop {hop} is missing its prefixed key"
+        ))
+    })?;
+
+    let commitment_proof_bytes = CommitmentProofBytes::try_from(proof.proof.clone())
+        .map_err(ChannelError::malformed_proof)?;
+
+    let merkle_proof =
+        MerkleProof::try_from(commitment_proof_bytes).map_err(ChannelError::malformed_proof)?;
+
+    merkle_proof
+        .verify_membership(
+            &ProofSpecs::cosmos(),
+            root.clone(),
+            prefixed_key,
+            proof.value.clone(),
+            0,
+        )
+        .map_err(ChannelError::malformed_proof)
+        .map_err(|e| {
+            error!("multihop proof verification failed at hop {hop}: {e}");
+            e
+        })
 }
 
 fn check_destination_channel_state(
@@ -2072,11 +3853,47 @@ fn check_destination_channel_state(
             && existing_channel.counterparty().port_id()
                 == expected_channel.counterparty().port_id();
 
-    // TODO: Check versions
+    if !good_state || !good_connection_hops || !good_channel_port_ids {
+        return Err(ChannelError::channel_already_exist(channel_id.clone()));
+    }
 
-    if good_state && good_connection_hops && good_channel_port_ids {
-        Ok(())
-    } else {
-        Err(ChannelError::channel_already_exist(channel_id.clone()))
+    check_channel_versions_compatible(expected_channel.version(), existing_channel.version())
+}
+
+/// Checks `found` -- the version an existing destination `ChannelEnd` already carries -- against
+/// `expected`, an empty version meaning "unset", i.e. accept whatever the destination settled on.
+/// A non-empty `expected` requires `found` to be compatible with it, where compatibility means
+/// either exact equality or, for versions that embed structured metadata alongside the app
+/// version (e.g. a JSON middleware wrapper like fee-enabled
+/// `{"fee_version":"ics29-1","app_version":"ics20-1"}`), that the app-layer version matches once
+/// parsed out of both sides.
+fn check_channel_versions_compatible(expected: &Version, found: &Version) -> Result<(), ChannelError> {
+    if expected == &Version::empty() || expected == found {
+        return Ok(());
+    }
+
+    if app_version_of(expected) == app_version_of(found) {
+        return Ok(());
+    }
+
+    Err(ChannelError::version_mismatch(
+        expected.clone(),
+        found.clone(),
+    ))
+}
+
+/// Extracts the app-layer version out of `version`: for a JSON middleware wrapper (e.g. the
+/// fee-middleware `{"fee_version":...,"app_version":...}`) this is the `app_version` field;
+/// otherwise `version` is itself a plain app version (e.g. `ics20-1`) and is returned unchanged.
+fn app_version_of(version: &Version) -> String {
+    let raw = version.to_string();
+
+    match serde_json::from_str::<serde_json::Value>(&raw) {
+        Ok(serde_json::Value::Object(fields)) => fields
+            .get("app_version")
+            .and_then(|v| v.as_str())
+            .map(str::to_owned)
+            .unwrap_or(raw),
+        _ => raw,
     }
 }
@@ -3,10 +3,14 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
 use alloc::sync::Arc;
 use once_cell::sync::OnceCell;
+use std::path::{Path, PathBuf};
 use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::SystemTime;
 
 use tokio::runtime::Runtime as TokioRuntime;
-use tracing::{trace, warn};
+use tokio::sync::watch;
+use tokio::time::{sleep, Duration};
+use tracing::{debug, error, trace, warn};
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
@@ -14,11 +18,75 @@ use crate::chain::handle::DefaultChainHandle;
 use crate::spawn::spawn_chain_runtime_with_config;
 use crate::{
     chain::handle::ChainHandle,
-    config::Config,
+    config::{self, Config},
     spawn::{spawn_chain_runtime, SpawnError},
     util::lock::RwArc,
 };
 
+/// Health state of a chain runtime as tracked by the registry's supervision layer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChainHealthState {
+    /// The runtime responded to its last health check.
+    Healthy,
+    /// The runtime failed a health check and is being respawned; carries the
+    /// number of consecutive failed restart attempts so far.
+    Restarting { attempt: u32 },
+    /// The runtime failed to come back healthy after exhausting the retry cap
+    /// and was evicted from the registry.
+    Failed,
+}
+
+/// Restart policy applied by the registry's supervision layer when a chain
+/// runtime fails a health check.
+#[derive(Clone, Debug)]
+pub struct RestartPolicy {
+    /// Delay before the second restart attempt, i.e. after the first one failed. The first
+    /// restart is attempted as soon as a health check fails.
+    pub base_delay: Duration,
+    /// Multiplicative growth factor applied to the delay after each failed attempt.
+    pub growth_factor: u32,
+    /// Maximum number of consecutive restart attempts before giving up.
+    pub max_retries: u32,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_secs(1),
+            growth_factor: 2,
+            max_retries: 5,
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// The backoff delay to wait after the `attempt`-th restart (0-indexed) failed, before the
+    /// next one.
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        self.base_delay * self.growth_factor.saturating_pow(attempt)
+    }
+}
+
+/// A membership change to a [`Registry`], broadcast through [`SharedRegistry::subscribe`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistryEvent {
+    /// A chain runtime was spawned and inserted into the registry.
+    Spawned(ChainId),
+    /// A chain runtime was shut down and removed from the registry.
+    ShutDown(ChainId),
+}
+
+/// A point-in-time view of registry membership plus the most recent change,
+/// carried by the `watch` channel returned from [`SharedRegistry::subscribe`].
+///
+/// Late subscribers immediately observe `chains` as it stood at subscription
+/// time, since `watch::Receiver` always yields the last sent value.
+#[derive(Clone, Debug, Default)]
+pub struct RegistrySnapshot {
+    pub chains: Vec<ChainId>,
+    pub last_event: Option<RegistryEvent>,
+}
+
 /// Registry for keeping track of [`ChainHandle`]s indexed by a `ChainId`.
 ///
 /// The purpose of this type is to avoid spawning multiple runtimes for a single `ChainId`.
@@ -27,18 +95,52 @@ pub struct Registry<Chain: ChainHandle> {
     config: Config,
     handles: HashMap<ChainId, Chain>,
     rt: Arc<TokioRuntime>,
+    /// Consecutive health-check failures per chain, used to drive [`RestartPolicy`].
+    failure_counts: HashMap<ChainId, u32>,
+    /// Last observed health state per chain, surfaced through [`Registry::health`].
+    health: HashMap<ChainId, ChainHealthState>,
+    /// Earliest instant at which a chain may be retried again, per [`RestartPolicy`] backoff.
+    next_retry_at: HashMap<ChainId, std::time::Instant>,
+    /// Broadcasts [`RegistrySnapshot`]s to subscribers on every membership change.
+    events: watch::Sender<RegistrySnapshot>,
 }
 
 impl<Chain: ChainHandle> Registry<Chain> {
     /// Construct a new [`Registry`] using the provided [`Config`]
     pub fn new(config: Config) -> Self {
+        let (events, _) = watch::channel(RegistrySnapshot::default());
+
         Self {
             config,
             handles: HashMap::new(),
             rt: Arc::new(TokioRuntime::new().unwrap()),
+            failure_counts: HashMap::new(),
+            health: HashMap::new(),
+            next_retry_at: HashMap::new(),
+            events,
         }
     }
 
+    /// Publish a [`RegistrySnapshot`] reflecting the current membership, tagged
+    /// with the event that triggered it. Subscribers that lag or arrive late
+    /// still observe the latest snapshot, since `watch` only retains the
+    /// most recent value.
+    fn notify(&self, event: RegistryEvent) {
+        let snapshot = RegistrySnapshot {
+            chains: self.handles.keys().cloned().collect(),
+            last_event: Some(event),
+        };
+
+        // A send error only means there are currently no receivers, which is fine.
+        let _ = self.events.send(snapshot);
+    }
+
+    /// Subscribe to [`RegistrySnapshot`] updates. The returned receiver
+    /// immediately yields the current membership snapshot.
+    pub fn subscribe(&self) -> watch::Receiver<RegistrySnapshot> {
+        self.events.subscribe()
+    }
+
     /// Return the size of the registry, i.e., the number of distinct chain runtimes.
     pub fn size(&self) -> usize {
         self.handles.len()
@@ -72,6 +174,7 @@ impl<Chain: ChainHandle> Registry<Chain> {
         if !self.handles.contains_key(chain_id) {
             let handle = spawn_chain_runtime(&self.config, chain_id, self.rt.clone())?;
             self.handles.insert(chain_id.clone(), handle);
+            self.notify(RegistryEvent::Spawned(chain_id.clone()));
             trace!(chain = %chain_id, "spawned chain runtime");
             Ok(true)
         } else {
@@ -85,6 +188,102 @@ impl<Chain: ChainHandle> Registry<Chain> {
             if let Err(e) = handle.shutdown() {
                 warn!(chain = %chain_id, "chain runtime might have failed to shutdown properly: {}", e);
             }
+            self.notify(RegistryEvent::ShutDown(chain_id.clone()));
+        }
+        self.failure_counts.remove(chain_id);
+        self.health.remove(chain_id);
+        self.next_retry_at.remove(chain_id);
+    }
+
+    /// Return the last observed health state for a chain, if the registry has
+    /// ever health-checked it. Chains with a live handle that have not yet
+    /// failed a check are reported as [`ChainHealthState::Healthy`].
+    pub fn health_of(&self, chain_id: &ChainId) -> Option<ChainHealthState> {
+        if let Some(state) = self.health.get(chain_id) {
+            return Some(state.clone());
+        }
+
+        if self.handles.contains_key(chain_id) {
+            Some(ChainHealthState::Healthy)
+        } else {
+            None
+        }
+    }
+
+    /// Health-check every registered chain handle with a lightweight
+    /// `query_application_status` keep-alive call. A chain whose handle has
+    /// a closed channel or whose query fails is evicted and, if `policy`
+    /// still allows it, respawned with an exponentially backed-off delay;
+    /// once `policy.max_retries` consecutive failures are hit for a chain,
+    /// it is left absent from the registry and marked [`ChainHealthState::Failed`].
+    pub fn health_check(&mut self, policy: &RestartPolicy) {
+        // Chains whose last respawn failed have no handle any more, but are still retried.
+        let mut chain_ids: Vec<ChainId> = self
+            .handles
+            .keys()
+            .chain(self.failure_counts.keys())
+            .cloned()
+            .collect();
+        chain_ids.sort();
+        chain_ids.dedup();
+
+        let now = std::time::Instant::now();
+
+        for chain_id in chain_ids {
+            let is_healthy = self
+                .handles
+                .get(&chain_id)
+                .map(|handle| handle.query_application_status().is_ok())
+                .unwrap_or(false);
+
+            if is_healthy {
+                self.failure_counts.remove(&chain_id);
+                self.next_retry_at.remove(&chain_id);
+                self.health.insert(chain_id, ChainHealthState::Healthy);
+                continue;
+            }
+
+            if matches!(self.next_retry_at.get(&chain_id), Some(at) if now < *at) {
+                // Still within the backoff window for this chain; try again next tick.
+                continue;
+            }
+
+            let attempt = *self.failure_counts.entry(chain_id.clone()).or_insert(0);
+
+            if attempt >= policy.max_retries {
+                warn!(chain = %chain_id, attempts = attempt, "chain runtime exhausted restart attempts, giving up");
+                self.handles.remove(&chain_id);
+                self.notify(RegistryEvent::ShutDown(chain_id.clone()));
+                self.failure_counts.remove(&chain_id);
+                self.next_retry_at.remove(&chain_id);
+                self.health.insert(chain_id, ChainHealthState::Failed);
+                continue;
+            }
+
+            self.health.insert(
+                chain_id.clone(),
+                ChainHealthState::Restarting { attempt },
+            );
+
+            trace!(chain = %chain_id, attempt, "chain runtime failed health check, restarting");
+
+            self.handles.remove(&chain_id);
+
+            match spawn_chain_runtime(&self.config, &chain_id, self.rt.clone()) {
+                Ok(handle) => {
+                    self.handles.insert(chain_id.clone(), handle);
+                    self.notify(RegistryEvent::Spawned(chain_id.clone()));
+                    self.failure_counts.remove(&chain_id);
+                    self.next_retry_at.remove(&chain_id);
+                    self.health.insert(chain_id, ChainHealthState::Healthy);
+                }
+                Err(e) => {
+                    self.failure_counts.insert(chain_id.clone(), attempt + 1);
+                    self.next_retry_at
+                        .insert(chain_id.clone(), now + policy.delay_for(attempt));
+                    warn!(chain = %chain_id, "restart attempt failed: {}", e);
+                }
+            }
         }
     }
 }
@@ -104,12 +303,18 @@ pub fn get_global_registry() -> SharedRegistry {
         .clone()
 }
 
+/// A [`Registry`] shared and synchronized across tasks/threads via a [`RwArc`].
+///
+/// Generic over the [`ChainHandle`] implementation so that tests can plug in a
+/// mock/in-memory handle; [`SharedRegistry`] (without type parameters, via the
+/// default) continues to mean `SharedRegistry<DefaultChainHandle>` for all
+/// existing call sites.
 #[derive(Clone)]
-pub struct SharedRegistry {
-    pub registry: RwArc<Registry<DefaultChainHandle>>,
+pub struct SharedRegistry<Chain: ChainHandle = DefaultChainHandle> {
+    pub registry: RwArc<Registry<Chain>>,
 }
 
-impl SharedRegistry {
+impl<Chain: ChainHandle> SharedRegistry<Chain> {
     pub fn new(config: Config) -> Self {
         let registry = Registry::new(config);
 
@@ -118,7 +323,7 @@ impl SharedRegistry {
         }
     }
 
-    pub fn get_or_spawn(&self, chain_id: &ChainId) -> Result<DefaultChainHandle, SpawnError> {
+    pub fn get_or_spawn(&self, chain_id: &ChainId) -> Result<Chain, SpawnError> {
         let read_reg = self.read();
 
         if read_reg.handles.contains_key(chain_id) {
@@ -137,10 +342,11 @@ impl SharedRegistry {
             let rt = Arc::clone(&read_reg.rt);
             drop(read_reg);
 
-            let handle: DefaultChainHandle = spawn_chain_runtime_with_config(chain_config, rt)?;
+            let handle: Chain = spawn_chain_runtime_with_config(chain_config, rt)?;
 
             let mut write_reg = self.write();
             write_reg.handles.insert(chain_id.clone(), handle.clone());
+            write_reg.notify(RegistryEvent::Spawned(chain_id.clone()));
             drop(write_reg);
 
             trace!(chain = %chain_id, "spawned chain runtime");
@@ -149,19 +355,319 @@ impl SharedRegistry {
         }
     }
 
+    /// Subscribe to membership changes on this registry. See [`Registry::subscribe`].
+    pub fn subscribe(&self) -> watch::Receiver<RegistrySnapshot> {
+        self.read().subscribe()
+    }
+
     pub fn shutdown(&self, chain_id: &ChainId) {
-        if let Some(handle) = self.write().handles.remove(chain_id) {
+        let mut write_reg = self.write();
+        if let Some(handle) = write_reg.handles.remove(chain_id) {
             if let Err(e) = handle.shutdown() {
                 warn!(chain = %chain_id, "chain runtime might have failed to shutdown properly: {}", e);
             }
+            write_reg.notify(RegistryEvent::ShutDown(chain_id.clone()));
         }
     }
 
-    pub fn write(&self) -> RwLockWriteGuard<'_, Registry<DefaultChainHandle>> {
+    /// Drain and shut down every chain runtime concurrently, each bounded by
+    /// `timeout`. Failures (an error from `handle.shutdown()`, or a chain that
+    /// didn't shut down within `timeout`) are collected rather than aborting
+    /// the rest of the teardown, and are reported in the returned
+    /// [`ShutdownAllOutcome`].
+    ///
+    /// This is an `async fn` rather than a blocking call so it can be awaited from
+    /// a task that is already running on the registry's own Tokio runtime (e.g. a
+    /// signal handler spawned on it) -- calling [`TokioRuntime::block_on`] from such
+    /// a task panics with "Cannot start a runtime from within a runtime".
+    pub async fn shutdown_all(&self, timeout: Duration) -> ShutdownAllOutcome
+    where
+        Chain: 'static,
+    {
+        let handles: Vec<(ChainId, Chain)> = {
+            let mut write_reg = self.write();
+            let drained: Vec<(ChainId, Chain)> = write_reg.handles.iter().map(|(id, h)| (id.clone(), h.clone())).collect();
+            write_reg.handles.clear();
+            for (id, _) in &drained {
+                write_reg.notify(RegistryEvent::ShutDown(id.clone()));
+            }
+            drained
+        };
+
+        let mut join_set = tokio::task::JoinSet::new();
+
+        for (chain_id, handle) in handles {
+            join_set.spawn(async move {
+                let result = tokio::time::timeout(
+                    timeout,
+                    tokio::task::spawn_blocking(move || handle.shutdown()),
+                )
+                .await;
+
+                match result {
+                    Ok(Ok(Ok(()))) => (chain_id, Ok(())),
+                    Ok(Ok(Err(e))) => (chain_id, Err(format!("{e}"))),
+                    Ok(Err(join_err)) => (chain_id, Err(format!("{join_err}"))),
+                    Err(_) => (chain_id, Err("timed out waiting for shutdown".to_string())),
+                }
+            });
+        }
+
+        let mut succeeded = Vec::new();
+        let mut failed = Vec::new();
+
+        while let Some(res) = join_set.join_next().await {
+            match res {
+                Ok((chain_id, Ok(()))) => succeeded.push(chain_id),
+                Ok((chain_id, Err(reason))) => {
+                    warn!(chain = %chain_id, "chain runtime failed to shut down: {}", reason);
+                    failed.push((chain_id, reason));
+                }
+                Err(join_err) => {
+                    error!("shutdown task panicked: {}", join_err);
+                }
+            }
+        }
+
+        ShutdownAllOutcome { succeeded, failed }
+    }
+
+    pub fn write(&self) -> RwLockWriteGuard<'_, Registry<Chain>> {
         self.registry.write().unwrap()
     }
 
-    pub fn read(&self) -> RwLockReadGuard<'_, Registry<DefaultChainHandle>> {
+    pub fn read(&self) -> RwLockReadGuard<'_, Registry<Chain>> {
         self.registry.read().unwrap()
     }
+
+    /// Return the current health state of a chain, if it has ever been spawned
+    /// or health-checked. See [`Registry::health_of`].
+    pub fn health_of(&self, chain_id: &ChainId) -> Option<ChainHealthState> {
+        self.read().health_of(chain_id)
+    }
+
+    /// Reconcile the registry with a freshly loaded [`Config`].
+    ///
+    /// Diffs `new_config` against the `Config` currently held by the registry and,
+    /// atomically under the write lock, spawns runtimes for chains that were added,
+    /// shuts down runtimes for chains that were removed, and restarts (shutdown then
+    /// respawn) runtimes for chains whose [`ChainConfig`](crate::config::ChainConfig)
+    /// changed. Returns a summary of the `ChainId`s affected by each kind of change.
+    pub fn reconcile(&self, new_config: Config) -> ReconcileOutcome {
+        let mut write_reg = self.write();
+
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        let mut restarted = Vec::new();
+
+        let old_ids: Vec<ChainId> = write_reg.config.chains.iter().map(|c| c.id.clone()).collect();
+        let new_ids: Vec<ChainId> = new_config.chains.iter().map(|c| c.id.clone()).collect();
+
+        // Chains that disappeared from the new config.
+        for id in &old_ids {
+            if !new_ids.contains(id) {
+                write_reg.shutdown(id);
+                removed.push(id.clone());
+            }
+        }
+
+        // Chains that are new, or whose configuration changed.
+        for new_chain in &new_config.chains {
+            match write_reg.config.find_chain(&new_chain.id) {
+                None => {
+                    added.push(new_chain.id.clone());
+                }
+                Some(old_chain) => {
+                    if !chain_config_eq(old_chain, new_chain) {
+                        write_reg.shutdown(&new_chain.id);
+                        restarted.push(new_chain.id.clone());
+                    }
+                }
+            }
+        }
+
+        // Install the new config before (re)spawning so `spawn` inside this
+        // same write-lock critical section observes the new chain configs.
+        write_reg.config = new_config;
+
+        for id in added.iter().chain(restarted.iter()) {
+            if let Err(e) = write_reg.spawn(id) {
+                error!(chain = %id, "failed to spawn chain runtime during reconcile: {}", e);
+            }
+        }
+
+        if !added.is_empty() || !removed.is_empty() || !restarted.is_empty() {
+            debug!(
+                added = ?added, removed = ?removed, restarted = ?restarted,
+                "reconciled registry with updated config"
+            );
+        }
+
+        ReconcileOutcome {
+            added,
+            removed,
+            restarted,
+        }
+    }
+}
+
+/// Compares two [`ChainConfig`](crate::config::ChainConfig)s for equality by
+/// comparing their serialized form, so this keeps working regardless of whether
+/// `ChainConfig` derives `PartialEq` upstream.
+fn chain_config_eq(a: &config::ChainConfig, b: &config::ChainConfig) -> bool {
+    match (toml::to_string(a), toml::to_string(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        // If we can't serialize for some reason, err on the side of treating
+        // the chain as changed so it gets a fresh runtime.
+        _ => false,
+    }
+}
+
+/// Outcome of [`SharedRegistry::shutdown_all`]: which chains shut down cleanly
+/// and which ones failed or timed out, with the failure reason for each.
+#[derive(Clone, Debug, Default)]
+pub struct ShutdownAllOutcome {
+    pub succeeded: Vec<ChainId>,
+    pub failed: Vec<(ChainId, String)>,
+}
+
+impl ShutdownAllOutcome {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Summary of the changes applied by [`SharedRegistry::reconcile`].
+#[derive(Clone, Debug, Default)]
+pub struct ReconcileOutcome {
+    pub added: Vec<ChainId>,
+    pub removed: Vec<ChainId>,
+    pub restarted: Vec<ChainId>,
+}
+
+/// Spawn a background task that watches `config_path` for changes and reconciles
+/// `registry` with the updated [`Config`] whenever the file's modification time advances.
+///
+/// This uses a polling strategy (checking the file's mtime on an interval) rather
+/// than OS-level filesystem notifications, so it works uniformly across platforms
+/// without an extra dependency. Rapid successive writes (e.g. an editor saving in
+/// several steps) are debounced by requiring the mtime to be stable across two
+/// consecutive polls before reloading.
+pub fn spawn_config_watcher<Chain: ChainHandle>(
+    registry: SharedRegistry<Chain>,
+    config_path: impl Into<PathBuf>,
+    poll_interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    let config_path = config_path.into();
+
+    tokio::spawn(async move {
+        let mut last_reloaded: Option<SystemTime> = None;
+        let mut last_seen: Option<SystemTime> = None;
+
+        loop {
+            sleep(poll_interval).await;
+
+            let mtime = match file_mtime(&config_path) {
+                Ok(mtime) => mtime,
+                Err(e) => {
+                    warn!(path = %config_path.display(), "could not stat config file: {}", e);
+                    continue;
+                }
+            };
+
+            // Debounce: only act once the mtime has been stable for two polls.
+            if last_seen != Some(mtime) {
+                last_seen = Some(mtime);
+                continue;
+            }
+
+            if last_reloaded == Some(mtime) {
+                continue;
+            }
+
+            match config::load(&config_path) {
+                Ok(new_config) => {
+                    let outcome = registry.reconcile(new_config);
+                    last_reloaded = Some(mtime);
+
+                    if !outcome.added.is_empty()
+                        || !outcome.removed.is_empty()
+                        || !outcome.restarted.is_empty()
+                    {
+                        warn!(
+                            added = ?outcome.added,
+                            removed = ?outcome.removed,
+                            restarted = ?outcome.restarted,
+                            "config file changed, reconciled chain registry"
+                        );
+                    }
+                }
+                Err(e) => {
+                    error!(path = %config_path.display(), "failed to reload config: {}", e);
+                }
+            }
+        }
+    })
+}
+
+fn file_mtime(path: &Path) -> std::io::Result<SystemTime> {
+    std::fs::metadata(path)?.modified()
+}
+
+/// Spawn a background task that periodically health-checks every chain runtime
+/// in `registry` and respawns unhealthy ones per `policy`, per [`Registry::health_check`].
+pub fn spawn_chain_supervisor<Chain: ChainHandle>(
+    registry: SharedRegistry<Chain>,
+    policy: RestartPolicy,
+    check_interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            sleep(check_interval).await;
+            registry.write().health_check(&policy);
+        }
+    })
+}
+
+/// Spawn a background task that waits for SIGINT or SIGTERM and, upon receipt,
+/// performs an orderly [`SharedRegistry::shutdown_all`] of every chain runtime
+/// before the process exits. `shutdown_timeout` bounds how long we wait for
+/// each chain to tear down; any chain that fails or times out is logged.
+#[cfg(unix)]
+pub fn spawn_shutdown_on_signal<Chain: ChainHandle + 'static>(
+    registry: SharedRegistry<Chain>,
+    shutdown_timeout: Duration,
+) -> tokio::task::JoinHandle<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    tokio::spawn(async move {
+        let mut sigint = match signal(SignalKind::interrupt()) {
+            Ok(s) => s,
+            Err(e) => {
+                error!("failed to install SIGINT handler: {}", e);
+                return;
+            }
+        };
+        let mut sigterm = match signal(SignalKind::terminate()) {
+            Ok(s) => s,
+            Err(e) => {
+                error!("failed to install SIGTERM handler: {}", e);
+                return;
+            }
+        };
+
+        tokio::select! {
+            _ = sigint.recv() => debug!("received SIGINT, shutting down all chain runtimes"),
+            _ = sigterm.recv() => debug!("received SIGTERM, shutting down all chain runtimes"),
+        }
+
+        let outcome = registry.shutdown_all(shutdown_timeout).await;
+
+        if !outcome.is_success() {
+            warn!(
+                failed = ?outcome.failed,
+                "some chain runtimes failed to shut down cleanly"
+            );
+        }
+    })
 }
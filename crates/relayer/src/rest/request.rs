@@ -1,6 +1,7 @@
 use serde::Serialize;
 
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 
 use crate::{
     config::ChainConfig, rest::RestApiError, snapshot::IbcSnapshot,
@@ -20,6 +21,14 @@ pub struct VersionInfo {
     pub version: String,
 }
 
+/// The packet commitments and acknowledgements on one end of a channel that the counterparty has
+/// not yet received, as reported by [`Request::GetPendingPackets`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PendingPackets {
+    pub unreceived_packets: Vec<Sequence>,
+    pub unreceived_acks: Vec<Sequence>,
+}
+
 /// REST API request variants
 #[derive(Clone, Debug)]
 pub enum Request {
@@ -44,4 +53,18 @@ pub enum Request {
         chain_id: ChainId,
         reply_to: ReplySender<Option<IbcSnapshot>>,
     },
+
+    GetPendingPackets {
+        chain_id: ChainId,
+        channel_id: ChannelId,
+        port_id: PortId,
+        reply_to: ReplySender<PendingPackets>,
+    },
+
+    ClearPackets {
+        chain_id: ChainId,
+        channel_id: ChannelId,
+        port_id: PortId,
+        reply_to: ReplySender<()>,
+    },
 }
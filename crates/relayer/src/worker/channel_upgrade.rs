@@ -0,0 +1,81 @@
+use tracing::{error, warn};
+
+use ibc_relayer_types::events::IbcEvent;
+
+use crate::chain::handle::ChainHandle;
+use crate::channel::{Channel, ChannelError};
+
+/// Drives a single channel's upgrade handshake forward in response to the
+/// `channel_upgrade_init`/`_try`/`_ack`/`_confirm`/`_open` (and the error/timeout) events the
+/// counterparty emits, the same way the packet worker reacts to packet events instead of the
+/// handshake being driven by hand.
+///
+/// A supervisor wires this up by subscribing to the events above on both chains of `channel`
+/// and calling [`process_event`](Self::process_event) with whatever is received; this worker
+/// does not itself own an event subscription so it stays agnostic of how the supervisor
+/// multiplexes events across the channels it is relaying for.
+pub struct ChannelUpgradeWorker<ChainA: ChainHandle, ChainB: ChainHandle> {
+    channel: Channel<ChainA, ChainB>,
+}
+
+impl<ChainA: ChainHandle, ChainB: ChainHandle> ChannelUpgradeWorker<ChainA, ChainB> {
+    pub fn new(channel: Channel<ChainA, ChainB>) -> Self {
+        Self { channel }
+    }
+
+    pub fn channel(&self) -> &Channel<ChainA, ChainB> {
+        &self.channel
+    }
+
+    /// Reacts to a single channel-upgrade event observed on the counterparty chain by
+    /// submitting the next handshake message on `self.channel`'s destination chain.
+    ///
+    /// Events for a different channel than the one this worker was built for, or that aren't
+    /// channel-upgrade events, are ignored. A proof-height-lag error (the destination's client
+    /// hasn't yet caught up with the height the event's proof was taken at) is logged and
+    /// swallowed rather than propagated, so the caller's own retry/backoff loop re-drives the
+    /// same event on its next pass instead of treating it as a fatal handshake failure.
+    pub fn process_event(&self, event: &IbcEvent) -> Result<(), ChannelError> {
+        let result = match event {
+            IbcEvent::UpgradeInitChannel(_) => self
+                .channel
+                .build_chan_upgrade_try_and_send()
+                .map(|_| ()),
+            IbcEvent::UpgradeTryChannel(_) => self
+                .channel
+                .build_chan_upgrade_ack_and_send()
+                .map(|_| ()),
+            IbcEvent::UpgradeAckChannel(_) => self
+                .channel
+                .build_chan_upgrade_confirm_and_send()
+                .map(|_| ()),
+            IbcEvent::UpgradeConfirmChannel(_) => self
+                .channel
+                .build_chan_upgrade_open_and_send()
+                .map(|_| ()),
+            IbcEvent::UpgradeTimeoutChannel(_) | IbcEvent::UpgradeErrorChannel(_) => self
+                .channel
+                .build_chan_upgrade_cancel_and_send()
+                .map(|_| ()),
+            _ => return Ok(()),
+        };
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) if e.is_expired_or_frozen_error() => {
+                warn!(
+                    "channel upgrade worker: destination client for {} is behind the event's proof height, will retry: {}",
+                    self.channel, e
+                );
+                Ok(())
+            }
+            Err(e) => {
+                error!(
+                    "channel upgrade worker: failed to relay next upgrade step for {}: {}",
+                    self.channel, e
+                );
+                Err(e)
+            }
+        }
+    }
+}
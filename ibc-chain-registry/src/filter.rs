@@ -0,0 +1,27 @@
+//! Bridges chain-registry IBC path data into a relayer's packet filter: given a tag to select by
+//! (e.g. only `preferred` channels, or `status = "live"`), produces the `(port_id, channel_id)`
+//! pairs a relayer should restrict itself to for a given chain pair, instead of requiring the
+//! operator to hand-list them in configuration.
+
+use ibc::core::ics24_host::identifier::{ChannelId, PortId};
+
+use crate::paths::{IBCPath, Tag};
+
+/// Returns the `(port_id, channel_id)` pairs, on `chain_name`'s side of `path`, for the channels
+/// matching `tag`.
+pub fn allowed_channels_for_tag<'a>(
+    path: &'a IBCPath,
+    chain_name: &str,
+    tag: &Tag,
+) -> Vec<(&'a PortId, &'a ChannelId)> {
+    path.filter_by_tag(tag)
+        .into_iter()
+        .map(|channel| {
+            if path.chain_1.chain_name == chain_name {
+                (&channel.chain_1.port_id, &channel.chain_1.channel_id)
+            } else {
+                (&channel.chain_2.port_id, &channel.chain_2.channel_id)
+            }
+        })
+        .collect()
+}
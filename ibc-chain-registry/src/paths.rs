@@ -1,8 +1,11 @@
 /// Models for serializing and deserializing IBC path JSON data found in the `_IBC/` directory of the registry repository
+use crate::constants::ALL_PATHS;
+use crate::error::RegistryError;
 use crate::fetchable::Fetchable;
 use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -72,6 +75,64 @@ pub enum Tag {
     Status(String),
 }
 
+impl IBCPath {
+    /// All channels this path records between its two chains.
+    pub fn channels_between(&self) -> &[Channel] {
+        &self.channels
+    }
+
+    /// Finds the channel whose `chain_1` or `chain_2` side matches `port_id`/`channel_id`.
+    pub fn find_channel(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<&Channel> {
+        self.channels.iter().find(|channel| {
+            (&channel.chain_1.port_id == port_id && &channel.chain_1.channel_id == channel_id)
+                || (&channel.chain_2.port_id == port_id
+                    && &channel.chain_2.channel_id == channel_id)
+        })
+    }
+
+    /// Returns the channels matching `tag`. `Tag::Preferred(true)` returns only preferred
+    /// channels; `Tag::Status`/`Tag::Dex`/`Tag::Properties` match the corresponding tag field
+    /// exactly.
+    pub fn filter_by_tag(&self, tag: &Tag) -> Vec<&Channel> {
+        self.channels
+            .iter()
+            .filter(|channel| match tag {
+                Tag::Preferred(preferred) => channel.tags.preferred == *preferred,
+                Tag::Status(status) => &channel.tags.status == status,
+                Tag::Dex(dex) => &channel.tags.dex == dex,
+                Tag::Properties(properties) => &channel.tags.properties == properties,
+            })
+            .collect()
+    }
+
+    /// Fetches every `_IBC/` path resource involving `chain_name` and indexes the results by the
+    /// counterparty chain's name, so a relayer configured against the registry can look up "the
+    /// path to chain X" without hand-deriving registry resource names itself.
+    pub async fn load_all_for_chain(
+        chain_name: &str,
+    ) -> Result<HashMap<String, IBCPath>, RegistryError> {
+        let mut paths = HashMap::new();
+
+        for resource in ALL_PATHS {
+            if !resource.contains(chain_name) {
+                continue;
+            }
+
+            let path = IBCPath::fetch(resource.to_string(), None).await?;
+
+            let counterparty_chain_name = if path.chain_1.chain_name == chain_name {
+                path.chain_2.chain_name.clone()
+            } else {
+                path.chain_1.chain_name.clone()
+            };
+
+            paths.insert(counterparty_chain_name, path);
+        }
+
+        Ok(paths)
+    }
+}
+
 impl Fetchable for IBCPath {
     fn path(resource: &str) -> PathBuf {
         ["_IBC", resource].iter().collect()
@@ -191,4 +252,96 @@ mod tests {
         assert_eq!(path.channels[0].tags.properties, "properties");
         assert_eq!(path.channels[0].tags.status, "status");
     }
+
+    fn test_path() -> IBCPath {
+        use std::str::FromStr;
+
+        IBCPath {
+            schema: String::new(),
+            chain_1: Chain1 {
+                chain_name: "chain_1".to_owned(),
+                ..Default::default()
+            },
+            chain_2: Chain2 {
+                chain_name: "chain_2".to_owned(),
+                ..Default::default()
+            },
+            channels: vec![
+                Channel {
+                    chain_1: ChannelChain1 {
+                        channel_id: ChannelId::from_str("channel-0").unwrap(),
+                        port_id: PortId::from_str("transfer").unwrap(),
+                    },
+                    chain_2: ChannelChain2 {
+                        channel_id: ChannelId::from_str("channel-1").unwrap(),
+                        port_id: PortId::from_str("transfer").unwrap(),
+                    },
+                    tags: Tags {
+                        preferred: true,
+                        status: "live".to_owned(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                Channel {
+                    chain_1: ChannelChain1 {
+                        channel_id: ChannelId::from_str("channel-2").unwrap(),
+                        port_id: PortId::from_str("transfer").unwrap(),
+                    },
+                    chain_2: ChannelChain2 {
+                        channel_id: ChannelId::from_str("channel-3").unwrap(),
+                        port_id: PortId::from_str("transfer").unwrap(),
+                    },
+                    tags: Tags {
+                        preferred: false,
+                        status: "deprecated".to_owned(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn find_channel_matches_either_side() {
+        use std::str::FromStr;
+
+        let path = test_path();
+
+        let found = path
+            .find_channel(
+                &PortId::from_str("transfer").unwrap(),
+                &ChannelId::from_str("channel-1").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(found.chain_1.channel_id, ChannelId::from_str("channel-0").unwrap());
+
+        assert!(path
+            .find_channel(
+                &PortId::from_str("transfer").unwrap(),
+                &ChannelId::from_str("channel-99").unwrap(),
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn filter_by_tag_selects_matching_channels() {
+        use std::str::FromStr;
+
+        let path = test_path();
+
+        let preferred = path.filter_by_tag(&Tag::Preferred(true));
+        assert_eq!(preferred.len(), 1);
+        assert_eq!(
+            preferred[0].chain_1.channel_id,
+            ChannelId::from_str("channel-0").unwrap()
+        );
+
+        let live = path.filter_by_tag(&Tag::Status("live".to_owned()));
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].tags.status, "live");
+
+        assert_eq!(path.channels_between().len(), 2);
+    }
 }
@@ -0,0 +1,4 @@
+//! IBC applications, i.e. handlers that sit behind the ICS26 routing module and exchange
+//! application-specific packet data over an established channel.
+
+pub mod transfer;
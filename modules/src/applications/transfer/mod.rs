@@ -0,0 +1,4 @@
+//! ICS 20: Fungible Token Transfer.
+
+pub mod module;
+pub mod msgs;
@@ -0,0 +1,25 @@
+//! Plugs ICS20 fungible-token transfer into the ICS26 routing layer.
+
+use crate::ics04_channel::packet::Packet;
+use crate::ics26_routing::context::{Acknowledgement, Module};
+use crate::ics26_routing::error::Error;
+
+/// The conventional ICS20 success acknowledgement: `{"result":"AQ=="}`.
+const ACK_SUCCESS: &str = r#"{"result":"AQ=="}"#;
+
+/// Handles the channel and packet callbacks for the ICS20 fungible-token-transfer application.
+/// The handshake callbacks use the `Module` defaults, which accept any proposed version: the
+/// channel version is not checked against `ics20-1`. Only the packet callbacks are overridden
+/// here.
+#[derive(Clone, Debug, Default)]
+pub struct TransferModule;
+
+impl Module for TransferModule {
+    fn on_recv_packet(&self, _packet: &Packet) -> Result<Acknowledgement, Error> {
+        // A full implementation would decode the packet data into a `FungibleTokenPacketData`
+        // and mint or unescrow the transferred denomination, falling back to an error
+        // acknowledgement if that fails. That bookkeeping depends on the host's bank module,
+        // which this crate does not model.
+        Ok(Acknowledgement::new(ACK_SUCCESS.as_bytes().to_vec()))
+    }
+}
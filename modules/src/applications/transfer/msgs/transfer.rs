@@ -41,6 +41,9 @@ pub struct MsgTransfer<C = Coin> {
     /// Timeout timestamp relative to the current block timestamp.
     /// The timeout is disabled when set to 0.
     pub timeout_timestamp: Timestamp,
+    /// Arbitrary memo, e.g. the JSON routing instructions read by middleware such as
+    /// packet-forward or async callbacks. Empty by default.
+    pub memo: String,
 }
 
 impl Msg for MsgTransfer {
@@ -85,6 +88,7 @@ impl TryFrom<RawMsgTransfer> for MsgTransfer {
             receiver: raw_msg.receiver.parse().map_err(Error::signer)?,
             timeout_height,
             timeout_timestamp,
+            memo: raw_msg.memo,
         })
     }
 }
@@ -99,6 +103,7 @@ impl From<MsgTransfer> for RawMsgTransfer {
             receiver: domain_msg.receiver.to_string(),
             timeout_height: domain_msg.timeout_height.map(|height| height.into()),
             timeout_timestamp: domain_msg.timeout_timestamp.nanoseconds(),
+            memo: domain_msg.memo,
         }
     }
 }
@@ -161,6 +166,7 @@ pub mod test_util {
                 revision_number: 0,
                 revision_height: height,
             }),
+            memo: "".to_owned(),
         }
     }
 }
@@ -1,3 +1,4 @@
+use core::any::Any;
 use core::time::Duration;
 
 use ibc_proto::google::protobuf::Any;
@@ -117,6 +118,36 @@ impl AnyClientState {
             AnyClientState::Mock(mock_state) => mock_state.expired(elapsed_since_latest),
         }
     }
+
+    /// Downcasts to the concrete client state type `T`, or returns `None` if
+    /// `self` holds a different variant. Lets callers that need
+    /// client-specific fields (e.g. a Tendermint client's trusting period)
+    /// stay variant-agnostic instead of matching on `AnyClientState::Tendermint`
+    /// directly, which breaks as soon as another variant is added.
+    pub fn downcast_client_state<T: ClientState + 'static>(&self) -> Option<&T> {
+        match self {
+            AnyClientState::Tendermint(tm_state) => (tm_state as &dyn Any).downcast_ref(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            AnyClientState::Mock(mock_state) => (mock_state as &dyn Any).downcast_ref(),
+        }
+    }
+
+    /// Owned counterpart of [`Self::downcast_client_state`]; returns `None`
+    /// when `T` doesn't match the held variant, rather than panicking like
+    /// the Mock arm of the old `AnyUpgradeOptions::into_tendermint` does.
+    pub fn into_concrete<T: ClientState + 'static>(self) -> Option<T> {
+        match self {
+            AnyClientState::Tendermint(tm_state) => {
+                (Box::new(tm_state) as Box<dyn Any>).downcast().ok().map(|b| *b)
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            AnyClientState::Mock(mock_state) => {
+                (Box::new(mock_state) as Box<dyn Any>).downcast().ok().map(|b| *b)
+            }
+        }
+    }
 }
 
 impl Protobuf<Any> for AnyClientState {}
@@ -138,6 +169,16 @@ impl TryFrom<Any> for AnyClientState {
                 MockClientState::decode_vec(&raw.value).map_err(Error::decode_raw_client_state)?,
             )),
 
+            // A type URL outside the two built-in variants above may still be
+            // decodable by a light client a downstream crate registered via
+            // `client_state_registry::register_client_state`. That registry
+            // produces a `Box<dyn client_state_registry::DynClientState>`
+            // rather than an `AnyClientState`, since this enum's derived
+            // `Clone`/`PartialEq`/`Serialize` bounds can't be satisfied by an
+            // arbitrary trait object, so it can't be returned from here;
+            // callers that need to support such a client currently have to
+            // query the registry directly instead of going through
+            // `AnyClientState::try_from`.
             _ => Err(Error::unknown_client_state_type(raw.type_url)),
         }
     }
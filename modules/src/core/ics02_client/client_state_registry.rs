@@ -0,0 +1,61 @@
+use std::sync::RwLock;
+
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::prelude::*;
+use crate::Height;
+
+/// Object-safe subset of [`super::client_state::ClientState`]'s surface,
+/// covering the accessors a caller can use without knowing the concrete
+/// light-client implementation behind it.
+///
+/// `upgrade` is deliberately left out: its `UpgradeOptions` associated type
+/// differs per client, which isn't expressible on a trait object, so
+/// upgrading a pluggable client state still has to go through its concrete
+/// type for now.
+pub trait DynClientState: Send + Sync + core::fmt::Debug {
+    fn chain_id(&self) -> ChainId;
+    fn client_type(&self) -> ClientType;
+    fn latest_height(&self) -> Height;
+    fn frozen_height(&self) -> Option<Height>;
+    fn encode_vec(&self) -> Result<Vec<u8>, Error>;
+}
+
+/// Decodes the protobuf-encoded value of an `Any` into a boxed client state,
+/// for a light client registered under a given type URL.
+pub type ClientStateDecoder = fn(value: &[u8]) -> Result<Box<dyn DynClientState>, Error>;
+
+fn registry() -> &'static RwLock<BTreeMap<String, ClientStateDecoder>> {
+    static REGISTRY: std::sync::OnceLock<RwLock<BTreeMap<String, ClientStateDecoder>>> =
+        std::sync::OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(BTreeMap::new()))
+}
+
+/// Registers a light client implementation for `type_url`, so a client state
+/// of a type this crate doesn't know about (Solomachine, `08-wasm`,
+/// Localhost, GRANDPA, ...) can still be decoded by downstream crates
+/// without forking `AnyClientState` itself.
+///
+/// Registering the same `type_url` twice replaces the previous factory.
+pub fn register_client_state(type_url: impl Into<String>, decode: ClientStateDecoder) {
+    registry()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(type_url.into(), decode);
+}
+
+/// Looks up a registered factory for `type_url` and decodes `value` with it.
+/// Returns `None` if nothing is registered for `type_url`, so the caller can
+/// fall back to its own handling (e.g. the built-in Tendermint/Mock decoding
+/// in [`super::client_state::AnyClientState`]).
+pub fn resolve_client_state(
+    type_url: &str,
+    value: &[u8],
+) -> Option<Result<Box<dyn DynClientState>, Error>> {
+    let decode = *registry()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(type_url)?;
+    Some(decode(value))
+}
@@ -0,0 +1,108 @@
+//! Events emitted by the ICS 027 (Interchain Accounts) module.
+
+use crate::prelude::*;
+
+use core::convert::TryFrom;
+
+use serde_derive::Serialize;
+use tendermint::abci::tag::Tag;
+use tendermint::abci::Event as AbciEvent;
+
+use crate::core::ics24_host::identifier::{ConnectionId, PortId};
+use crate::events::Error;
+
+const ACCOUNT_REGISTERED_EVENT: &str = "register_account";
+
+/// Emitted by the controller chain once an interchain account has been registered for `owner`
+/// over `connection_id`, at the end of the ICA channel handshake. Relayers previously had no way
+/// to tell this apart from an ordinary `channel_open_init` short of string-matching the port id;
+/// this carries the ICA-specific attributes directly.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct AccountRegistered {
+    pub port_id: PortId,
+    pub connection_id: ConnectionId,
+    pub owner: String,
+    pub account_address: String,
+}
+
+impl core::fmt::Display for AccountRegistered {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "AccountRegistered {{ port_id: {}, connection_id: {}, owner: {}, account_address: {} }}",
+            self.port_id, self.connection_id, self.owner, self.account_address
+        )
+    }
+}
+
+impl From<AccountRegistered> for AbciEvent {
+    fn from(ev: AccountRegistered) -> Self {
+        let attributes = vec![
+            Tag {
+                key: "port_id".parse().unwrap(),
+                value: ev.port_id.to_string().parse().unwrap(),
+            },
+            Tag {
+                key: "connection_id".parse().unwrap(),
+                value: ev.connection_id.to_string().parse().unwrap(),
+            },
+            Tag {
+                key: "owner".parse().unwrap(),
+                value: ev.owner.parse().unwrap(),
+            },
+            Tag {
+                key: "account_address".parse().unwrap(),
+                value: ev.account_address.parse().unwrap(),
+            },
+        ];
+
+        AbciEvent {
+            type_str: ACCOUNT_REGISTERED_EVENT.to_string(),
+            attributes,
+        }
+    }
+}
+
+impl TryFrom<&AbciEvent> for AccountRegistered {
+    type Error = Error;
+
+    fn try_from(abci_event: &AbciEvent) -> Result<Self, Self::Error> {
+        let mut port_id = None;
+        let mut connection_id = None;
+        let mut owner = None;
+        let mut account_address = None;
+
+        for tag in &abci_event.attributes {
+            match tag.key.as_ref() {
+                "port_id" => {
+                    port_id = Some(
+                        tag.value
+                            .to_string()
+                            .parse()
+                            .map_err(|_| Error::missing_key("port_id".to_string()))?,
+                    )
+                }
+                "connection_id" => {
+                    connection_id = Some(
+                        tag.value
+                            .to_string()
+                            .parse()
+                            .map_err(|_| Error::missing_key("connection_id".to_string()))?,
+                    )
+                }
+                "owner" => owner = Some(tag.value.to_string()),
+                "account_address" => account_address = Some(tag.value.to_string()),
+                _ => {}
+            }
+        }
+
+        Ok(AccountRegistered {
+            port_id: port_id.ok_or_else(|| Error::missing_key("port_id".to_string()))?,
+            connection_id: connection_id
+                .ok_or_else(|| Error::missing_key("connection_id".to_string()))?,
+            owner: owner.ok_or_else(|| Error::missing_key("owner".to_string()))?,
+            account_address: account_address
+                .ok_or_else(|| Error::missing_key("account_address".to_string()))?,
+        })
+    }
+}
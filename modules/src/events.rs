@@ -3,6 +3,7 @@ use crate::prelude::*;
 use core::convert::{TryFrom, TryInto};
 use core::fmt;
 use core::str::FromStr;
+use std::collections::HashMap;
 use flex_error::{define_error, TraceError};
 use prost::alloc::fmt::Formatter;
 use serde_derive::{Deserialize, Serialize};
@@ -21,7 +22,9 @@ use crate::core::ics04_channel::events::Attributes as ChannelAttributes;
 use crate::core::ics04_channel::packet::Packet;
 use crate::core::ics24_host::error::ValidationError;
 use crate::core::ics26_routing::context::ModuleId;
+use crate::core::ics27_interchain_accounts::events as InterchainAccountsEvents;
 use crate::timestamp::ParseTimestampError;
+use crate::Height;
 
 define_error! {
     Error {
@@ -77,6 +80,70 @@ define_error! {
     }
 }
 
+/// The attribute map a Tendermint `/tx_search` or `/block_search` RPC result carries for a single
+/// event: every attribute key observed anywhere in the queried range, each mapped to the list of
+/// values it took on in order, since a single query can span multiple transactions/events that
+/// reuse the same attribute key. `idx` picks out which occurrence of each key belongs to this
+/// particular event.
+#[derive(Clone, Debug)]
+pub struct RawObject {
+    pub height: Height,
+    pub action: String,
+    pub idx: usize,
+    pub events: HashMap<String, Vec<String>>,
+}
+
+impl RawObject {
+    pub fn new(height: Height, action: String, idx: usize, events: HashMap<String, Vec<String>>) -> Self {
+        Self {
+            height,
+            action,
+            idx,
+            events,
+        }
+    }
+}
+
+/// Looks up `key` in `object`, at the occurrence selected by `object.idx`. Fails if the RPC
+/// result never carried this attribute at all, or didn't carry it for this particular event.
+pub fn extract_attribute(object: &RawObject, key: &str) -> Result<String, Error> {
+    let value = object
+        .events
+        .get(key)
+        .ok_or_else(|| Error::missing_key(key.to_string()))?
+        .get(object.idx)
+        .ok_or_else(|| Error::missing_key(key.to_string()))?
+        .clone();
+
+    Ok(value)
+}
+
+/// Like [`extract_attribute`], but for attributes an event may legitimately omit.
+pub fn maybe_extract_attribute(object: &RawObject, key: &str) -> Option<String> {
+    object.events.get(key)?.get(object.idx).cloned()
+}
+
+/// Generates a `TryFrom<RawObject>` impl for an event struct whose only payload is the block
+/// height it was recorded at (e.g. `NewBlock`). Rejects the conversion unless `object.action`
+/// matches the expected action string for this event, so that RPC results for unrelated events
+/// sharing the same attribute keys aren't mistakenly accepted.
+#[macro_export]
+macro_rules! make_event {
+    ($name:ident, $action:expr) => {
+        impl core::convert::TryFrom<$crate::events::RawObject> for $name {
+            type Error = $crate::events::Error;
+
+            fn try_from(obj: $crate::events::RawObject) -> Result<Self, Self::Error> {
+                if obj.action != $action {
+                    return Err($crate::events::Error::incorrect_event_type(obj.action));
+                }
+
+                Ok($name { height: obj.height })
+            }
+        }
+    };
+}
+
 /// Events whose data is not included in the app state and must be extracted using tendermint RPCs
 /// (i.e. /tx_search or /block_search)
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -126,6 +193,8 @@ const WRITE_ACK_EVENT: &str = "write_acknowledgement";
 const ACK_PACKET_EVENT: &str = "acknowledge_packet";
 const TIMEOUT_EVENT: &str = "timeout_packet";
 const TIMEOUT_ON_CLOSE_EVENT: &str = "timeout_packet_on_close";
+/// Interchain Accounts (ICS 027) event types
+const ACCOUNT_REGISTERED_EVENT: &str = "register_account";
 
 /// Events types
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -151,6 +220,7 @@ pub enum IbcEventType {
     AckPacket,
     Timeout,
     TimeoutOnClose,
+    AccountRegistered,
     AppModule,
     Empty,
     ChainError,
@@ -180,6 +250,7 @@ impl IbcEventType {
             IbcEventType::AckPacket => ACK_PACKET_EVENT,
             IbcEventType::Timeout => TIMEOUT_EVENT,
             IbcEventType::TimeoutOnClose => TIMEOUT_ON_CLOSE_EVENT,
+            IbcEventType::AccountRegistered => ACCOUNT_REGISTERED_EVENT,
             IbcEventType::AppModule => APP_MODULE_EVENT,
             IbcEventType::Empty => EMPTY_EVENT,
             IbcEventType::ChainError => CHAIN_ERROR_EVENT,
@@ -213,6 +284,7 @@ impl FromStr for IbcEventType {
             ACK_PACKET_EVENT => Ok(IbcEventType::AckPacket),
             TIMEOUT_EVENT => Ok(IbcEventType::Timeout),
             TIMEOUT_ON_CLOSE_EVENT => Ok(IbcEventType::TimeoutOnClose),
+            ACCOUNT_REGISTERED_EVENT => Ok(IbcEventType::AccountRegistered),
             EMPTY_EVENT => Ok(IbcEventType::Empty),
             CHAIN_ERROR_EVENT => Ok(IbcEventType::ChainError),
             // from_str() for `APP_MODULE_EVENT` MUST fail because a `ModuleEvent`'s type isn't constant
@@ -250,6 +322,8 @@ pub enum IbcEvent {
     TimeoutPacket(ChannelEvents::TimeoutPacket),
     TimeoutOnClosePacket(ChannelEvents::TimeoutOnClosePacket),
 
+    AccountRegistered(InterchainAccountsEvents::AccountRegistered),
+
     AppModule(ModuleEvent),
 
     ChainError(String), // Special event, signifying an error on CheckTx or DeliverTx
@@ -284,6 +358,8 @@ impl fmt::Display for IbcEvent {
             IbcEvent::TimeoutPacket(ev) => write!(f, "TimeoutPacketEv({})", ev),
             IbcEvent::TimeoutOnClosePacket(ev) => write!(f, "TimeoutOnClosePacketEv({})", ev),
 
+            IbcEvent::AccountRegistered(ev) => write!(f, "AccountRegisteredEv({})", ev),
+
             IbcEvent::AppModule(ev) => write!(f, "AppModuleEv({:?})", ev),
 
             IbcEvent::ChainError(ev) => write!(f, "ChainErrorEv({})", ev),
@@ -316,6 +392,7 @@ impl TryFrom<IbcEvent> for AbciEvent {
             IbcEvent::AcknowledgePacket(event) => event.try_into().map_err(Error::channel)?,
             IbcEvent::TimeoutPacket(event) => event.try_into().map_err(Error::channel)?,
             IbcEvent::TimeoutOnClosePacket(event) => event.try_into().map_err(Error::channel)?,
+            IbcEvent::AccountRegistered(event) => event.into(),
             IbcEvent::AppModule(event) => event.try_into()?,
             IbcEvent::NewBlock(_) | IbcEvent::ChainError(_) => {
                 return Err(Error::incorrect_event_type(event.to_string()))
@@ -374,6 +451,9 @@ impl TryFrom<&AbciEvent> for IbcEvent {
             Ok(IbcEventType::SendPacket) => Ok(IbcEvent::SendPacket(
                 ChannelEvents::SendPacket::try_from(abci_event).map_err(Error::channel)?,
             )),
+            Ok(IbcEventType::ReceivePacket) => Ok(IbcEvent::ReceivePacket(
+                ChannelEvents::ReceivePacket::try_from(abci_event).map_err(Error::channel)?,
+            )),
             Ok(IbcEventType::WriteAck) => Ok(IbcEvent::WriteAcknowledgement(
                 ChannelEvents::WriteAcknowledgement::try_from(abci_event)
                     .map_err(Error::channel)?,
@@ -384,6 +464,12 @@ impl TryFrom<&AbciEvent> for IbcEvent {
             Ok(IbcEventType::Timeout) => Ok(IbcEvent::TimeoutPacket(
                 ChannelEvents::TimeoutPacket::try_from(abci_event).map_err(Error::channel)?,
             )),
+            Ok(IbcEventType::TimeoutOnClose) => Ok(IbcEvent::TimeoutOnClosePacket(
+                ChannelEvents::TimeoutOnClosePacket::try_from(abci_event).map_err(Error::channel)?,
+            )),
+            Ok(IbcEventType::AccountRegistered) => Ok(IbcEvent::AccountRegistered(
+                InterchainAccountsEvents::AccountRegistered::try_from(abci_event)?,
+            )),
             _ => Err(Error::unsupported_abci_event(
                 abci_event.type_str.to_owned(),
             )),
@@ -422,6 +508,7 @@ impl IbcEvent {
             IbcEvent::AcknowledgePacket(_) => IbcEventType::AckPacket,
             IbcEvent::TimeoutPacket(_) => IbcEventType::Timeout,
             IbcEvent::TimeoutOnClosePacket(_) => IbcEventType::TimeoutOnClose,
+            IbcEvent::AccountRegistered(_) => IbcEventType::AccountRegistered,
             IbcEvent::AppModule(_) => IbcEventType::AppModule,
             IbcEvent::ChainError(_) => IbcEventType::ChainError,
         }
@@ -465,6 +552,93 @@ impl IbcEvent {
             _ => None,
         }
     }
+
+    /// Like `IbcEvent::try_from`, but consults `registry` for any `AbciEvent` whose `type_str`
+    /// isn't one of the core ICS event types, instead of immediately failing with
+    /// `UnsupportedAbciEvent`. A chain running custom Cosmos SDK modules can register a parser for
+    /// its own event types so their attributes survive as a typed `AppModule` rather than being
+    /// dropped at the parse boundary.
+    pub fn try_from_abci_event_with_registry(
+        abci_event: &AbciEvent,
+        registry: &ModuleEventRegistry,
+    ) -> Result<IbcEvent, Error> {
+        match IbcEvent::try_from(abci_event) {
+            Ok(event) => Ok(event),
+            Err(_) => registry
+                .parse(abci_event)
+                .unwrap_or_else(|| Err(Error::unsupported_abci_event(abci_event.type_str.clone())))
+                .map(IbcEvent::AppModule),
+        }
+    }
+}
+
+/// Parses an `AbciEvent` this parser claims (by [`ModuleEventParser::type_str`]) into a
+/// `ModuleEvent` carrying whatever module-specific attributes it cares to keep.
+pub trait ModuleEventParser: Send + Sync {
+    /// The `type_str` this parser handles.
+    fn type_str(&self) -> &str;
+
+    fn parse(&self, abci_event: &AbciEvent) -> Result<ModuleEvent, Error>;
+}
+
+/// A registry of [`ModuleEventParser`]s consulted by
+/// [`IbcEvent::try_from_abci_event_with_registry`] before it gives up on an `AbciEvent` whose
+/// `type_str` isn't a core ICS event type. Turns the otherwise all-or-nothing ABCI event matching
+/// into an extension point: integrators register a parser per custom module, keyed on the
+/// `type_str` that module's events use.
+#[derive(Default)]
+pub struct ModuleEventRegistry {
+    parsers: HashMap<String, Box<dyn ModuleEventParser>>,
+}
+
+impl ModuleEventRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, parser: Box<dyn ModuleEventParser>) {
+        self.parsers.insert(parser.type_str().to_string(), parser);
+    }
+
+    fn parse(&self, abci_event: &AbciEvent) -> Option<Result<ModuleEvent, Error>> {
+        self.parsers
+            .get(&abci_event.type_str)
+            .map(|parser| parser.parse(abci_event))
+    }
+}
+
+/// An `IbcEvent` paired with the height of the block it was emitted at. The bare `IbcEvent`
+/// payload describes only what happened; this wrapper is what associates it with where it
+/// happened, so that an event can be constructed once and stamped with a height only once it's
+/// actually known to be part of a concrete block.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct IbcEventWithHeight {
+    pub event: IbcEvent,
+    pub height: Height,
+}
+
+impl IbcEventWithHeight {
+    pub fn new(event: IbcEvent, height: Height) -> Self {
+        Self { event, height }
+    }
+
+    pub fn event_type(&self) -> IbcEventType {
+        self.event.event_type()
+    }
+}
+
+impl fmt::Display for IbcEventWithHeight {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at height {}", self.event, self.height)
+    }
+}
+
+impl TryFrom<IbcEventWithHeight> for AbciEvent {
+    type Error = Error;
+
+    fn try_from(event: IbcEventWithHeight) -> Result<Self, Self::Error> {
+        event.event.try_into()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
@@ -525,3 +699,36 @@ impl From<ModuleEventAttribute> for Tag {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        /// `IbcEvent::try_from(&AbciEvent::try_from(ev)?)? == ev` must hold for every variant the
+        /// ABCI conversion actually supports, since a relayer reconstructs events from exactly
+        /// this round trip when replaying a `tx_search`/`block_search` result. Scoped to
+        /// `AccountRegistered` here because it's the only wrapped event struct whose fields are
+        /// materialized in this tree -- the ics02/03/04 `::events` structs the other `IbcEvent`
+        /// variants wrap aren't, so this same `proptest!` shape should be widened to cover them
+        /// once they exist.
+        #[test]
+        fn account_registered_round_trips_through_abci_event(
+            owner in "[a-zA-Z0-9]{1,20}",
+            account_address in "[a-zA-Z0-9]{1,40}",
+        ) {
+            let event = IbcEvent::AccountRegistered(InterchainAccountsEvents::AccountRegistered {
+                port_id: "transfer".parse().unwrap(),
+                connection_id: "connection-0".parse().unwrap(),
+                owner,
+                account_address,
+            });
+
+            let abci_event = AbciEvent::try_from(event.clone()).unwrap();
+            let round_tripped = IbcEvent::try_from(&abci_event).unwrap();
+
+            prop_assert_eq!(event, round_tripped);
+        }
+    }
+}
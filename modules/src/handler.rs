@@ -0,0 +1,82 @@
+//! Generic types shared by the handler (a.k.a. protocol logic) of every ICS module: the event
+//! and logging machinery that a `process(ctx, msg)` function uses to report what it did, without
+//! needing to know about any particular chain's event bus.
+
+use std::marker::PhantomData;
+
+/// The type of an emitted event, kept abstract here since each ICS module defines its own set of
+/// event kinds (e.g. `connection_open_try`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventType {
+    Custom(String),
+}
+
+/// A generic IBC event, carrying a type tag plus a list of key-value attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub tpe: EventType,
+    pub attributes: Vec<(String, String)>,
+}
+
+impl Event {
+    pub fn new(tpe: EventType, attributes: Vec<(String, String)>) -> Self {
+        Self { tpe, attributes }
+    }
+}
+
+/// The outcome of processing a message: the resulting domain object `T`, the events that the
+/// processing emitted, and a human-readable log trail, useful for debugging and for tests.
+#[derive(Clone, Debug)]
+pub struct HandlerOutput<T> {
+    pub result: T,
+    pub events: Vec<Event>,
+    pub log: Vec<String>,
+}
+
+impl<T> HandlerOutput<T> {
+    pub fn builder() -> HandlerOutputBuilder<T> {
+        HandlerOutputBuilder::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HandlerOutputBuilder<T> {
+    log: Vec<String>,
+    events: Vec<Event>,
+    marker: PhantomData<T>,
+}
+
+impl<T> HandlerOutputBuilder<T> {
+    fn new() -> Self {
+        Self {
+            log: Vec::new(),
+            events: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn log(&mut self, message: impl Into<String>) {
+        self.log.push(message.into());
+    }
+
+    pub fn emit(&mut self, event: impl Into<Event>) {
+        self.events.push(event.into());
+    }
+
+    pub fn with_result(self, result: T) -> HandlerOutput<T> {
+        HandlerOutput {
+            result,
+            events: self.events,
+            log: self.log,
+        }
+    }
+}
+
+impl<T> Default for HandlerOutputBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The result of a handler: either a successful [`HandlerOutput`], or the ICS module's own error.
+pub type HandlerResult<T, E> = Result<HandlerOutput<T>, E>;
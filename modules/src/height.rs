@@ -0,0 +1,62 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A chain height that also tracks which revision (chain-id generation) it belongs to.
+///
+/// A plain block height is ambiguous across a chain upgrade: the upgraded chain typically resets
+/// its block height back to (or near) zero, so comparing two `u64` heights from before and after
+/// an upgrade would wrongly conclude the post-upgrade chain is behind. `revision_number` is bumped
+/// on every upgrade so that ordering always prefers a later revision over any height within an
+/// earlier one.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Height {
+    pub revision_number: u64,
+    pub revision_height: u64,
+}
+
+impl Height {
+    pub fn new(revision_number: u64, revision_height: u64) -> Self {
+        Self {
+            revision_number,
+            revision_height,
+        }
+    }
+
+    pub fn revision_number(&self) -> u64 {
+        self.revision_number
+    }
+
+    pub fn revision_height(&self) -> u64 {
+        self.revision_height
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.revision_height == 0
+    }
+
+    /// `true` if `self` belongs to a later revision than `other`, regardless of block height --
+    /// the condition a chain upgrade must satisfy to be accepted.
+    pub fn is_later_revision_than(&self, other: &Self) -> bool {
+        self.revision_number > other.revision_number
+    }
+}
+
+impl PartialOrd for Height {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Height {
+    // `revision_number` is compared before `revision_height`, so a higher revision always
+    // compares greater regardless of how the two heights' `revision_height`s relate.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.revision_number, self.revision_height).cmp(&(other.revision_number, other.revision_height))
+    }
+}
+
+impl fmt::Display for Height {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.revision_number, self.revision_height)
+    }
+}
@@ -0,0 +1,77 @@
+//! ICS2 (client) context. The two traits `ClientReader` and `ClientKeeper` define the interface
+//! that any host chain must implement to be able to process any `ClientMsg`.
+
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::error::Error;
+use crate::ics23_commitment::commitment::CommitmentRoot;
+use crate::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// A context supplying all the necessary read-only dependencies for processing any `ClientMsg`.
+pub trait ClientReader {
+    /// Returns the type of the client tracked by `client_id`.
+    fn client_type(&self, client_id: &ClientId) -> Option<ClientType>;
+
+    /// Returns the client state for the client tracked by `client_id`.
+    fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState>;
+
+    /// Returns the consensus state for the client tracked by `client_id`, at `height`.
+    fn consensus_state(&self, client_id: &ClientId, height: Height) -> Option<AnyConsensusState>;
+
+    /// Returns the current height of the host chain.
+    fn host_height(&self) -> Height;
+
+    /// Returns the current timestamp of the host chain, used to measure trusting-period elapse
+    /// and to bound how far into the future a submitted header's own timestamp may be.
+    fn host_timestamp(&self) -> Timestamp;
+}
+
+/// A context supplying all the necessary write-only dependencies (i.e. storage writing facility)
+/// for processing any `ClientMsg`.
+pub trait ClientKeeper {
+    fn store_client_state(
+        &mut self,
+        client_id: ClientId,
+        client_state: AnyClientState,
+    ) -> Result<(), Error>;
+
+    fn store_consensus_state(
+        &mut self,
+        client_id: ClientId,
+        consensus_state: AnyConsensusState,
+    ) -> Result<(), Error>;
+}
+
+/// The host chain's own state at a given height: the state root it committed, and the time it
+/// produced that block. Recorded so that a counterparty's assumptions about this chain --
+/// embedded in the client/consensus state it submits back to us -- can be checked against what
+/// this chain's own history actually says, rather than trusted outright.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelfHeader {
+    pub height: Height,
+    pub timestamp: Timestamp,
+    pub root: CommitmentRoot,
+}
+
+/// The historical record the host chain keeps of its own headers, keyed by height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistoricalInfo {
+    pub header: SelfHeader,
+}
+
+/// A context supplying the host chain's own recent history, so that a handler processing a
+/// counterparty's claims about this chain can check them rather than trust them outright.
+/// Parallel to `ClientReader`, which supplies a client's view of a *counterparty*.
+pub trait ChainReader {
+    /// Returns this chain's own historical record at `height`, or `None` if the chain never
+    /// reached that height or has since pruned it.
+    fn self_historical_info(&self, height: Height) -> Option<HistoricalInfo>;
+}
+
+/// A context supplying the write-only dependencies for a chain to record its own history.
+/// Parallel to `ClientKeeper`.
+pub trait ChainKeeper {
+    fn store_historical_info(&mut self, height: Height, info: HistoricalInfo) -> Result<(), Error>;
+}
@@ -0,0 +1,106 @@
+//! Mock contexts, used for testing the ICS2 handlers without needing a full chain
+//! implementation. `MockClientContext` mocks a client's view of a *counterparty* (the
+//! `ClientReader`/`ClientKeeper` dependencies); `MockChainContext` mocks the host chain's own
+//! historical record (the `ChainReader`/`ChainKeeper` dependencies). A handler may need either or
+//! both, since the two model different things.
+
+use std::collections::HashMap;
+
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::context::{ChainKeeper, ChainReader, ClientKeeper, ClientReader, HistoricalInfo};
+use crate::ics02_client::error::Error;
+use crate::ics02_client::state::ConsensusState;
+use crate::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// An in-memory stand-in for a client's view of the counterparty it tracks. Every client
+/// instance constructed in the handler tests is assumed to be the single client this mock
+/// services.
+#[derive(Clone, Debug)]
+pub struct MockClientContext {
+    pub client_type: Option<ClientType>,
+    pub client_states: HashMap<ClientId, AnyClientState>,
+    pub consensus_states: HashMap<Height, AnyConsensusState>,
+    pub host_height: Height,
+    pub host_timestamp: Timestamp,
+}
+
+impl ClientReader for MockClientContext {
+    fn client_type(&self, client_id: &ClientId) -> Option<ClientType> {
+        self.client_states.get(client_id)?;
+        self.client_type.clone()
+    }
+
+    fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState> {
+        self.client_states.get(client_id).cloned()
+    }
+
+    fn consensus_state(&self, _client_id: &ClientId, height: Height) -> Option<AnyConsensusState> {
+        self.consensus_states.get(&height).cloned()
+    }
+
+    fn host_height(&self) -> Height {
+        self.host_height
+    }
+
+    fn host_timestamp(&self) -> Timestamp {
+        self.host_timestamp
+    }
+}
+
+impl ClientKeeper for MockClientContext {
+    fn store_client_state(
+        &mut self,
+        client_id: ClientId,
+        client_state: AnyClientState,
+    ) -> Result<(), Error> {
+        self.client_states.insert(client_id, client_state);
+        Ok(())
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        _client_id: ClientId,
+        consensus_state: AnyConsensusState,
+    ) -> Result<(), Error> {
+        self.consensus_states
+            .insert(consensus_state.height(), consensus_state);
+        Ok(())
+    }
+}
+
+/// An in-memory stand-in for a host chain's own historical record.
+#[derive(Clone, Debug, Default)]
+pub struct MockChainContext {
+    history: HashMap<Height, HistoricalInfo>,
+}
+
+impl MockChainContext {
+    pub fn new() -> Self {
+        Self {
+            history: HashMap::new(),
+        }
+    }
+
+    /// Registers a historical record for `height` with this mock context.
+    pub fn with_historical_info(self, height: Height, info: HistoricalInfo) -> Self {
+        let mut history = self.history;
+        history.insert(height, info);
+        Self { history, ..self }
+    }
+}
+
+impl ChainReader for MockChainContext {
+    fn self_historical_info(&self, height: Height) -> Option<HistoricalInfo> {
+        self.history.get(&height).cloned()
+    }
+}
+
+impl ChainKeeper for MockChainContext {
+    fn store_historical_info(&mut self, height: Height, info: HistoricalInfo) -> Result<(), Error> {
+        self.history.insert(height, info);
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+#![allow(unreachable_code, unused_variables)]
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::{AnyClient, ClientDef};
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::handler::ClientEvent;
+use crate::ics02_client::state::ClientState;
+use crate::ics24_host::identifier::ClientId;
+
+/// A report of two headers, from different proposers, that both claim to extend the client's
+/// chain at the same height -- proof that the chain's validator set has equivocated. Unlike the
+/// misbehaviour `update_client::process` discovers incidentally (a relayer submits a header for
+/// a height it already has a consensus state for), this lets an operator freeze a client as soon
+/// as they've independently obtained the conflicting headers, without waiting for a relayer to
+/// resubmit one.
+#[derive(Clone, Debug)]
+pub struct MsgSubmitMisbehaviour {
+    pub client_id: ClientId,
+    pub header1: <AnyClient as ClientDef>::Header,
+    pub header2: <AnyClient as ClientDef>::Header,
+}
+
+#[derive(Debug)]
+pub struct MisbehaviourResult {
+    client_id: ClientId,
+    client_state: <AnyClient as ClientDef>::ClientState,
+}
+
+pub fn process(
+    ctx: &dyn ClientReader,
+    msg: MsgSubmitMisbehaviour,
+) -> HandlerResult<MisbehaviourResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let MsgSubmitMisbehaviour {
+        client_id,
+        header1,
+        header2,
+    } = msg;
+
+    let client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    if let Some(frozen_height) = client_state.frozen_height() {
+        return Err(Kind::ClientFrozen(client_id, frozen_height).into());
+    }
+
+    let frozen_client_state = client_state
+        .check_misbehaviour_headers(&header1, &header2)
+        .map_err(|_| Kind::HeaderVerificationFailure)?
+        .ok_or(Kind::MisbehaviourHeadersNotConflicting)?;
+
+    output.emit(ClientEvent::ClientMisbehaviour(client_id.clone()));
+
+    Ok(output.with_result(MisbehaviourResult {
+        client_id,
+        client_state: frozen_client_state,
+    }))
+}
+
+pub fn keep(
+    keeper: &mut dyn crate::ics02_client::context::ClientKeeper,
+    result: MisbehaviourResult,
+) -> Result<(), Error> {
+    keeper.store_client_state(result.client_id, result.client_state)
+}
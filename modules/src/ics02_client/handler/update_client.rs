@@ -33,16 +33,74 @@ pub fn process(
         .client_state(&client_id)
         .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
 
-    let latest_height = client_state.latest_height();
-    let consensus_state = ctx
-        .consensus_state(&client_id, latest_height)
-        .ok_or_else(|| Kind::ConsensusStateNotFound(client_id.clone(), latest_height))?;
-
-    // Use client_state to validate the new header against the latest consensus_state.
-    // This function will return the new client_state (its latest_height changed) and a
-    // consensus_state obtained from header. These will be later persisted by the keeper.
+    // A frozen client has equivocated in the past; refuse to move its state any further.
+    if let Some(frozen_height) = client_state.frozen_height() {
+        return Err(Kind::ClientFrozen(client_id, frozen_height).into());
+    }
+
+    // If a consensus state already exists at the incoming header's height, submitting this
+    // header is only valid if it derives the very same consensus state: anything else means two
+    // conflicting headers were signed for the same height, i.e. misbehaviour.
+    let header_height = header.height();
+    if let Some(existing_consensus_state) = ctx.consensus_state(&client_id, header_height) {
+        if let Some(frozen_client_state) = client_state
+            .check_misbehaviour_and_update_state(&existing_consensus_state, &header)
+            .map_err(|_| Kind::HeaderVerificationFailure)?
+        {
+            output.emit(ClientEvent::ClientMisbehaviour(client_id.clone()));
+
+            return Ok(output.with_result(UpdateClientResult {
+                client_id,
+                client_state: frozen_client_state,
+                consensus_state: existing_consensus_state,
+            }));
+        }
+    }
+
+    // The header names the height of the consensus state it was verified against, which need not
+    // be the client's current latest_height: a relayer may be catching up several headers at once
+    // (forward-skip), or filling in a height older than the latest one it already relayed
+    // (back-fill). Look up that specific trusted consensus state rather than assuming latest.
+    let trusted_height = header.trusted_height();
+    let trusted_consensus_state = ctx
+        .consensus_state(&client_id, trusted_height)
+        .ok_or_else(|| Kind::ConsensusStateNotFound(client_id.clone(), trusted_height))?;
+
+    // Reject the update once the trusted consensus state has aged past the client's configured
+    // trusting period: an out-of-period consensus state can no longer be assumed to reflect the
+    // counterparty chain's current validator set, so a header verified against it is unsafe to
+    // trust regardless of how valid its signatures are.
+    let host_timestamp = ctx.host_timestamp();
+    let trusting_period = client_state.trusting_period();
+    if let Some(elapsed) = host_timestamp.duration_since(&trusted_consensus_state.timestamp()) {
+        if elapsed > trusting_period {
+            return Err(Kind::ClientExpired {
+                elapsed,
+                trusting_period,
+            }
+            .into());
+        }
+    }
+
+    // A header claiming a timestamp from the future (beyond the client's allowed clock drift)
+    // would let a misbehaving proposer pre-date its own equivocation window; reject it outright.
+    if let Some(drift) = header.timestamp().duration_since(&host_timestamp) {
+        if drift > client_state.max_clock_drift() {
+            return Err(Kind::HeaderTimestampTooFarInFuture {
+                header_timestamp: header.timestamp(),
+                host_timestamp,
+            }
+            .into());
+        }
+    }
+
+    // Use client_state to validate the new header against the trusted consensus_state. This
+    // function will return the new client_state -- whose latest_height only advances if
+    // header_height exceeds the client's current latest_height, so a back-fill update stores a
+    // consensus state without regressing the client -- and a consensus_state obtained from
+    // header. These will be later persisted by the keeper.
     let (new_client_state, new_consensus_state) = client_state
-        .check_header_and_update_state(header)
+        .check_header_and_update_state(&trusted_consensus_state, header)
         .map_err(|_| Kind::HeaderVerificationFailure)?;
 
     output.emit(ClientEvent::ClientUpdated(client_id.clone()));
@@ -71,7 +129,9 @@ mod tests {
     use crate::ics02_client::context_mock::MockClientContext;
     use crate::mock_client::header::MockHeader;
     use crate::mock_client::state::{MockClientState, MockConsensusState};
+    use crate::timestamp::Timestamp;
     use std::collections::HashMap;
+    use std::time::Duration;
     use tendermint::block::Height;
 
     #[test]
@@ -80,18 +140,24 @@ mod tests {
             client_type: Some(ClientType::Tendermint),
             client_states: HashMap::with_capacity(1),
             consensus_states: HashMap::with_capacity(1),
+            host_height: Height(0),
+            host_timestamp: Timestamp::none(),
         };
 
         ctx.client_states.insert(
             "mockclient".parse().unwrap(),
-            MockClientState(MockHeader(Height(42))).into(),
+            MockClientState(MockHeader::new(Height(42))).into(),
+        );
+        ctx.consensus_states.insert(
+            Height(42),
+            MockConsensusState(MockHeader::new(Height(42))),
         );
-        ctx.consensus_states
-            .insert(Height(42), MockConsensusState(MockHeader(Height(42))));
 
         let msg = MsgUpdateAnyClient {
             client_id: "mockclient".parse().unwrap(),
-            header: MockHeader(Height(46)).into(),
+            header: MockHeader::new(Height(46))
+                .with_trusted_height(Height(42))
+                .into(),
         };
 
         let output = process(&ctx, msg.clone());
@@ -120,18 +186,24 @@ mod tests {
             client_type: Some(ClientType::Tendermint),
             client_states: HashMap::with_capacity(1),
             consensus_states: HashMap::with_capacity(1),
+            host_height: Height(0),
+            host_timestamp: Timestamp::none(),
         };
 
         ctx.client_states.insert(
             "mockclient1".parse().unwrap(),
-            MockClientState(MockHeader(Height(42))).into(),
+            MockClientState(MockHeader::new(Height(42))).into(),
+        );
+        ctx.consensus_states.insert(
+            Height(42),
+            MockConsensusState(MockHeader::new(Height(42))),
         );
-        ctx.consensus_states
-            .insert(Height(42), MockConsensusState(MockHeader(Height(42))));
 
         let msg = MsgUpdateAnyClient {
             client_id: "nonexistingclient".parse().unwrap(),
-            header: MockHeader(Height(46)).into(),
+            header: MockHeader::new(Height(46))
+                .with_trusted_height(Height(42))
+                .into(),
         };
 
         let output = process(&ctx, msg.clone());
@@ -161,23 +233,27 @@ mod tests {
             client_type: Some(ClientType::Tendermint),
             client_states: HashMap::with_capacity(client_ids.len()),
             consensus_states: HashMap::with_capacity(client_ids.len()),
+            host_height: Height(0),
+            host_timestamp: Timestamp::none(),
         };
 
         for cid in &client_ids {
             ctx.client_states.insert(
                 cid.clone(),
-                MockClientState(MockHeader(initial_height)).into(),
+                MockClientState(MockHeader::new(initial_height)).into(),
             );
             ctx.consensus_states.insert(
                 initial_height,
-                MockConsensusState(MockHeader(initial_height)),
+                MockConsensusState(MockHeader::new(initial_height)),
             );
         }
 
         for cid in &client_ids {
             let msg = MsgUpdateAnyClient {
                 client_id: cid.clone(),
-                header: MockHeader(update_height).into(),
+                header: MockHeader::new(update_height)
+                    .with_trusted_height(initial_height)
+                    .into(),
             };
 
             let output = process(&ctx, msg.clone());
@@ -200,4 +276,269 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_update_client_forward_skip() {
+        // The client's latest consensus state is at height 42, but the relayer has a header for
+        // height 50 that was verified against an intermediate consensus state at height 46 --
+        // never submitted on its own. `trusted_height` must be consulted rather than latest_height.
+        let initial_height = Height(42);
+        let trusted_height = Height(46);
+        let update_height = Height(50);
+
+        let mut ctx = MockClientContext {
+            client_type: Some(ClientType::Tendermint),
+            client_states: HashMap::with_capacity(1),
+            consensus_states: HashMap::with_capacity(2),
+            host_height: Height(0),
+            host_timestamp: Timestamp::none(),
+        };
+
+        ctx.client_states.insert(
+            "mockclient".parse().unwrap(),
+            MockClientState(MockHeader::new(initial_height)).into(),
+        );
+        ctx.consensus_states.insert(
+            initial_height,
+            MockConsensusState(MockHeader::new(initial_height)),
+        );
+        ctx.consensus_states.insert(
+            trusted_height,
+            MockConsensusState(MockHeader::new(trusted_height)),
+        );
+
+        let msg = MsgUpdateAnyClient {
+            client_id: "mockclient".parse().unwrap(),
+            header: MockHeader::new(update_height)
+                .with_trusted_height(trusted_height)
+                .into(),
+        };
+
+        let output = process(&ctx, msg.clone());
+
+        match output {
+            Ok(HandlerOutput {
+                result: _,
+                events,
+                log,
+            }) => {
+                assert_eq!(
+                    events,
+                    vec![ClientEvent::ClientUpdated(msg.client_id).into()]
+                );
+                assert!(log.is_empty());
+            }
+            Err(err) => {
+                panic!("unexpected error: {}", err);
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_client_missing_trusted_consensus_state() {
+        // The header claims to be trusted against a consensus state the client never stored.
+        let initial_height = Height(42);
+        let missing_trusted_height = Height(46);
+        let update_height = Height(50);
+
+        let mut ctx = MockClientContext {
+            client_type: Some(ClientType::Tendermint),
+            client_states: HashMap::with_capacity(1),
+            consensus_states: HashMap::with_capacity(1),
+            host_height: Height(0),
+            host_timestamp: Timestamp::none(),
+        };
+
+        ctx.client_states.insert(
+            "mockclient".parse().unwrap(),
+            MockClientState(MockHeader::new(initial_height)).into(),
+        );
+        ctx.consensus_states.insert(
+            initial_height,
+            MockConsensusState(MockHeader::new(initial_height)),
+        );
+
+        let msg = MsgUpdateAnyClient {
+            client_id: "mockclient".parse().unwrap(),
+            header: MockHeader::new(update_height)
+                .with_trusted_height(missing_trusted_height)
+                .into(),
+        };
+
+        let output = process(&ctx, msg.clone());
+
+        match output {
+            Ok(_) => {
+                panic!("unexpected success (expected error)");
+            }
+            Err(err) => {
+                assert_eq!(
+                    err.kind(),
+                    &Kind::ConsensusStateNotFound(msg.client_id, missing_trusted_height)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_client_backfill_does_not_regress_latest_height() {
+        // The client's latest_height is already 50; the relayer submits a header for an older
+        // height (40) trusted against an even older consensus state (30) that it happens to be
+        // filling in. This must succeed and must not move latest_height backwards.
+        let latest_height = Height(50);
+        let trusted_height = Height(30);
+        let backfill_height = Height(40);
+
+        let mut ctx = MockClientContext {
+            client_type: Some(ClientType::Tendermint),
+            client_states: HashMap::with_capacity(1),
+            consensus_states: HashMap::with_capacity(2),
+            host_height: Height(0),
+            host_timestamp: Timestamp::none(),
+        };
+
+        ctx.client_states.insert(
+            "mockclient".parse().unwrap(),
+            MockClientState(MockHeader::new(latest_height)).into(),
+        );
+        ctx.consensus_states.insert(
+            latest_height,
+            MockConsensusState(MockHeader::new(latest_height)),
+        );
+        ctx.consensus_states.insert(
+            trusted_height,
+            MockConsensusState(MockHeader::new(trusted_height)),
+        );
+
+        let msg = MsgUpdateAnyClient {
+            client_id: "mockclient".parse().unwrap(),
+            header: MockHeader::new(backfill_height)
+                .with_trusted_height(trusted_height)
+                .into(),
+        };
+
+        let output = process(&ctx, msg.clone());
+
+        match output {
+            Ok(HandlerOutput {
+                result,
+                events,
+                log,
+            }) => {
+                assert_eq!(
+                    events,
+                    vec![ClientEvent::ClientUpdated(msg.client_id).into()]
+                );
+                assert!(log.is_empty());
+                // The new client_state must still report the pre-existing latest_height, not the
+                // lower backfill_height the header itself was verified at.
+                assert_eq!(result.client_state.latest_height(), latest_height);
+            }
+            Err(err) => {
+                panic!("unexpected error: {}", err);
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_client_trusting_period_expired() {
+        // The trusted consensus state is 30 days older than the host clock -- comfortably past
+        // any plausible trusting period -- so the update must be rejected as expired rather than
+        // verified against a consensus state that's no longer safe to trust.
+        let trusted_height = Height(42);
+        let update_height = Height(46);
+
+        let consensus_timestamp = Timestamp::from_nanoseconds(0);
+        let host_timestamp =
+            Timestamp::from_nanoseconds(Duration::from_secs(30 * 24 * 60 * 60).as_nanos() as u64);
+
+        let mut ctx = MockClientContext {
+            client_type: Some(ClientType::Tendermint),
+            client_states: HashMap::with_capacity(1),
+            consensus_states: HashMap::with_capacity(1),
+            host_height: Height(0),
+            host_timestamp,
+        };
+
+        ctx.client_states.insert(
+            "mockclient".parse().unwrap(),
+            MockClientState(MockHeader::new(trusted_height)).into(),
+        );
+        ctx.consensus_states.insert(
+            trusted_height,
+            MockConsensusState(MockHeader::new(trusted_height).with_timestamp(consensus_timestamp)),
+        );
+
+        let msg = MsgUpdateAnyClient {
+            client_id: "mockclient".parse().unwrap(),
+            header: MockHeader::new(update_height)
+                .with_trusted_height(trusted_height)
+                // Matches the host clock so this case only exercises trusting-period expiry, not
+                // the separate future-timestamp clock-drift check below.
+                .with_timestamp(host_timestamp)
+                .into(),
+        };
+
+        let output = process(&ctx, msg.clone());
+
+        match output {
+            Ok(_) => {
+                panic!("unexpected success (expected trusting period expiry)");
+            }
+            Err(err) => {
+                assert!(matches!(err.kind(), Kind::ClientExpired { .. }));
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_client_header_timestamp_too_far_in_future() {
+        // The header's own timestamp is 30 days ahead of the host clock -- comfortably past any
+        // plausible clock-drift tolerance -- so it must be rejected outright.
+        let trusted_height = Height(42);
+        let update_height = Height(46);
+
+        let host_timestamp = Timestamp::from_nanoseconds(0);
+        let header_timestamp =
+            Timestamp::from_nanoseconds(Duration::from_secs(30 * 24 * 60 * 60).as_nanos() as u64);
+
+        let mut ctx = MockClientContext {
+            client_type: Some(ClientType::Tendermint),
+            client_states: HashMap::with_capacity(1),
+            consensus_states: HashMap::with_capacity(1),
+            host_height: Height(0),
+            host_timestamp,
+        };
+
+        ctx.client_states.insert(
+            "mockclient".parse().unwrap(),
+            MockClientState(MockHeader::new(trusted_height)).into(),
+        );
+        ctx.consensus_states.insert(
+            trusted_height,
+            MockConsensusState(MockHeader::new(trusted_height).with_timestamp(host_timestamp)),
+        );
+
+        let msg = MsgUpdateAnyClient {
+            client_id: "mockclient".parse().unwrap(),
+            header: MockHeader::new(update_height)
+                .with_trusted_height(trusted_height)
+                .with_timestamp(header_timestamp)
+                .into(),
+        };
+
+        let output = process(&ctx, msg.clone());
+
+        match output {
+            Ok(_) => {
+                panic!("unexpected success (expected future-timestamp rejection)");
+            }
+            Err(err) => {
+                assert!(matches!(
+                    err.kind(),
+                    Kind::HeaderTimestampTooFarInFuture { .. }
+                ));
+            }
+        }
+    }
 }
@@ -0,0 +1,226 @@
+//! Handles a client upgrade driven by a chain upgrade: the counterparty chain went through a
+//! governance-voted upgrade, bumping its chain-id revision and resetting its block height, and
+//! the relayer is submitting the resulting upgraded client/consensus state along with membership
+//! proofs that the upgrade was actually committed on the old chain.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::{AnyClient, ClientDef};
+use crate::ics02_client::context::{ChainReader, ClientReader};
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::handler::ClientEvent;
+use crate::ics02_client::state::{ClientState, ConsensusState};
+use crate::ics23_commitment::commitment::CommitmentProofBytes;
+use crate::ics24_host::identifier::ClientId;
+
+#[derive(Clone, Debug)]
+pub struct MsgUpgradeAnyClient {
+    pub client_id: ClientId,
+    pub client_state: <AnyClient as ClientDef>::ClientState,
+    pub consensus_state: <AnyClient as ClientDef>::ConsensusState,
+    pub proof_upgrade_client: CommitmentProofBytes,
+    pub proof_upgrade_consensus_state: CommitmentProofBytes,
+}
+
+#[derive(Debug)]
+pub struct UpgradeClientResult {
+    client_id: ClientId,
+    client_state: <AnyClient as ClientDef>::ClientState,
+    consensus_state: <AnyClient as ClientDef>::ConsensusState,
+}
+
+pub fn process(
+    ctx: &dyn ClientReader,
+    chain_ctx: &dyn ChainReader,
+    msg: MsgUpgradeAnyClient,
+) -> HandlerResult<UpgradeClientResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let MsgUpgradeAnyClient {
+        client_id,
+        client_state: upgraded_client_state,
+        consensus_state: upgraded_consensus_state,
+        proof_upgrade_client,
+        proof_upgrade_consensus_state,
+    } = msg;
+
+    let old_client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    if let Some(frozen_height) = old_client_state.frozen_height() {
+        return Err(Kind::ClientFrozen(client_id, frozen_height).into());
+    }
+
+    // `verify_upgrade_and_update_state` both checks the two membership proofs against the old
+    // client's current consensus state root, and rejects the upgrade unless the upgraded client
+    // state's revision (per `Height::is_later_revision_than`) is strictly greater than the old
+    // one's -- accepting an upgrade at the current or an earlier revision would let a stale or
+    // replayed upgrade proof roll the client's assumptions backward.
+    let (new_client_state, new_consensus_state) = old_client_state
+        .verify_upgrade_and_update_state(
+            upgraded_client_state,
+            upgraded_consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+        )
+        .map_err(|_| Kind::UpgradeVerificationFailure)?;
+
+    // If the host chain's own historical record happens to cover the same height the upgraded
+    // consensus state now reports, the embedded root must agree with what the host itself
+    // recorded there. A disagreement can only mean the client being upgraded is (mistakenly or
+    // maliciously) asserting self-consensus assumptions this chain never actually had; if the
+    // host has no record that far back (the ordinary case, since the client tracks a distinct
+    // counterparty chain with its own, unrelated height numbering), there is nothing to check.
+    if let Some(historical_info) = chain_ctx.self_historical_info(new_consensus_state.height()) {
+        if historical_info.header.root != new_consensus_state.root() {
+            return Err(Kind::SelfConsensusStateMismatch(new_consensus_state.height()).into());
+        }
+    }
+
+    output.emit(ClientEvent::ClientUpgraded(client_id.clone()));
+
+    Ok(output.with_result(UpgradeClientResult {
+        client_id,
+        client_state: new_client_state,
+        consensus_state: new_consensus_state,
+    }))
+}
+
+pub fn keep(
+    keeper: &mut dyn crate::ics02_client::context::ClientKeeper,
+    result: UpgradeClientResult,
+) -> Result<(), Error> {
+    keeper.store_client_state(result.client_id.clone(), result.client_state)?;
+    keeper.store_consensus_state(result.client_id, result.consensus_state)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::{HistoricalInfo, SelfHeader};
+    use crate::ics02_client::context_mock::{MockChainContext, MockClientContext};
+    use crate::ics23_commitment::commitment::CommitmentRoot;
+    use crate::mock_client::header::MockHeader;
+    use crate::mock_client::state::{MockClientState, MockConsensusState};
+    use crate::timestamp::Timestamp;
+    use std::collections::HashMap;
+    use tendermint::block::Height as BlockHeight;
+
+    #[test]
+    fn test_upgrade_client_ok() {
+        // Mirrors the state transition `update_client::process` exercises: the mock client is
+        // upgraded in place, re-using the same mock header/consensus-state shapes, but through
+        // the upgrade path instead of the ordinary header-verification path.
+        let old_height = BlockHeight(42);
+
+        let mut ctx = MockClientContext {
+            client_type: Some(ClientType::Tendermint),
+            client_states: HashMap::with_capacity(1),
+            consensus_states: HashMap::with_capacity(1),
+            host_height: BlockHeight(0),
+            host_timestamp: Timestamp::none(),
+        };
+
+        ctx.client_states.insert(
+            "mockclient".parse().unwrap(),
+            MockClientState(MockHeader::new(old_height)).into(),
+        );
+        ctx.consensus_states.insert(
+            old_height,
+            MockConsensusState(MockHeader::new(old_height)),
+        );
+
+        let upgraded_height = BlockHeight(0);
+        let msg = MsgUpgradeAnyClient {
+            client_id: "mockclient".parse().unwrap(),
+            client_state: MockClientState(MockHeader::new(upgraded_height)).into(),
+            consensus_state: MockConsensusState(MockHeader::new(upgraded_height)).into(),
+            proof_upgrade_client: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+            proof_upgrade_consensus_state: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+        };
+
+        // Empty: the host has no historical record covering the upgraded consensus state's
+        // height, so the new self-consensus cross-check has nothing to compare against and is a
+        // no-op for this test, same as every other pre-existing case here.
+        let chain_ctx = MockChainContext::new();
+
+        let output = process(&ctx, &chain_ctx, msg.clone());
+
+        match output {
+            Ok(HandlerOutput {
+                result: _,
+                events,
+                log,
+            }) => {
+                assert_eq!(
+                    events,
+                    vec![ClientEvent::ClientUpgraded(msg.client_id).into()]
+                );
+                assert!(log.is_empty());
+            }
+            Err(err) => {
+                panic!("unexpected error: {}", err);
+            }
+        }
+    }
+
+    #[test]
+    fn test_upgrade_client_self_consensus_state_mismatch() {
+        // The host's own historical record at the upgraded consensus state's height disagrees
+        // with the root the upgrade asserts for that same height -- the upgrade must be rejected
+        // rather than silently adopting a self-consensus assumption the host never actually had.
+        let old_height = BlockHeight(42);
+
+        let mut ctx = MockClientContext {
+            client_type: Some(ClientType::Tendermint),
+            client_states: HashMap::with_capacity(1),
+            consensus_states: HashMap::with_capacity(1),
+            host_height: BlockHeight(0),
+            host_timestamp: Timestamp::none(),
+        };
+
+        ctx.client_states.insert(
+            "mockclient".parse().unwrap(),
+            MockClientState(MockHeader::new(old_height)).into(),
+        );
+        ctx.consensus_states.insert(
+            old_height,
+            MockConsensusState(MockHeader::new(old_height)),
+        );
+
+        let upgraded_height = BlockHeight(0);
+        let msg = MsgUpgradeAnyClient {
+            client_id: "mockclient".parse().unwrap(),
+            client_state: MockClientState(MockHeader::new(upgraded_height)).into(),
+            consensus_state: MockConsensusState(MockHeader::new(upgraded_height)).into(),
+            proof_upgrade_client: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+            proof_upgrade_consensus_state: CommitmentProofBytes::try_from(vec![1]).unwrap(),
+        };
+
+        let conflicting_root = CommitmentRoot::from_bytes(vec![0xff]);
+        let chain_ctx = MockChainContext::new().with_historical_info(
+            upgraded_height,
+            HistoricalInfo {
+                header: SelfHeader {
+                    height: upgraded_height,
+                    timestamp: Timestamp::none(),
+                    root: conflicting_root,
+                },
+            },
+        );
+
+        let output = process(&ctx, &chain_ctx, msg.clone());
+
+        match output {
+            Ok(_) => {
+                panic!("unexpected success (expected self-consensus state mismatch)");
+            }
+            Err(err) => {
+                assert!(matches!(err.kind(), Kind::SelfConsensusStateMismatch(_)));
+            }
+        }
+    }
+}
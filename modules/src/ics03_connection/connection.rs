@@ -0,0 +1,220 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use ibc_proto::ibc::commitment::MerklePrefix;
+use ibc_proto::ibc::connection::Counterparty as RawCounterparty;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::ics03_connection::error::{Error, Kind};
+use crate::ics03_connection::exported::ConnectionCounterparty;
+use crate::ics23_commitment::CommitmentPrefix;
+use crate::ics24_host::identifier::{ClientId, ConnectionId};
+
+/// Enumeration of proposed connection states.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum State {
+    Uninitialized = 0,
+    Init = 1,
+    TryOpen = 2,
+    Open = 3,
+}
+
+impl State {
+    /// Yields the State as a string.
+    pub fn as_string(&self) -> &'static str {
+        match self {
+            Self::Uninitialized => "UNINITIALIZED",
+            Self::Init => "INIT",
+            Self::TryOpen => "TRYOPEN",
+            Self::Open => "OPEN",
+        }
+    }
+
+    /// Returns whether or not this connection state is `Open`.
+    pub fn is_open(self) -> bool {
+        self == State::Open
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionEnd {
+    state: State,
+    client_id: ClientId,
+    counterparty: Counterparty,
+    versions: Vec<String>,
+    delay_period: Duration,
+}
+
+impl ConnectionEnd {
+    pub fn new(
+        state: State,
+        client_id: ClientId,
+        counterparty: Counterparty,
+        versions: Vec<String>,
+        delay_period: Duration,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            state,
+            client_id,
+            counterparty,
+            versions: validate_versions(versions).map_err(|e| Kind::InvalidVersion.context(e))?,
+            delay_period,
+        })
+    }
+
+    /// Getter for the state of this connection end.
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Setter for the state of this connection end.
+    pub fn set_state(&mut self, new_state: State) {
+        self.state = new_state;
+    }
+
+    /// Setter for the version of this connection end.
+    pub fn set_version(&mut self, new_version: String) {
+        self.versions = vec![new_version];
+    }
+
+    /// Getter for the client id on the local party of this connection end.
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    /// Getter for the list of versions in this connection end.
+    pub fn versions(&self) -> Vec<String> {
+        self.versions.clone()
+    }
+
+    /// Getter for the counterparty of this connection end.
+    pub fn counterparty(&self) -> Counterparty {
+        self.counterparty.clone()
+    }
+
+    /// Getter for the delay period that must elapse, both in terms of chain time and number of
+    /// blocks, before a packet relying on this connection can be processed.
+    pub fn delay_period(&self) -> Duration {
+        self.delay_period
+    }
+
+    /// Checks if the state of this connection end matches `other`.
+    pub fn state_matches(&self, other: &State) -> bool {
+        self.state.eq(other)
+    }
+
+    /// Checks if the counterparty of this connection end matches `other`.
+    pub fn counterparty_matches(&self, other: &Counterparty) -> bool {
+        self.counterparty.eq(other)
+    }
+
+    /// Checks if the client id of this connection end matches `other`.
+    pub fn client_id_matches(&self, other: &ClientId) -> bool {
+        self.client_id.eq(other)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Counterparty {
+    client_id: ClientId,
+    connection_id: ConnectionId,
+    prefix: CommitmentPrefix,
+}
+
+impl Counterparty {
+    pub fn new(
+        client_id: ClientId,
+        connection_id: ConnectionId,
+        prefix: CommitmentPrefix,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            client_id,
+            connection_id,
+            prefix,
+        })
+    }
+
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.prefix.is_empty() {
+            return Err(Kind::InvalidCounterparty.into());
+        }
+        Ok(())
+    }
+
+    /// Getter for the client id.
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    /// Getter for the connection id.
+    pub fn connection_id(&self) -> &ConnectionId {
+        &self.connection_id
+    }
+
+    /// Getter for the commitment prefix used by the counterparty.
+    pub fn prefix(&self) -> &CommitmentPrefix {
+        &self.prefix
+    }
+}
+
+impl ConnectionCounterparty for Counterparty {
+    fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    fn connection_id(&self) -> &ConnectionId {
+        &self.connection_id
+    }
+
+    fn prefix(&self) -> &CommitmentPrefix {
+        &self.prefix
+    }
+}
+
+impl TryFrom<RawCounterparty> for Counterparty {
+    type Error = Error;
+
+    fn try_from(raw: RawCounterparty) -> Result<Self, Self::Error> {
+        Counterparty::new(
+            raw.client_id
+                .parse()
+                .map_err(|e| Kind::IdentifierError.context(e))?,
+            raw.connection_id
+                .parse()
+                .map_err(|e| Kind::IdentifierError.context(e))?,
+            raw.prefix.map(|p| p.key_prefix).unwrap_or_default().into(),
+        )
+    }
+}
+
+impl From<Counterparty> for RawCounterparty {
+    fn from(counterparty: Counterparty) -> Self {
+        RawCounterparty {
+            client_id: counterparty.client_id.to_string(),
+            connection_id: counterparty.connection_id.to_string(),
+            prefix: Some(MerklePrefix {
+                key_prefix: counterparty.prefix.as_bytes().to_vec(),
+            }),
+        }
+    }
+}
+
+/// Validates that a proposed connection version is a non-empty string.
+pub fn validate_version(version: String) -> Result<String, String> {
+    if version.trim().is_empty() {
+        return Err("version cannot be empty".to_string());
+    }
+    Ok(version)
+}
+
+/// Validates a list of proposed versions, requiring it to be non-empty and every entry to be
+/// a non-empty string.
+pub fn validate_versions(versions: Vec<String>) -> Result<Vec<String>, String> {
+    if versions.is_empty() {
+        return Err("versions cannot be empty".to_string());
+    }
+    for v in versions.iter() {
+        validate_version(v.clone())?;
+    }
+    Ok(versions)
+}
@@ -0,0 +1,76 @@
+//! ICS3 (connection) context. The two traits `ConnectionReader` and `ConnectionKeeper` define
+//! the interface that any host chain must implement to be able to process any connection
+//! handshake message.
+
+use std::time::Duration;
+
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::error::Error;
+use crate::ics23_commitment::CommitmentPrefix;
+use crate::ics24_host::identifier::{ClientId, ConnectionId};
+use crate::Height;
+
+/// A context supplying all the necessary read-only dependencies for processing any connection
+/// handshake message.
+pub trait ConnectionReader {
+    /// Returns the ConnectionEnd for the given identifier `conn_id`.
+    fn fetch_connection_end(&self, conn_id: &ConnectionId) -> Option<ConnectionEnd>;
+
+    /// Returns the ClientState for the given identifier `client_id`.
+    fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState>;
+
+    /// Returns the ConsensusState that the given client stores at a specific height.
+    fn client_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Option<AnyConsensusState>;
+
+    /// Returns the prefix that the local chain uses in the KV store to identify the IBC-related
+    /// paths (e.g. connections, channels).
+    fn commitment_prefix(&self) -> CommitmentPrefix;
+
+    /// Returns the most recent height of the local chain.
+    fn host_current_height(&self) -> Height;
+
+    /// Returns the oldest height still available on the local chain, i.e. the lowest height for
+    /// which a consensus state is still stored.
+    fn host_oldest_height(&self) -> Height;
+
+    /// Returns the connection versions that the local chain supports.
+    fn get_compatible_versions(&self) -> Vec<String>;
+
+    /// Picks one version, from a list of candidates, that both parties agree on.
+    fn pick_version(&self, candidates: Vec<String>) -> String;
+
+    /// Returns a counter on how many connections have been created thus far. The value of this
+    /// counter should increase only via `ConnectionKeeper::increase_connection_counter`.
+    fn connection_counter(&self) -> u64;
+
+    /// Returns the upper bound on the amount of time an honest host chain can take to produce
+    /// the next block, used to convert a connection's `delay_period` into a number of blocks
+    /// during delay verification. Implementations may override this with a chain-specific value;
+    /// the default is a conservative estimate.
+    fn max_expected_time_per_block(&self) -> Duration {
+        Duration::from_secs(30)
+    }
+}
+
+/// A context supplying all the necessary write-only dependencies (i.e. storage writing facility)
+/// for processing any connection handshake message.
+pub trait ConnectionKeeper {
+    fn store_connection(
+        &mut self,
+        connection_id: ConnectionId,
+        connection_end: &ConnectionEnd,
+    ) -> Result<(), Error>;
+
+    fn store_connection_to_client(
+        &mut self,
+        connection_id: ConnectionId,
+        client_id: &ClientId,
+    ) -> Result<(), Error>;
+
+    fn increase_connection_counter(&mut self);
+}
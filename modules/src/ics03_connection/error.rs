@@ -0,0 +1,79 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+use crate::ics24_host::identifier::{ClientId, ConnectionId};
+use crate::Height;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("connection state unknown")]
+    UninitializedConnection,
+
+    #[error("connection identifier or client identifier is invalid")]
+    IdentifierError,
+
+    #[error("connection end for identifier {0} was never initialized")]
+    ConnectionNotFound(ConnectionId),
+
+    #[error("connection end for identifier {0} does not match the one in the handshake message")]
+    ConnectionMismatch(ConnectionId),
+
+    #[error("counterparty provided in the message is invalid")]
+    InvalidCounterparty,
+
+    #[error("the message is missing a counterparty")]
+    MissingCounterparty,
+
+    #[error("the client targeted by the message could not be found")]
+    MissingClient,
+
+    #[error("the client state for client {0} could not be found")]
+    MissingClientState(ClientId),
+
+    #[error("the client {0} is frozen")]
+    FrozenClient(ClientId),
+
+    #[error("the consensus state for client {0} at height {1} could not be found")]
+    MissingClientConsensusState(ClientId, Height),
+
+    #[error("the proposed connection version is invalid")]
+    InvalidVersion,
+
+    #[error("version identifier `{0}` is supported, but none of its proposed features are")]
+    NoCommonFeatures(String),
+
+    #[error("the proof height or consensus height in the message cannot be zero")]
+    InvalidHeight,
+
+    #[error("the message is missing a proof height")]
+    MissingProofHeight,
+
+    #[error("the message is missing a consensus height")]
+    MissingConsensusHeight,
+
+    #[error("the supplied proof could not be verified against the expected connection end")]
+    InvalidProof,
+
+    #[error("the connection state proof does not verify against the counterparty's stored connection end")]
+    ConnectionProofVerificationFailed,
+
+    #[error("the consensus state proof does not verify against the counterparty's stored consensus state")]
+    ConsensusStateProofVerificationFailed,
+
+    #[error("the connection's delay period has not yet elapsed")]
+    ConnectionDelayNotElapsed,
+
+    #[error("the message is missing a required proof")]
+    MissingProof,
+
+    #[error("the signer in the message is invalid")]
+    InvalidSigner,
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
@@ -0,0 +1,17 @@
+//! Re-exportable abstractions over the counterparty side of a connection, so that other ICS
+//! modules (e.g. ICS4 channel) can refer to "the other end of a connection" without depending on
+//! the concrete `Counterparty` struct.
+
+use crate::ics23_commitment::CommitmentPrefix;
+use crate::ics24_host::identifier::{ClientId, ConnectionId};
+
+pub trait ConnectionCounterparty {
+    /// The client id on the counterparty chain, which tracks this chain.
+    fn client_id(&self) -> &ClientId;
+
+    /// The connection id on the counterparty chain, if known.
+    fn connection_id(&self) -> &ConnectionId;
+
+    /// The commitment prefix used by the counterparty chain.
+    fn prefix(&self) -> &CommitmentPrefix;
+}
@@ -0,0 +1,67 @@
+//! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenConfirm`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics03_connection::error::{Error, Kind};
+use crate::ics03_connection::handler::verify::verify_proofs;
+use crate::ics03_connection::handler::ConnectionEvent::ConnOpenConfirm;
+use crate::ics03_connection::handler::ConnectionResult;
+use crate::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+
+pub(crate) fn process(
+    ctx: &dyn ConnectionReader,
+    msg: MsgConnectionOpenConfirm,
+) -> HandlerResult<ConnectionResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    // Unwrap the old connection end & validate it is in a state where `Confirm` is expected.
+    let mut new_connection_end = match ctx.fetch_connection_end(msg.connection_id()) {
+        Some(old_conn_end) => {
+            if old_conn_end.state_matches(&State::TryOpen) {
+                old_conn_end
+            } else {
+                return Err(Kind::ConnectionMismatch(msg.connection_id().clone()).into());
+            }
+        }
+        None => return Err(Kind::ConnectionNotFound(msg.connection_id().clone()).into()),
+    };
+
+    // Proof verification in two steps:
+    // 1. Setup: build the ConnectionEnd as we expect to find it on the other party.
+    let expected_conn = ConnectionEnd::new(
+        State::Open,
+        new_connection_end.counterparty().client_id().clone(),
+        Counterparty::new(
+            new_connection_end.client_id().clone(),
+            msg.connection_id().clone(),
+            ctx.commitment_prefix(),
+        )?,
+        new_connection_end.versions(),
+        new_connection_end.delay_period(),
+    )?;
+
+    // 2. Pass the details to the verification function.
+    verify_proofs(
+        ctx,
+        msg.connection_id(),
+        None,
+        &new_connection_end,
+        &expected_conn,
+        msg.proofs(),
+    )?;
+
+    // Transition the connection end to the new state.
+    new_connection_end.set_state(State::Open);
+
+    output.log("success: connection verification passed");
+
+    let result = ConnectionResult {
+        connection_id: msg.connection_id().clone(),
+        connection_end: new_connection_end,
+    };
+
+    output.emit(ConnOpenConfirm(result.clone()));
+
+    Ok(output.with_result(result))
+}
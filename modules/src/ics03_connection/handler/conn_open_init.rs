@@ -0,0 +1,41 @@
+//! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenInit`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics03_connection::connection::{ConnectionEnd, State};
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics03_connection::error::{Error, Kind};
+use crate::ics03_connection::handler::ConnectionEvent::ConnOpenInit;
+use crate::ics03_connection::handler::ConnectionResult;
+use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+
+pub(crate) fn process(
+    ctx: &dyn ConnectionReader,
+    msg: MsgConnectionOpenInit,
+) -> HandlerResult<ConnectionResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    // An `Init` message must create a brand new connection end; reject if one already exists for
+    // this identifier.
+    if ctx.fetch_connection_end(msg.connection_id()).is_some() {
+        return Err(Kind::ConnectionMismatch(msg.connection_id().clone()).into());
+    }
+
+    let new_connection_end = ConnectionEnd::new(
+        State::Init,
+        msg.client_id().clone(),
+        msg.counterparty(),
+        ctx.get_compatible_versions(),
+        msg.delay_period(),
+    )?;
+
+    output.log("success: no connection state to validate");
+
+    let result = ConnectionResult {
+        connection_id: msg.connection_id().clone(),
+        connection_end: new_connection_end,
+    };
+
+    output.emit(ConnOpenInit(result.clone()));
+
+    Ok(output.with_result(result))
+}
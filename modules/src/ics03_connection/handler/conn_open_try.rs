@@ -41,6 +41,7 @@ pub(crate) fn process(
             msg.client_id().clone(),
             msg.counterparty(),
             msg.counterparty_versions(),
+            msg.delay_period(),
         )?),
     }?;
 
@@ -55,6 +56,7 @@ pub(crate) fn process(
             ctx.commitment_prefix(),
         )?,
         msg.counterparty_versions(),
+        msg.delay_period(),
     )?;
 
     // 2. Pass the details to the verification function.
@@ -123,6 +125,7 @@ mod tests {
             dummy_msg.client_id().clone(),
             dummy_msg.counterparty(),
             default_context.get_compatible_versions(),
+            dummy_msg.delay_period(),
         )
         .unwrap();
 
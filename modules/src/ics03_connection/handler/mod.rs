@@ -0,0 +1,82 @@
+//! This module implements the processing logic for ICS3 (connection) messages.
+
+use crate::handler::{Event, EventType, HandlerOutput};
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
+use crate::ics03_connection::error::Error;
+use crate::ics03_connection::msgs::ConnectionMsg;
+use crate::ics24_host::identifier::ConnectionId;
+
+pub mod conn_open_ack;
+pub mod conn_open_confirm;
+pub mod conn_open_init;
+pub mod conn_open_try;
+pub mod verify;
+
+/// The result of processing any connection handshake message: the connection identifier together
+/// with the (possibly freshly created, possibly updated) connection end.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectionResult {
+    pub connection_id: ConnectionId,
+    pub connection_end: ConnectionEnd,
+}
+
+/// The events that a connection handshake handler can emit, one per message kind.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    ConnOpenInit(ConnectionResult),
+    ConnOpenTry(ConnectionResult),
+    ConnOpenAck(ConnectionResult),
+    ConnOpenConfirm(ConnectionResult),
+}
+
+impl From<ConnectionEvent> for Event {
+    fn from(event: ConnectionEvent) -> Self {
+        let (tpe, result) = match event {
+            ConnectionEvent::ConnOpenInit(result) => ("connection_open_init", result),
+            ConnectionEvent::ConnOpenTry(result) => ("connection_open_try", result),
+            ConnectionEvent::ConnOpenAck(result) => ("connection_open_ack", result),
+            ConnectionEvent::ConnOpenConfirm(result) => ("connection_open_confirm", result),
+        };
+
+        Event::new(
+            EventType::Custom(tpe.to_string()),
+            vec![
+                ("connection_id".to_string(), result.connection_id.to_string()),
+                ("client_id".to_string(), result.connection_end.client_id().to_string()),
+            ],
+        )
+    }
+}
+
+/// General entry point for processing any message of type `ConnectionMsg`.
+pub fn dispatch<Ctx>(
+    ctx: &mut Ctx,
+    msg: ConnectionMsg,
+) -> Result<HandlerOutput<ConnectionResult>, Error>
+where
+    Ctx: ConnectionReader + ConnectionKeeper,
+{
+    let output = match msg {
+        ConnectionMsg::ConnectionOpenInit(msg) => conn_open_init::process(&*ctx, *msg)?,
+        ConnectionMsg::ConnectionOpenTry(msg) => conn_open_try::process(&*ctx, *msg)?,
+        ConnectionMsg::ConnectionOpenAck(msg) => conn_open_ack::process(&*ctx, *msg)?,
+        ConnectionMsg::ConnectionOpenConfirm(msg) => conn_open_confirm::process(&*ctx, *msg)?,
+    };
+
+    // The handshake step validated and produced a new (or updated) connection end; persist it,
+    // and bump the connection counter only when this step created the end.
+    let created = ctx
+        .fetch_connection_end(&output.result.connection_id)
+        .is_none();
+    ctx.store_connection(output.result.connection_id.clone(), &output.result.connection_end)?;
+    ctx.store_connection_to_client(
+        output.result.connection_id.clone(),
+        output.result.connection_end.client_id(),
+    )?;
+    if created {
+        ctx.increase_connection_counter();
+    }
+
+    Ok(output)
+}
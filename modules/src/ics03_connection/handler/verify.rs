@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use tendermint::Time;
+
+use crate::ics02_client::client_def::{AnyClient, AnyClientState, ClientDef};
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics03_connection::error::{Error, Kind};
+use crate::ics24_host::identifier::ConnectionId;
+use crate::proofs::Proofs;
+use crate::Height;
+
+/// Verifies the connection-state proof (`proofs.object_proof()`) bundled in an ICS3 message
+/// against `expected_conn`, after checking that the client is not frozen and holds a consensus
+/// state at the proof height. The client-state and consensus-state proofs the message may also
+/// carry are not verified.
+pub fn verify_proofs(
+    ctx: &dyn ConnectionReader,
+    connection_id: &ConnectionId,
+    client_state: Option<AnyClientState>,
+    connection_end: &ConnectionEnd,
+    expected_conn: &ConnectionEnd,
+    proofs: &Proofs,
+) -> Result<(), Error> {
+    let client_id = connection_end.client_id().clone();
+
+    let client_state = client_state
+        .or_else(|| ctx.client_state(&client_id))
+        .ok_or_else(|| Kind::MissingClientState(client_id.clone()))?;
+
+    // The client must not be frozen.
+    if client_state.is_frozen() {
+        return Err(Kind::FrozenClient(client_id).into());
+    }
+
+    if ctx
+        .client_consensus_state(&client_id, proofs.height())
+        .is_none()
+    {
+        return Err(Kind::MissingClientConsensusState(client_id, proofs.height()).into());
+    }
+
+    let client_def = AnyClient::from_client_type(client_state.client_type());
+
+    // Verify the proof for the connection state against the expected connection end.
+    client_def
+        .verify_connection_state(
+            &client_state,
+            proofs.height(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            connection_id,
+            expected_conn,
+        )
+        .map_err(|_| Kind::InvalidProof.into())
+}
+
+/// Checks that the consensus height (i.e., the height of the counterparty chain, as reported in
+/// the message by the sender) is not advanced relative to the local (host chain) current height,
+/// nor older than the oldest height still available on the local chain.
+pub fn check_client_consensus_height(
+    ctx: &dyn ConnectionReader,
+    claimed_height: crate::Height,
+) -> Result<(), Error> {
+    if claimed_height > ctx.host_current_height() {
+        return Err(Kind::InvalidHeight.into());
+    }
+
+    if claimed_height < ctx.host_oldest_height() {
+        return Err(Kind::InvalidHeight.into());
+    }
+
+    Ok(())
+}
+
+/// Verifies that at least `delay_period` (in both wall-clock time and number of blocks) has
+/// elapsed since the height/time at which the client's update (relied upon by the current proof)
+/// was processed by the local chain. `processed_height`/`processed_time` identify that update;
+/// `current_height`/`current_time` identify the present. The number of blocks corresponding to
+/// `delay_period` is derived from `max_expected_time_per_block`, since connections do not track
+/// block production time directly.
+pub fn verify_delay_passed(
+    current_time: Time,
+    current_height: Height,
+    processed_time: Time,
+    processed_height: Height,
+    delay_period: Duration,
+    max_expected_time_per_block: Duration,
+) -> Result<(), Error> {
+    let earliest_time = processed_time
+        .checked_add(delay_period)
+        .ok_or(Kind::ConnectionDelayNotElapsed)?;
+    if current_time < earliest_time {
+        return Err(Kind::ConnectionDelayNotElapsed.into());
+    }
+
+    let delay_period_blocks = delay_blocks(delay_period, max_expected_time_per_block);
+    let earliest_height = Height(processed_height.value() + delay_period_blocks);
+    if current_height < earliest_height {
+        return Err(Kind::ConnectionDelayNotElapsed.into());
+    }
+
+    Ok(())
+}
+
+/// Converts a `delay_period` duration into a number of blocks, assuming the host chain never
+/// takes longer than `max_expected_time_per_block` to produce a block. Rounds up, so that the
+/// block-based check is at least as conservative as the time-based one.
+fn delay_blocks(delay_period: Duration, max_expected_time_per_block: Duration) -> u64 {
+    if max_expected_time_per_block.is_zero() {
+        return 0;
+    }
+    let delay_nanos = delay_period.as_nanos();
+    let block_nanos = max_expected_time_per_block.as_nanos();
+    ((delay_nanos + block_nanos - 1) / block_nanos) as u64
+}
@@ -5,4 +5,5 @@ pub mod error;
 pub mod events;
 pub mod exported;
 pub mod msgs;
+pub mod version;
 pub mod core; // Core (message processing logic) of ICS 03.
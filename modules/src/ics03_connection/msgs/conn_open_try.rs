@@ -1,8 +1,10 @@
 use serde_derive::{Deserialize, Serialize};
 use std::convert::{TryFrom, TryInto};
 use std::str::{from_utf8, FromStr};
+use std::time::Duration;
 
 use ibc_proto::ibc::connection::MsgConnectionOpenTry as RawMsgConnectionOpenTry;
+use prost::Message as _;
 use tendermint::account::Id as AccountId;
 use tendermint::block::Height;
 
@@ -27,6 +29,7 @@ pub struct MsgConnectionOpenTry {
     counterparty: Counterparty,
     counterparty_versions: Vec<String>,
     proofs: Proofs,
+    delay_period: Duration,
     signer: AccountId,
 }
 
@@ -61,6 +64,11 @@ impl MsgConnectionOpenTry {
         &self.proofs
     }
 
+    /// Getter for accessing the delay period that the new connection should enforce.
+    pub fn delay_period(&self) -> Duration {
+        self.delay_period
+    }
+
     /// Getter for accessing the `consensus_height` field from this message. Returns the special
     /// value `0` if this field is not set.
     pub fn consensus_height(&self) -> Height {
@@ -89,7 +97,8 @@ impl Msg for MsgConnectionOpenTry {
     }
 
     fn get_sign_bytes(&self) -> Vec<u8> {
-        unimplemented!()
+        let raw: RawMsgConnectionOpenTry = self.clone().into();
+        raw.encode_to_vec()
     }
 
     fn get_signers(&self) -> Vec<AccountId> {
@@ -101,14 +110,16 @@ impl TryFrom<RawMsgConnectionOpenTry> for MsgConnectionOpenTry {
     type Error = Error;
 
     fn try_from(msg: RawMsgConnectionOpenTry) -> Result<Self, Self::Error> {
-        let proof_height = msg
-            .proof_height
-            .ok_or_else(|| Kind::MissingProofHeight)?
-            .epoch_height; // FIXME: This is wrong as it does not take the epoch number into account
-        let consensus_height = msg
+        let raw_proof_height = msg.proof_height.ok_or_else(|| Kind::MissingProofHeight)?;
+        let proof_height =
+            crate::Height::new(raw_proof_height.epoch_number, raw_proof_height.epoch_height);
+        let raw_consensus_height = msg
             .consensus_height
-            .ok_or_else(|| Kind::MissingConsensusHeight)?
-            .epoch_height; // FIXME: This is wrong as it does not take the epoch number into account
+            .ok_or_else(|| Kind::MissingConsensusHeight)?;
+        let consensus_height = crate::Height::new(
+            raw_consensus_height.epoch_number,
+            raw_consensus_height.epoch_height,
+        );
         let consensus_proof_obj = ConsensusProof::new(msg.proof_consensus.into(), consensus_height)
             .map_err(|e| Kind::InvalidProof.context(e))?;
 
@@ -141,9 +152,11 @@ impl TryFrom<RawMsgConnectionOpenTry> for MsgConnectionOpenTry {
                 msg.proof_init.into(),
                 client_proof,
                 Some(consensus_proof_obj),
+                None,
                 proof_height,
             )
             .map_err(|e| Kind::InvalidProof.context(e))?,
+            delay_period: Duration::from_nanos(msg.delay_period),
             signer: AccountId::from_str(
                 from_utf8(&msg.signer).map_err(|e| Kind::InvalidSigner.context(e))?,
             )
@@ -152,6 +165,46 @@ impl TryFrom<RawMsgConnectionOpenTry> for MsgConnectionOpenTry {
     }
 }
 
+impl From<MsgConnectionOpenTry> for RawMsgConnectionOpenTry {
+    fn from(msg: MsgConnectionOpenTry) -> Self {
+        let proof_height = msg.proofs.height();
+        let consensus_proof = msg.proofs.consensus_proof();
+        let consensus_height = consensus_proof
+            .as_ref()
+            .map(|p| p.height())
+            .unwrap_or_default();
+
+        RawMsgConnectionOpenTry {
+            client_id: msg.client_id.to_string(),
+            connection_id: msg.connection_id.to_string(),
+            client_state: msg.client_state.map(Into::into),
+            counterparty: Some(msg.counterparty.into()),
+            counterparty_versions: msg.counterparty_versions,
+            delay_period: msg.delay_period.as_nanos() as u64,
+            proof_init: msg.proofs.object_proof().as_bytes().to_vec(),
+            proof_height: Some(ibc_proto::ibc::client::Height {
+                epoch_number: proof_height.revision_number(),
+                epoch_height: proof_height.revision_height(),
+            }),
+            proof_consensus: consensus_proof
+                .as_ref()
+                .map(|p| p.proof().as_bytes().to_vec())
+                .unwrap_or_default(),
+            consensus_height: Some(ibc_proto::ibc::client::Height {
+                epoch_number: consensus_height.revision_number(),
+                epoch_height: consensus_height.revision_height(),
+            }),
+            signer: msg.signer.as_bytes().to_vec(),
+            proof_client: msg
+                .proofs
+                .client_proof()
+                .as_ref()
+                .map(|p| p.as_bytes().to_vec())
+                .unwrap_or_default(),
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod test_util {
     use ibc_proto::ibc::client::Height;
@@ -171,6 +224,7 @@ pub mod test_util {
             client_state: None,
             counterparty: Some(get_dummy_counterparty()),
             counterparty_versions: vec!["1.0.0".to_string()],
+            delay_period: 0,
             proof_init: get_dummy_proof(),
             proof_height: Some(Height {
                 epoch_number: 1,
@@ -0,0 +1,45 @@
+//! These are definitions shared across all the connection messages.
+
+use crate::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
+use crate::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use crate::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+
+pub mod conn_open_ack;
+pub mod conn_open_confirm;
+pub mod conn_open_init;
+pub mod conn_open_try;
+
+/// Enumeration of all possible messages that the ICS3 protocol processes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConnectionMsg {
+    ConnectionOpenInit(Box<MsgConnectionOpenInit>),
+    ConnectionOpenTry(Box<MsgConnectionOpenTry>),
+    ConnectionOpenAck(Box<MsgConnectionOpenAck>),
+    ConnectionOpenConfirm(Box<MsgConnectionOpenConfirm>),
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use ibc_proto::ibc::connection::Counterparty as RawCounterparty;
+
+    pub fn get_dummy_proof() -> Vec<u8> {
+        "Y29uc2Vuc3VzU3RhdGUvaWJjMC1jbGllbnQtMC9oZWlnaHQvMTAtMTU="
+            .as_bytes()
+            .to_vec()
+    }
+
+    pub fn get_dummy_account_id_bytes() -> Vec<u8> {
+        "0CDA3F47EF3C4906693B170EF650EB968C5F4B2"
+            .as_bytes()
+            .to_vec()
+    }
+
+    pub fn get_dummy_counterparty() -> RawCounterparty {
+        RawCounterparty {
+            client_id: "destclient".to_string(),
+            connection_id: "destconnection".to_string(),
+            prefix: None,
+        }
+    }
+}
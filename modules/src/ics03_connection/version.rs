@@ -0,0 +1,55 @@
+//! Structured connection version negotiation over identifiers and feature lists. The
+//! `MsgConnectionOpenTry` handler does not call [`pick_version`]: it intersects plain version
+//! strings and hands the result to `ConnectionReader::pick_version`.
+
+use crate::ics03_connection::error::{Error, Kind};
+
+/// A connection version, made up of an `identifier` (e.g. `"1"`) and an ordered list of
+/// `features` the two ends negotiate independently of the identifier (e.g. `ORDER_ORDERED`,
+/// `ORDER_UNORDERED`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Version {
+    pub identifier: String,
+    pub features: Vec<String>,
+}
+
+impl Version {
+    pub fn new(identifier: String, features: Vec<String>) -> Self {
+        Self {
+            identifier,
+            features,
+        }
+    }
+}
+
+/// Picks the version to use out of `candidates` (proposed by the counterparty, in their
+/// preference order) given the versions `supported` locally.
+///
+/// Negotiation happens in two steps: first the `identifier`s are intersected, then -- for the
+/// first matching identifier, in `candidates`' order -- the `features` lists are intersected,
+/// preserving `supported`'s preference order. A matching identifier with no overlapping features
+/// is rejected with [`Kind::NoCommonFeatures`] rather than silently falling through to the next
+/// candidate, since feature mismatch is itself meaningful information for the caller.
+pub fn pick_version(supported: &[Version], candidates: &[Version]) -> Result<Version, Error> {
+    for candidate in candidates {
+        let local = match supported.iter().find(|v| v.identifier == candidate.identifier) {
+            Some(local) => local,
+            None => continue,
+        };
+
+        let features: Vec<String> = local
+            .features
+            .iter()
+            .filter(|f| candidate.features.contains(f))
+            .cloned()
+            .collect();
+
+        if features.is_empty() && !(local.features.is_empty() && candidate.features.is_empty()) {
+            return Err(Kind::NoCommonFeatures(candidate.identifier.clone()).into());
+        }
+
+        return Ok(Version::new(candidate.identifier.clone(), features));
+    }
+
+    Err(Kind::InvalidVersion.into())
+}
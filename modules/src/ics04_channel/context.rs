@@ -2,13 +2,18 @@
 //! the interface that any host chain must implement to be able to process any `ChannelMsg`.
 //!
 
+use std::time::Duration;
+
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics04_channel::channel::ChannelEnd;
 use crate::ics04_channel::error::Error;
 use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
+use crate::ics04_channel::packet;
 use crate::ics05_port::capabilities::Capability;
 use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics26_routing::context::ModuleId;
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 use super::packet::{PacketResult, Sequence};
@@ -35,6 +40,22 @@ pub trait ChannelReader {
 
     fn authenticated_capability(&self, port_id: &PortId) -> Result<Capability, Error>;
 
+    /// Returns the application module bound to `port_id`, if any.
+    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<ModuleId>;
+
+    /// Returns the application module bound to the channel's port, together with the channel
+    /// end itself, so callers needing both don't have to look up the channel end a second time.
+    fn lookup_module_by_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Option<(ModuleId, ChannelEnd)> {
+        let module_id = self.lookup_module_by_port(port_id)?;
+        let channel_end = self.channel_end(&(port_id.clone(), channel_id.clone()))?;
+
+        Some((module_id, channel_end))
+    }
+
     fn get_next_sequence_send(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence>;
 
     fn get_next_sequence_recv(&self, port_channel_id: &(PortId, ChannelId)) -> Option<Sequence>;
@@ -43,14 +64,39 @@ pub trait ChannelReader {
 
     fn get_packet_acknowledgement(&self, key: &(PortId, ChannelId, Sequence)) -> Option<String>;
 
-    /// A hashing function for packet commitments  
-    fn hash(&self, value: String) -> String;
+    /// Computes the packet commitment bytes stored on-chain for a packet carrying `data`, with
+    /// the given `timeout_height` and `timeout_timestamp`, matching the ICS4 on-chain commitment
+    /// scheme so proofs produced against a live chain verify correctly.
+    fn packet_commitment(
+        &self,
+        data: Vec<u8>,
+        timeout_height: Height,
+        timeout_timestamp: Timestamp,
+    ) -> Vec<u8> {
+        packet::packet_commitment(timeout_timestamp.nanoseconds(), timeout_height, &data)
+    }
 
     /// Returns the current height of the local chain.
     fn host_height(&self) -> Height;
 
     /// Returns the current timestamp of the local chain.
-    fn host_timestamp(&self) -> u64;
+    fn host_timestamp(&self) -> Timestamp;
+
+    /// Returns the timestamp at which the counterparty light client for `client_id` was last
+    /// updated to `height`, used to check whether a packet has timed out relative to the
+    /// counterparty's clock.
+    fn client_update_time(&self, client_id: &ClientId, height: Height) -> Option<Timestamp>;
+
+    /// Returns the height at which the counterparty light client for `client_id` was last
+    /// updated to `height`. Together with `client_update_time`, lets packet-timeout processing
+    /// check both the `timeout_timestamp` and `timeout_height` against when the client actually
+    /// observed that height, rather than against the current host time/height.
+    fn client_update_height(&self, client_id: &ClientId, height: Height) -> Option<Height>;
+
+    /// The maximum time expected to be needed to produce a block on the host chain, used to
+    /// compute the block-delay period of connections whose clients are rooted here, per ICS4's
+    /// delay period semantics.
+    fn max_expected_time_per_block(&self) -> Duration;
 
     /// Returns a counter on the number of channel ids have been created thus far.
     /// The value of this counter should increase only via method
@@ -0,0 +1,53 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+use crate::ics04_channel::packet::Sequence;
+use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
+use crate::Height;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("the client state for client {0} could not be found")]
+    MissingClientState(ClientId),
+
+    #[error("the client {0} is frozen")]
+    FrozenClient(ClientId),
+
+    #[error("the consensus state for client {0} at height {1} could not be found")]
+    MissingClientConsensusState(ClientId, Height),
+
+    #[error("the supplied proof could not be verified against the expected channel end")]
+    InvalidProof,
+
+    #[error("the commitment proof bytes in the message are empty")]
+    EmptyProof,
+
+    #[error("the counterparty's commitment prefix is empty")]
+    EmptyCommitmentPrefix,
+
+    #[error("the packet proof does not verify against the counterparty's stored commitment for packet {0}")]
+    PacketVerificationFailed(Sequence),
+
+    #[error("failed to parse the response")]
+    ResponseParsing,
+
+    #[error("no channel end exists for port `{0}` and channel `{1}` at the queried height")]
+    ChannelNotFound(PortId, ChannelId),
+
+    #[error("no packet commitment stored for port `{0}`, channel `{1}`, sequence `{2}` at the queried height")]
+    PacketCommitmentNotFound(PortId, ChannelId, Sequence),
+
+    #[error("no acknowledgement stored for port `{0}`, channel `{1}`, sequence `{2}` at the queried height")]
+    PacketAcknowledgementNotFound(PortId, ChannelId, Sequence),
+
+    #[error("no next-sequence-recv stored for port `{0}`, channel `{1}` at the queried height")]
+    NextSequenceRecvNotFound(PortId, ChannelId),
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
@@ -3,6 +3,7 @@ use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics04_channel::channel::ChannelEnd;
 use crate::ics04_channel::context::ChannelReader;
 use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::packet::packet_commitment;
 use crate::proofs::Proofs;
 use crate::{
     ics02_client::state::ClientState, ics04_channel::packet::Packet,
@@ -36,6 +37,14 @@ pub fn verify_proofs(
         return Err(Kind::MissingClientConsensusState(client_id, proofs.height()).into());
     }
 
+    if proofs.object_proof().is_empty() {
+        return Err(Kind::EmptyProof.into());
+    }
+
+    if connection_end.counterparty().prefix().is_empty() {
+        return Err(Kind::EmptyCommitmentPrefix.into());
+    }
+
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
     // Verify the proof for the channel state against the expected channel end.
@@ -76,13 +85,17 @@ pub fn verify_packet_proofs(
         return Err(Kind::MissingClientConsensusState(client_id, proofs.height()).into());
     }
 
+    if proofs.object_proof().is_empty() {
+        return Err(Kind::EmptyProof.into());
+    }
+
     let client_def = AnyClient::from_client_type(client_state.client_type());
 
-    let input = format!(
-        "{:?},{:?},{:?}",
-        packet.timeout_timestamp, packet.timeout_height, packet.data
+    let commitment = packet_commitment(
+        packet.timeout_timestamp,
+        packet.timeout_height,
+        &packet.data,
     );
-    let commitment = ctx.hash(input);
 
     // Verify the proof for the packet against the chain store.
     Ok(client_def
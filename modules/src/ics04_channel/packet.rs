@@ -0,0 +1,30 @@
+use sha2::{Digest, Sha256};
+
+use crate::Height;
+
+/// Constructs the canonical packet commitment bytes, as defined by the ICS4 spec:
+///
+/// ```text
+/// sha256(
+///     be_u64(timeout_timestamp) ||
+///     be_u64(timeout_height.revision_number) ||
+///     be_u64(timeout_height.revision_height) ||
+///     sha256(data)
+/// )
+/// ```
+///
+/// This must match byte-for-byte what a counterparty chain stores at the packet commitment
+/// path, so that proofs produced by a live chain (and not just the mock context's `hash`) verify
+/// correctly. Used by `verify_packet_proofs`, when it checks a packet proof, and by the default
+/// `ChannelReader::packet_commitment`; `ChannelKeeper::store_packet_commitment` receives the raw
+/// timeout and data and does not call it.
+pub fn packet_commitment(timeout_timestamp: u64, timeout_height: Height, data: &[u8]) -> Vec<u8> {
+    let mut input = Vec::new();
+
+    input.extend_from_slice(&timeout_timestamp.to_be_bytes());
+    input.extend_from_slice(&timeout_height.revision_number.to_be_bytes());
+    input.extend_from_slice(&timeout_height.revision_height.to_be_bytes());
+    input.extend_from_slice(&Sha256::digest(data));
+
+    Sha256::digest(&input).to_vec()
+}
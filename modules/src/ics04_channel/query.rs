@@ -6,8 +6,11 @@ use crate::ics23_commitment::{CommitmentPath, CommitmentProof};
 
 use crate::error;
 use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::packet::Sequence;
 use crate::ics24_host::identifier::{ChannelId, PortId};
-use crate::path::{ChannelEndsPath, Path};
+use crate::path::{
+    AcksPath, ChannelEndsPath, CommitmentsPath, NextSequenceRecvPath, Path, ReceiptsPath,
+};
 use crate::query::{IbcQuery, IbcResponse};
 use crate::Height;
 
@@ -16,7 +19,7 @@ use crate::ics04_channel::error::Error;
 // Import protobuf definitions.
 use ibc_proto::channel::Channel as ProtoChannel;
 
-use bytes::Bytes;
+use bytes::{Buf, Bytes};
 use prost::Message;
 use std::convert::TryFrom;
 
@@ -88,25 +91,454 @@ impl ChannelResponse {
 
 impl IbcResponse<QueryChannel> for ChannelResponse {
     fn from_abci_response(query: QueryChannel, response: AbciQuery) -> Result<Self, error::Error> {
-        match proto_unmarshal(response.value) {
-            Ok(decoded_conn) => Ok(ChannelResponse::new(
-                query.port_id,
-                query.channel_id,
-                decoded_conn,
-                response.proof,
-                response.height.into(),
-            )),
-            Err(e) => Err(error::Kind::ResponseParsing.context(e).into()),
+        if response.value.is_empty() {
+            return Err(error::Kind::ChannelNotFound(query.port_id, query.channel_id).into());
         }
+
+        let value = response.value;
+        let decoded_conn =
+            proto_unmarshal(value.clone()).or_else(|_| amino_unmarshal_binary_length_prefixed(&value))?;
+
+        Ok(ChannelResponse::new(
+            query.port_id,
+            query.channel_id,
+            decoded_conn,
+            response.proof,
+            response.height.into(),
+        ))
     }
 }
 
-fn amino_unmarshal_binary_length_prefixed<T>(_bytes: &[u8]) -> Result<T, error::Error> {
-    todo!()
+/// Decodes a legacy Amino-encoded, length-prefixed `ChannelEnd`: a uvarint byte length followed
+/// by exactly that many bytes of the protobuf-encoded message. Some chains still serve values in
+/// this wrapped form instead of bare protobuf.
+fn amino_unmarshal_binary_length_prefixed(bytes: &[u8]) -> Result<ChannelEnd, Error> {
+    let mut buf = Bytes::from(bytes.to_vec());
+
+    let length = prost::encoding::decode_varint(&mut buf)
+        .map_err(|e| error::Kind::ResponseParsing.context(e))?;
+
+    if length as usize != buf.remaining() {
+        return Err(error::Kind::ResponseParsing
+            .context(format!(
+                "amino length prefix {} does not match remaining buffer length {}",
+                length,
+                buf.remaining()
+            ))
+            .into());
+    }
+
+    proto_unmarshal(buf.to_vec())
 }
 
 fn proto_unmarshal(bytes: Vec<u8>) -> Result<ChannelEnd, Error> {
     let buf = Bytes::from(bytes);
-    let decoded = ProtoChannel::decode(buf).unwrap();
+    let decoded = ProtoChannel::decode(buf).map_err(|e| error::Kind::ResponseParsing.context(e))?;
     ChannelEnd::try_from(decoded)
 }
+
+/// A proven query for the commitment stored for a sent packet, at
+/// `commitments/ports/{port}/channels/{chan}/sequences/{seq}`.
+pub struct QueryPacketCommitment {
+    pub chain_height: Height,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+    pub prove: bool,
+}
+
+impl QueryPacketCommitment {
+    pub fn new(
+        chain_height: Height,
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        prove: bool,
+    ) -> Self {
+        Self {
+            chain_height,
+            port_id,
+            channel_id,
+            sequence,
+            prove,
+        }
+    }
+}
+
+impl IbcQuery for QueryPacketCommitment {
+    type Response = PacketCommitmentResponse;
+
+    fn path(&self) -> abci::Path {
+        "/store/ibc/key".parse().unwrap()
+    }
+
+    fn height(&self) -> Height {
+        self.chain_height
+    }
+
+    fn prove(&self) -> bool {
+        self.prove
+    }
+
+    fn data(&self) -> Vec<u8> {
+        CommitmentsPath::new(self.port_id.clone(), self.channel_id.clone(), self.sequence)
+            .to_key()
+            .into()
+    }
+}
+
+pub struct PacketCommitmentResponse {
+    pub commitment: Vec<u8>,
+    pub proof: Option<CommitmentProof>,
+    pub proof_path: CommitmentPath,
+    pub proof_height: Height,
+}
+
+impl IbcResponse<QueryPacketCommitment> for PacketCommitmentResponse {
+    fn from_abci_response(
+        query: QueryPacketCommitment,
+        response: AbciQuery,
+    ) -> Result<Self, error::Error> {
+        if response.value.is_empty() {
+            return Err(error::Kind::PacketCommitmentNotFound(
+                query.port_id,
+                query.channel_id,
+                query.sequence,
+            )
+            .into());
+        }
+
+        Ok(PacketCommitmentResponse {
+            commitment: response.value,
+            proof: response.proof,
+            proof_path: CommitmentPath::from_path(CommitmentsPath::new(
+                query.port_id,
+                query.channel_id,
+                query.sequence,
+            )),
+            proof_height: response.height.into(),
+        })
+    }
+}
+
+/// A proven query for the acknowledgement written for a received packet, at
+/// `acks/ports/{port}/channels/{chan}/sequences/{seq}`.
+pub struct QueryPacketAcknowledgement {
+    pub chain_height: Height,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+    pub prove: bool,
+}
+
+impl QueryPacketAcknowledgement {
+    pub fn new(
+        chain_height: Height,
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        prove: bool,
+    ) -> Self {
+        Self {
+            chain_height,
+            port_id,
+            channel_id,
+            sequence,
+            prove,
+        }
+    }
+}
+
+impl IbcQuery for QueryPacketAcknowledgement {
+    type Response = PacketAcknowledgementResponse;
+
+    fn path(&self) -> abci::Path {
+        "/store/ibc/key".parse().unwrap()
+    }
+
+    fn height(&self) -> Height {
+        self.chain_height
+    }
+
+    fn prove(&self) -> bool {
+        self.prove
+    }
+
+    fn data(&self) -> Vec<u8> {
+        AcksPath::new(self.port_id.clone(), self.channel_id.clone(), self.sequence)
+            .to_key()
+            .into()
+    }
+}
+
+pub struct PacketAcknowledgementResponse {
+    pub acknowledgement: Vec<u8>,
+    pub proof: Option<CommitmentProof>,
+    pub proof_path: CommitmentPath,
+    pub proof_height: Height,
+}
+
+impl IbcResponse<QueryPacketAcknowledgement> for PacketAcknowledgementResponse {
+    fn from_abci_response(
+        query: QueryPacketAcknowledgement,
+        response: AbciQuery,
+    ) -> Result<Self, error::Error> {
+        if response.value.is_empty() {
+            return Err(error::Kind::PacketAcknowledgementNotFound(
+                query.port_id,
+                query.channel_id,
+                query.sequence,
+            )
+            .into());
+        }
+
+        Ok(PacketAcknowledgementResponse {
+            acknowledgement: response.value,
+            proof: response.proof,
+            proof_path: CommitmentPath::from_path(AcksPath::new(
+                query.port_id,
+                query.channel_id,
+                query.sequence,
+            )),
+            proof_height: response.height.into(),
+        })
+    }
+}
+
+/// A proven query for whether a packet has been received, at
+/// `receipts/ports/{port}/channels/{chan}/sequences/{seq}`. An empty `response.value` is a
+/// legitimate result here (it means the packet is unreceived), so unlike the other packet
+/// queries it is not treated as an error.
+pub struct QueryPacketReceipt {
+    pub chain_height: Height,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+    pub prove: bool,
+}
+
+impl QueryPacketReceipt {
+    pub fn new(
+        chain_height: Height,
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        prove: bool,
+    ) -> Self {
+        Self {
+            chain_height,
+            port_id,
+            channel_id,
+            sequence,
+            prove,
+        }
+    }
+}
+
+impl IbcQuery for QueryPacketReceipt {
+    type Response = PacketReceiptResponse;
+
+    fn path(&self) -> abci::Path {
+        "/store/ibc/key".parse().unwrap()
+    }
+
+    fn height(&self) -> Height {
+        self.chain_height
+    }
+
+    fn prove(&self) -> bool {
+        self.prove
+    }
+
+    fn data(&self) -> Vec<u8> {
+        ReceiptsPath::new(self.port_id.clone(), self.channel_id.clone(), self.sequence)
+            .to_key()
+            .into()
+    }
+}
+
+pub struct PacketReceiptResponse {
+    /// Whether a receipt is stored for this packet, i.e. whether it has already been received.
+    pub received: bool,
+    pub proof: Option<CommitmentProof>,
+    pub proof_path: CommitmentPath,
+    pub proof_height: Height,
+}
+
+impl IbcResponse<QueryPacketReceipt> for PacketReceiptResponse {
+    fn from_abci_response(
+        query: QueryPacketReceipt,
+        response: AbciQuery,
+    ) -> Result<Self, error::Error> {
+        Ok(PacketReceiptResponse {
+            received: !response.value.is_empty(),
+            proof: response.proof,
+            proof_path: CommitmentPath::from_path(ReceiptsPath::new(
+                query.port_id,
+                query.channel_id,
+                query.sequence,
+            )),
+            proof_height: response.height.into(),
+        })
+    }
+}
+
+/// A proven query for the next receive sequence number of an ordered channel, at
+/// `nextSequenceRecv/ports/{port}/channels/{chan}`.
+pub struct QueryNextSequenceRecv {
+    pub chain_height: Height,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub prove: bool,
+}
+
+impl QueryNextSequenceRecv {
+    pub fn new(chain_height: Height, port_id: PortId, channel_id: ChannelId, prove: bool) -> Self {
+        Self {
+            chain_height,
+            port_id,
+            channel_id,
+            prove,
+        }
+    }
+}
+
+impl IbcQuery for QueryNextSequenceRecv {
+    type Response = NextSequenceRecvResponse;
+
+    fn path(&self) -> abci::Path {
+        "/store/ibc/key".parse().unwrap()
+    }
+
+    fn height(&self) -> Height {
+        self.chain_height
+    }
+
+    fn prove(&self) -> bool {
+        self.prove
+    }
+
+    fn data(&self) -> Vec<u8> {
+        NextSequenceRecvPath::new(self.port_id.clone(), self.channel_id.clone())
+            .to_key()
+            .into()
+    }
+}
+
+pub struct NextSequenceRecvResponse {
+    pub next_sequence_recv: Sequence,
+    pub proof: Option<CommitmentProof>,
+    pub proof_path: CommitmentPath,
+    pub proof_height: Height,
+}
+
+impl IbcResponse<QueryNextSequenceRecv> for NextSequenceRecvResponse {
+    fn from_abci_response(
+        query: QueryNextSequenceRecv,
+        response: AbciQuery,
+    ) -> Result<Self, error::Error> {
+        if response.value.is_empty() {
+            return Err(
+                error::Kind::NextSequenceRecvNotFound(query.port_id, query.channel_id).into(),
+            );
+        }
+
+        let next_sequence_recv = Sequence::from(u64::from_be_bytes(
+            response.value[..8]
+                .try_into()
+                .map_err(|e| error::Kind::ResponseParsing.context(e))?,
+        ));
+
+        Ok(NextSequenceRecvResponse {
+            next_sequence_recv,
+            proof: response.proof,
+            proof_path: CommitmentPath::from_path(NextSequenceRecvPath::new(
+                query.port_id,
+                query.channel_id,
+            )),
+            proof_height: response.height.into(),
+        })
+    }
+}
+
+/// The proofs a relayer needs, bundled together, to assemble `MsgRecvPacket`,
+/// `MsgAcknowledgement`, or `MsgTimeout` for the packet identified by `port_id`/`channel_id`/
+/// `sequence` -- without the relayer having to separately track each proven value.
+pub struct PacketProofs {
+    pub height: Height,
+    pub commitment_proof: Option<CommitmentProof>,
+    pub ack_proof: Option<CommitmentProof>,
+    pub receipt_proof: Option<CommitmentProof>,
+    pub next_sequence_recv_proof: Option<CommitmentProof>,
+}
+
+/// Runs the four packet-level proven queries for `sequence` against `abci_query` (the chain's
+/// own ABCI query executor: given a store path, the query data, the height to query at, and
+/// whether to request a proof, it returns the raw `AbciQuery` response) and bundles the
+/// resulting proofs, discarding the decoded values -- callers that also need the
+/// commitment/ack/receipt contents should run the corresponding `Query*`/`*Response` pair
+/// directly instead.
+pub fn build_packet_proofs(
+    abci_query: impl Fn(abci::Path, Vec<u8>, Height, bool) -> Result<AbciQuery, error::Error>,
+    chain_height: Height,
+    port_id: PortId,
+    channel_id: ChannelId,
+    sequence: Sequence,
+) -> Result<PacketProofs, error::Error> {
+    let commitment_query = QueryPacketCommitment::new(
+        chain_height,
+        port_id.clone(),
+        channel_id.clone(),
+        sequence,
+        true,
+    );
+    let commitment_proof = abci_query(
+        commitment_query.path(),
+        commitment_query.data(),
+        commitment_query.height(),
+        true,
+    )?
+    .proof;
+
+    let ack_query = QueryPacketAcknowledgement::new(
+        chain_height,
+        port_id.clone(),
+        channel_id.clone(),
+        sequence,
+        true,
+    );
+    let ack_proof = abci_query(ack_query.path(), ack_query.data(), ack_query.height(), true)?.proof;
+
+    let receipt_query = QueryPacketReceipt::new(
+        chain_height,
+        port_id.clone(),
+        channel_id.clone(),
+        sequence,
+        true,
+    );
+    let receipt_proof = abci_query(
+        receipt_query.path(),
+        receipt_query.data(),
+        receipt_query.height(),
+        true,
+    )?
+    .proof;
+
+    let next_sequence_recv_query =
+        QueryNextSequenceRecv::new(chain_height, port_id, channel_id, true);
+    let next_sequence_recv_proof = abci_query(
+        next_sequence_recv_query.path(),
+        next_sequence_recv_query.data(),
+        next_sequence_recv_query.height(),
+        true,
+    )?
+    .proof;
+
+    Ok(PacketProofs {
+        height: chain_height,
+        commitment_proof,
+        ack_proof,
+        receipt_proof,
+        next_sequence_recv_proof,
+    })
+}
@@ -0,0 +1,101 @@
+//! Owned byte representations of the values exchanged during ICS23 proof verification: the
+//! counterparty's store prefix, the root of its commitment tree, and the serialized proofs
+//! anchored at that root.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ics23_commitment::error::{Error, Kind};
+
+/// The root of the Merkle tree that a host chain commits its IBC-related state to, as reported by
+/// a client tracking that chain at a given height.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentRoot {
+    bytes: Vec<u8>,
+}
+
+impl CommitmentRoot {
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl From<Vec<u8>> for CommitmentRoot {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+/// The prefix that a host chain prepends to the standard ICS24 paths (e.g. `connections/{id}`)
+/// before committing them to its Merkle store. A counterparty-supplied prefix must be non-empty:
+/// an empty prefix cannot be used to distinguish a chain's IBC state from any other key in its
+/// store, so accepting one would let a malicious counterparty bypass proof verification.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentPrefix {
+    bytes: Vec<u8>,
+}
+
+impl CommitmentPrefix {
+    /// Builds a new `CommitmentPrefix` from `bytes`, rejecting an empty value.
+    pub fn new(bytes: Vec<u8>) -> Result<Self, Error> {
+        if bytes.is_empty() {
+            return Err(Kind::EmptyCommitmentPrefix.into());
+        }
+        Ok(Self { bytes })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+// Wraps `bytes` as-is, without rejecting an empty value. Used at proto-decoding boundaries,
+// where a prefix that turns out to be empty is instead caught later by
+// `Counterparty::validate_basic`.
+impl From<Vec<u8>> for CommitmentPrefix {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+}
+
+/// A proof, serialized exactly as produced by the host chain's Merkle store (e.g. the raw bytes
+/// obtained via an ABCI query with `prove: true`). A proof used in a handshake or packet message
+/// must be non-empty: an empty proof can never attest to anything.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentProofBytes {
+    bytes: Vec<u8>,
+}
+
+impl CommitmentProofBytes {
+    /// Builds a new `CommitmentProofBytes` from `bytes`, rejecting an empty value.
+    pub fn new(bytes: Vec<u8>) -> Result<Self, Error> {
+        if bytes.is_empty() {
+            return Err(Kind::EmptyCommitmentProof.into());
+        }
+        Ok(Self { bytes })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+// Wraps `bytes` as-is, without rejecting an empty value. Used at proto-decoding boundaries:
+// `Proofs::new` then rejects an empty object proof and `ConsensusProof::new` an empty consensus
+// proof, while an empty client proof is accepted.
+impl From<Vec<u8>> for CommitmentProofBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+}
@@ -0,0 +1,28 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("commitment prefix bytes cannot be empty")]
+    EmptyCommitmentPrefix,
+
+    #[error("commitment proof bytes cannot be empty")]
+    EmptyCommitmentProof,
+
+    #[error("failed to verify membership proof")]
+    MembershipProofVerificationFailed,
+
+    #[error("failed to verify non-membership proof")]
+    NonMembershipProofVerificationFailed,
+
+    #[error("proof has {0} ops but {1} proof specs were supplied")]
+    ProofSpecMismatch(usize, usize),
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
@@ -0,0 +1,197 @@
+//! A thin wrapper around the raw proof produced by a host chain's Merkle store, used to verify
+//! that a given value (or its absence) is committed at a given path under a known root.
+
+use ics23::commitment_proof::Proof;
+use ics23::{calculate_existence_root, ExistenceProof, HostFunctionsManager};
+
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
+use crate::ics23_commitment::error::{Error, Kind};
+use crate::ics23_commitment::CommitmentProof;
+
+/// The sequence of proof specs that a value committed in a standard two-layer Cosmos-SDK store
+/// (an IAVL-backed module store, itself committed into the top-level multistore) must be verified
+/// against, innermost layer first.
+#[derive(Clone, Debug)]
+pub struct ProofSpecs(Vec<ics23::ProofSpec>);
+
+impl ProofSpecs {
+    /// The proof specs of a standard Cosmos-SDK chain: an IAVL module store proof, followed by
+    /// the simple Merkle (multistore) proof that commits it into `app_hash`.
+    pub fn cosmos() -> Self {
+        Self(vec![ics23::iavl_spec(), ics23::tendermint_spec()])
+    }
+}
+
+impl AsRef<[ics23::ProofSpec]> for ProofSpecs {
+    fn as_ref(&self) -> &[ics23::ProofSpec] {
+        &self.0
+    }
+}
+
+/// The full key path of a value, from the root of the multistore down to its key within a module
+/// store, e.g. `["ibc", "channelEnds/ports/{port_id}/channels/{channel_id}"]`.
+#[derive(Clone, Debug)]
+pub struct MerklePath {
+    key_path: Vec<String>,
+}
+
+/// Prepends `prefix` onto `path`, producing the full [`MerklePath`] that a host chain's Merkle
+/// store commits a value at.
+pub fn apply_prefix(prefix: &CommitmentPrefix, path: impl Into<String>) -> MerklePath {
+    let prefix = String::from_utf8_lossy(prefix.as_bytes()).into_owned();
+
+    MerklePath {
+        key_path: vec![prefix, path.into()],
+    }
+}
+
+fn existence_root(existence_proof: &ExistenceProof) -> Result<Vec<u8>, Error> {
+    calculate_existence_root::<HostFunctionsManager>(existence_proof)
+        .map_err(|_| Kind::MembershipProofVerificationFailed.into())
+}
+
+/// A Merkle membership (or non-membership) proof, as obtained from a host chain's store: one
+/// `ics23::CommitmentProof` per store layer, ordered innermost (e.g. the IAVL module store) to
+/// outermost (e.g. the top-level multistore that commits into `app_hash`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct MerkleProof {
+    proofs: Vec<CommitmentProof>,
+}
+
+impl MerkleProof {
+    /// Verifies that `value` is committed at `keys`, under `root`, by checking each proof op
+    /// bottom-up: the innermost op proves `value` is stored under its own layer's key, and the
+    /// root that op computes becomes the "value" the next op must prove is stored at its own
+    /// layer's key, and so on until the outermost computed root is compared against `root`.
+    pub fn verify_membership(
+        &self,
+        specs: &ProofSpecs,
+        root: &CommitmentRoot,
+        keys: MerklePath,
+        value: Vec<u8>,
+    ) -> Result<(), Error> {
+        let specs = specs.as_ref();
+        self.check_lengths(specs, &keys)?;
+
+        let mut subroot = value;
+
+        for (i, proof) in self.proofs.iter().enumerate() {
+            let key = self.key_at(&keys, i);
+
+            let existence_proof = match &proof.proof {
+                Some(Proof::Exist(existence_proof)) => existence_proof,
+                _ => return Err(Kind::MembershipProofVerificationFailed.into()),
+            };
+
+            let computed_root = existence_root(existence_proof)?;
+
+            if !ics23::verify_membership::<HostFunctionsManager>(
+                proof,
+                &specs[i],
+                &computed_root,
+                key,
+                &subroot,
+            ) {
+                return Err(Kind::MembershipProofVerificationFailed.into());
+            }
+
+            subroot = computed_root;
+        }
+
+        if &subroot != root.as_bytes() {
+            return Err(Kind::MembershipProofVerificationFailed.into());
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that nothing is committed at `keys`, under `root`. The innermost op must be a
+    /// non-existence proof; every op above it is an ordinary existence proof, verified exactly as
+    /// in [`Self::verify_membership`], chaining up to the outermost root.
+    pub fn verify_non_membership(
+        &self,
+        specs: &ProofSpecs,
+        root: &CommitmentRoot,
+        keys: MerklePath,
+    ) -> Result<(), Error> {
+        let specs = specs.as_ref();
+        self.check_lengths(specs, &keys)?;
+
+        let innermost = &self.proofs[0];
+        let non_existence_proof = match &innermost.proof {
+            Some(Proof::Nonexist(non_existence_proof)) => non_existence_proof,
+            _ => return Err(Kind::NonMembershipProofVerificationFailed.into()),
+        };
+
+        // A non-existence proof attests to the absence of a key by bracketing it between its
+        // left and right neighbors, at least one of which must be present; either one's
+        // existence proof yields the same root this layer's non-existence proof is checked
+        // against.
+        let neighbor = non_existence_proof
+            .left
+            .as_ref()
+            .or(non_existence_proof.right.as_ref())
+            .ok_or(Kind::NonMembershipProofVerificationFailed)?;
+
+        let mut subroot = existence_root(neighbor)?;
+
+        if !ics23::verify_non_membership::<HostFunctionsManager>(
+            innermost,
+            &specs[0],
+            &subroot,
+            self.key_at(&keys, 0),
+        ) {
+            return Err(Kind::NonMembershipProofVerificationFailed.into());
+        }
+
+        for (i, proof) in self.proofs.iter().enumerate().skip(1) {
+            let key = self.key_at(&keys, i);
+
+            let existence_proof = match &proof.proof {
+                Some(Proof::Exist(existence_proof)) => existence_proof,
+                _ => return Err(Kind::MembershipProofVerificationFailed.into()),
+            };
+
+            let computed_root = existence_root(existence_proof)?;
+
+            if !ics23::verify_membership::<HostFunctionsManager>(
+                proof,
+                &specs[i],
+                &computed_root,
+                key,
+                &subroot,
+            ) {
+                return Err(Kind::MembershipProofVerificationFailed.into());
+            }
+
+            subroot = computed_root;
+        }
+
+        if &subroot != root.as_bytes() {
+            return Err(Kind::NonMembershipProofVerificationFailed.into());
+        }
+
+        Ok(())
+    }
+
+    fn check_lengths(&self, specs: &[ics23::ProofSpec], keys: &MerklePath) -> Result<(), Error> {
+        if self.proofs.len() != specs.len() || keys.key_path.len() != specs.len() {
+            return Err(Kind::ProofSpecMismatch(self.proofs.len(), specs.len()).into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the key that the `i`-th proof op (counting from the innermost) must prove
+    /// membership/non-membership for. Keys are listed outermost-first, so this reverses the
+    /// index.
+    fn key_at<'a>(&self, keys: &'a MerklePath, i: usize) -> &'a [u8] {
+        keys.key_path[keys.key_path.len() - 1 - i].as_bytes()
+    }
+}
+
+impl From<Vec<CommitmentProof>> for MerkleProof {
+    fn from(proofs: Vec<CommitmentProof>) -> Self {
+        Self { proofs }
+    }
+}
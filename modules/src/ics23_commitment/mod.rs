@@ -0,0 +1,14 @@
+//! ICS 23: Vector Commitment Scheme used to verify existence/non-existence of a value at a given
+//! path, anchored at a root hash supplied by a light client tracking a counterparty chain.
+
+pub mod commitment;
+pub mod error;
+pub mod merkle;
+
+pub use commitment::{CommitmentPrefix, CommitmentProofBytes, CommitmentRoot};
+pub use merkle::{apply_prefix, MerklePath, MerkleProof, ProofSpecs};
+
+/// A single layer's proof of existence/non-existence produced by a host chain's vector commitment
+/// scheme. A [`merkle::MerkleProof`] bundles one of these per store layer, exactly as obtained by
+/// decoding an ABCI query response with `prove: true`.
+pub type CommitmentProof = ics23::CommitmentProof;
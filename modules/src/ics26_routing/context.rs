@@ -0,0 +1,219 @@
+//! The ICS26 routing layer: maps each IBC application (identified by a `ModuleId`) to the
+//! `Module` implementation handling its channel and packet callbacks, so the core channel and
+//! packet handlers can stay generic over whichever applications happen to be installed on a
+//! given chain.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::ics04_channel::channel::{Counterparty, Order};
+use crate::ics04_channel::packet::Packet;
+use crate::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+use crate::ics26_routing::error::{Error, Kind};
+
+/// Uniquely identifies an application module registered with a `Router`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModuleId(String);
+
+impl ModuleId {
+    /// Builds a `ModuleId` from `id`, rejecting an empty identifier.
+    pub fn new(id: String) -> Result<Self, Error> {
+        if id.trim().is_empty() {
+            return Err(Kind::InvalidModuleId.into());
+        }
+
+        Ok(Self(id))
+    }
+}
+
+impl fmt::Display for ModuleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The bytes an application module returns in response to a received packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Acknowledgement(Vec<u8>);
+
+impl Acknowledgement {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for Acknowledgement {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+/// The callbacks an IBC application must implement to participate in the channel handshake and
+/// packet lifecycle. Every method has a no-op default, so an application only needs to override
+/// the hooks it actually cares about (e.g. a module with no handshake-time checks of its own can
+/// skip straight to `on_recv_packet`).
+pub trait Module: Send + Sync {
+    fn on_chan_open_init(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        _version: &str,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn on_chan_open_try(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &str,
+    ) -> Result<String, Error> {
+        Ok(counterparty_version.to_string())
+    }
+
+    fn on_chan_open_ack(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &str,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn on_chan_open_confirm(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn on_recv_packet(&self, _packet: &Packet) -> Result<Acknowledgement, Error> {
+        Ok(Acknowledgement::new(Vec::new()))
+    }
+
+    fn on_acknowledgement_packet(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn on_timeout_packet(&mut self, _packet: &Packet) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn on_chan_close_init(&mut self, _port_id: &PortId, _channel_id: &ChannelId) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn on_chan_close_confirm(
+        &mut self,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Looks up the `Module` bound to a given `ModuleId`, and the `ModuleId` a given `PortId` is
+/// bound to.
+pub trait Router {
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module>;
+
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module>;
+
+    fn has_route(&self, module_id: &ModuleId) -> bool;
+
+    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<ModuleId>;
+}
+
+/// Builds up a `Router`'s module table one registration at a time, rejecting an attempt to bind
+/// the same module id or port twice.
+pub trait RouterBuilder: Sized {
+    type Router: Router;
+
+    /// Registers `module`, identified by `module_id`, as the handler for `port_id`.
+    fn add_route(
+        self,
+        module_id: ModuleId,
+        port_id: PortId,
+        module: impl Module + 'static,
+    ) -> Result<Self, Error>;
+
+    fn build(self) -> Self::Router;
+}
+
+/// The default, in-memory `Router`/`RouterBuilder` implementation: a chain wires up its
+/// applications by registering each one with an `IbcRouterBuilder`, then calls `build` once, at
+/// startup, to obtain the `IbcRouter` it will dispatch messages through.
+#[derive(Default)]
+pub struct IbcRouter {
+    modules: BTreeMap<ModuleId, Box<dyn Module>>,
+    port_to_module: BTreeMap<PortId, ModuleId>,
+}
+
+impl Router for IbcRouter {
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        self.modules.get(module_id).map(|m| m.as_ref())
+    }
+
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        self.modules.get_mut(module_id).map(|m| m.as_mut())
+    }
+
+    fn has_route(&self, module_id: &ModuleId) -> bool {
+        self.modules.contains_key(module_id)
+    }
+
+    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.port_to_module.get(port_id).cloned()
+    }
+}
+
+#[derive(Default)]
+pub struct IbcRouterBuilder(IbcRouter);
+
+impl IbcRouterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RouterBuilder for IbcRouterBuilder {
+    type Router = IbcRouter;
+
+    fn add_route(
+        mut self,
+        module_id: ModuleId,
+        port_id: PortId,
+        module: impl Module + 'static,
+    ) -> Result<Self, Error> {
+        if self.0.modules.contains_key(&module_id) {
+            return Err(Kind::ModuleAlreadyRegistered(module_id.to_string()).into());
+        }
+        if self.0.port_to_module.contains_key(&port_id) {
+            return Err(Kind::PortAlreadyBound(port_id).into());
+        }
+
+        self.0.modules.insert(module_id.clone(), Box::new(module));
+        self.0.port_to_module.insert(port_id, module_id);
+
+        Ok(self)
+    }
+
+    fn build(self) -> Self::Router {
+        self.0
+    }
+}
@@ -0,0 +1,33 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+use crate::ics24_host::identifier::PortId;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("no module is bound to port `{0}`")]
+    PortNotBound(PortId),
+
+    #[error("a module is already bound to port `{0}`")]
+    PortAlreadyBound(PortId),
+
+    #[error("a module is already registered under id `{0}`")]
+    ModuleAlreadyRegistered(String),
+
+    #[error("module callback failed")]
+    ModuleCallbackFailed,
+
+    #[error("module id cannot be empty")]
+    InvalidModuleId,
+
+    #[error("no channel end exists for port `{0}` and channel `{1}`")]
+    MissingChannel(PortId, crate::ics24_host::identifier::ChannelId),
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
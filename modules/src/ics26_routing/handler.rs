@@ -0,0 +1,123 @@
+//! Pre-dispatch: before a channel or packet message is allowed to mutate any handler state, look
+//! up the application module that owns its port (and, through the port, its channel), so the
+//! module's own validation can run first and reject the message before anything is stored.
+
+use crate::ics04_channel::channel::{Counterparty, Order};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::Packet;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics26_routing::context::{Acknowledgement, ModuleId, Router};
+use crate::ics26_routing::error::{Error, Kind};
+
+/// Finds the module bound to `port_id`, failing if no application has claimed that port.
+pub fn lookup_module_by_port(router: &impl Router, port_id: &PortId) -> Result<ModuleId, Error> {
+    router
+        .lookup_module_by_port(port_id)
+        .ok_or_else(|| Kind::PortNotBound(port_id.clone()).into())
+}
+
+/// Finds the module owning the channel identified by `(port_id, channel_id)`. A channel is
+/// always owned by whichever module its port is bound to, so this defers to
+/// `lookup_module_by_port`; `channel_id` is accepted (rather than inferred from `port_id` alone)
+/// so callers can pass the exact channel a packet or handshake message refers to.
+pub fn lookup_module_by_channel(
+    router: &impl Router,
+    port_id: &PortId,
+    _channel_id: &ChannelId,
+) -> Result<ModuleId, Error> {
+    lookup_module_by_port(router, port_id)
+}
+
+/// Runs pre-dispatch validation for a `ChanOpenInit` message (capability check via the channel
+/// reader's port/channel lookup is the caller's responsibility; this step only resolves the
+/// owning module), then invokes the module's `on_chan_open_init` callback and, if it accepts,
+/// returns `version` unchanged, to be written back into the channel end the core handler is
+/// building.
+#[allow(clippy::too_many_arguments)]
+pub fn dispatch_chan_open_init(
+    ctx: &impl ChannelReader,
+    router: &mut impl Router,
+    order: Order,
+    connection_hops: &[crate::ics24_host::identifier::ConnectionId],
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    version: &str,
+) -> Result<String, Error> {
+    let module_id = ctx
+        .lookup_module_by_port(port_id)
+        .ok_or_else(|| Kind::PortNotBound(port_id.clone()))?;
+
+    let module = router
+        .get_route_mut(&module_id)
+        .ok_or_else(|| Kind::PortNotBound(port_id.clone()))?;
+
+    module
+        .on_chan_open_init(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            version,
+        )
+        .map_err(|e| Error::from(Kind::ModuleCallbackFailed.context(e)))?;
+
+    Ok(version.to_string())
+}
+
+/// Same as [`dispatch_chan_open_init`], but for the `ChanOpenTry` step: the module may propose a
+/// different version than the counterparty's, which is what gets written back.
+#[allow(clippy::too_many_arguments)]
+pub fn dispatch_chan_open_try(
+    ctx: &impl ChannelReader,
+    router: &mut impl Router,
+    order: Order,
+    connection_hops: &[crate::ics24_host::identifier::ConnectionId],
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    counterparty: &Counterparty,
+    counterparty_version: &str,
+) -> Result<String, Error> {
+    let module_id = ctx
+        .lookup_module_by_port(port_id)
+        .ok_or_else(|| Kind::PortNotBound(port_id.clone()))?;
+
+    let module = router
+        .get_route_mut(&module_id)
+        .ok_or_else(|| Kind::PortNotBound(port_id.clone()))?;
+
+    module
+        .on_chan_open_try(
+            order,
+            connection_hops,
+            port_id,
+            channel_id,
+            counterparty,
+            counterparty_version,
+        )
+        .map_err(|e| Error::from(Kind::ModuleCallbackFailed.context(e)))
+}
+
+/// Resolves the module owning `port_id`/`channel_id` and hands it the received `packet`,
+/// returning the `Acknowledgement` it produced so the packet handler can write it back to the
+/// chain store.
+pub fn dispatch_recv_packet(
+    ctx: &impl ChannelReader,
+    router: &impl Router,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    packet: &Packet,
+) -> Result<Acknowledgement, Error> {
+    let (module_id, _channel_end) = ctx
+        .lookup_module_by_channel(port_id, channel_id)
+        .ok_or_else(|| Kind::MissingChannel(port_id.clone(), channel_id.clone()))?;
+
+    let module = router
+        .get_route(&module_id)
+        .ok_or_else(|| Kind::PortNotBound(port_id.clone()))?;
+
+    module
+        .on_recv_packet(packet)
+        .map_err(|e| Error::from(Kind::ModuleCallbackFailed.context(e)))
+}
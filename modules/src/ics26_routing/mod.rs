@@ -0,0 +1,7 @@
+//! ICS 26: Routing Module, dispatching messages to the IBC application (e.g. ICS20 token
+//! transfer) registered for the port they target.
+
+pub mod context;
+pub mod error;
+pub mod handler;
+pub mod msgs;
@@ -0,0 +1,17 @@
+//! The envelope routed by the ICS26 handler: every message an external party submits to an
+//! IBC-enabled chain is wrapped in one of these before being dispatched to its owning handler.
+
+use crate::applications::transfer::msgs::transfer::MsgTransfer;
+use crate::ics02_client::msgs::ClientMsg;
+use crate::ics03_connection::msgs::ConnectionMsg;
+use crate::ics04_channel::msgs::{ChannelMsg, PacketMsg};
+
+/// Enumeration of all the messages that the local ICS26 router may dispatch.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ICS26Envelope {
+    Ics2Msg(ClientMsg),
+    Ics3Msg(ConnectionMsg),
+    Ics4ChannelMsg(ChannelMsg),
+    Ics4PacketMsg(PacketMsg),
+    Ics20Msg(MsgTransfer),
+}
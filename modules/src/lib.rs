@@ -16,8 +16,14 @@
 //! - ICS 07: Tendermint Client
 //! - ICS 23: Vector Commitment Scheme
 //! - ICS 24: Host Requirements
+//! - ICS 26: Routing Module
 
+pub mod applications;
+pub mod height;
 pub mod ics02_client;
 pub mod ics07_tendermint;
-// pub mod ics23_commitment;
+pub mod ics23_commitment;
 pub mod ics24_host;
+pub mod ics26_routing;
+
+pub use height::Height;
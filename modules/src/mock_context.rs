@@ -0,0 +1,137 @@
+//! A mock context, used for testing handlers of any ICS, where the host chain is replaced by an
+//! in-memory store of the minimal state that a handler needs to read or write.
+
+use std::collections::HashMap;
+
+use tendermint::block::Height;
+
+use crate::ics02_client::client_def::AnyClientState;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
+use crate::ics03_connection::error::Error;
+use crate::ics23_commitment::CommitmentPrefix;
+use crate::ics24_host::identifier::{ClientId, ConnectionId};
+
+/// A mock of an IBC host chain's state, suitable for testing the connection and channel handshake
+/// handlers without needing a full chain implementation.
+#[derive(Clone, Debug)]
+pub struct MockContext {
+    /// The maximum number of past heights that `history` retains.
+    max_history_size: usize,
+
+    /// The heights of the blocks the host chain has processed, in increasing order, with
+    /// `latest_height` at the back.
+    history: Vec<Height>,
+
+    /// The clients known to the host chain, together with the height at which each was created.
+    clients: HashMap<ClientId, Height>,
+
+    /// The connections known to the host chain.
+    connections: HashMap<ConnectionId, ConnectionEnd>,
+
+    /// How many connections have been created thus far.
+    connection_counter: u64,
+}
+
+impl MockContext {
+    pub fn new(max_history_size: usize, latest_height: Height) -> Self {
+        let n = latest_height.value();
+        let history = ((n.saturating_sub(max_history_size as u64) + 1)..=n)
+            .map(Height)
+            .collect();
+
+        Self {
+            max_history_size,
+            history,
+            clients: HashMap::new(),
+            connections: HashMap::new(),
+            connection_counter: 0,
+        }
+    }
+
+    /// Registers a client, as having been created at height `height`, with this mock context.
+    pub fn with_client(self, client_id: &ClientId, height: Height) -> Self {
+        let mut clients = self.clients;
+        clients.insert(client_id.clone(), height);
+        Self { clients, ..self }
+    }
+
+    /// Registers a connection end with this mock context.
+    pub fn with_connection(self, connection_id: ConnectionId, connection_end: ConnectionEnd) -> Self {
+        let mut connections = self.connections;
+        connections.insert(connection_id, connection_end);
+        Self { connections, ..self }
+    }
+}
+
+impl ConnectionReader for MockContext {
+    fn fetch_connection_end(&self, conn_id: &ConnectionId) -> Option<ConnectionEnd> {
+        self.connections.get(conn_id).cloned()
+    }
+
+    fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState> {
+        // This mock does not model concrete client states; it only tracks which client
+        // identifiers the host chain is aware of.
+        self.clients.get(client_id)?;
+        None
+    }
+
+    fn client_consensus_state(
+        &self,
+        _client_id: &ClientId,
+        _height: Height,
+    ) -> Option<crate::ics02_client::client_def::AnyConsensusState> {
+        None
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        CommitmentPrefix::new(b"ibc".to_vec()).expect("non-empty commitment prefix")
+    }
+
+    fn host_current_height(&self) -> Height {
+        *self.history.last().expect("history is never empty")
+    }
+
+    fn host_oldest_height(&self) -> Height {
+        *self.history.first().expect("history is never empty")
+    }
+
+    fn get_compatible_versions(&self) -> Vec<String> {
+        vec!["1.0.0".to_string()]
+    }
+
+    fn pick_version(&self, candidates: Vec<String>) -> String {
+        candidates
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| "1.0.0".to_string())
+    }
+
+    fn connection_counter(&self) -> u64 {
+        self.connection_counter
+    }
+}
+
+impl ConnectionKeeper for MockContext {
+    fn store_connection(
+        &mut self,
+        connection_id: ConnectionId,
+        connection_end: &ConnectionEnd,
+    ) -> Result<(), Error> {
+        self.connections
+            .insert(connection_id, connection_end.clone());
+        Ok(())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        _connection_id: ConnectionId,
+        _client_id: &ClientId,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn increase_connection_counter(&mut self) {
+        self.connection_counter += 1;
+    }
+}
@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+/// A point in time expressed as nanoseconds since the Unix epoch (ADR-028), with an explicit
+/// "no timestamp" state distinct from zero so hosts and messages that never set a timeout
+/// timestamp don't get compared against the epoch by accident.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Timestamp {
+    /// No timestamp has been set.
+    None,
+    /// A concrete point in time, as nanoseconds since the Unix epoch.
+    Time(u64),
+}
+
+impl Timestamp {
+    /// A `Timestamp` carrying no value.
+    pub fn none() -> Self {
+        Self::None
+    }
+
+    /// Builds a `Timestamp` from a nanosecond count since the Unix epoch.
+    pub fn from_nanoseconds(nanoseconds: u64) -> Self {
+        Self::Time(nanoseconds)
+    }
+
+    /// The nanosecond count since the Unix epoch this timestamp represents, or `0` if none was
+    /// set. Matches the on-chain encoding, where an unset timeout timestamp is stored as `0`.
+    pub fn nanoseconds(&self) -> u64 {
+        match self {
+            Self::None => 0,
+            Self::Time(t) => *t,
+        }
+    }
+
+    /// Returns `self + duration`, or `None` if `self` carries no value or the addition would
+    /// overflow.
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        match self {
+            Self::None => None,
+            Self::Time(t) => t
+                .checked_add(duration.as_nanos().try_into().ok()?)
+                .map(Self::Time),
+        }
+    }
+
+    /// Returns the `Duration` elapsed between `earlier` and `self`, or `None` if either
+    /// `Timestamp` carries no value or `earlier` is after `self`.
+    pub fn duration_since(&self, earlier: &Self) -> Option<Duration> {
+        match (self, earlier) {
+            (Self::Time(later), Self::Time(earlier)) if later >= earlier => {
+                Some(Duration::from_nanos(later - earlier))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for Timestamp {
+    fn default() -> Self {
+        Self::None
+    }
+}
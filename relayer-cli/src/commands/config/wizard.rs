@@ -0,0 +1,98 @@
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::config::wizard::Prompter;
+use ibc_relayer::config::{self, ChainConfig, Error};
+
+use crate::conclude::Output;
+
+/// Interactively build a `ChainConfig` for a running chain and append it to the config file,
+/// rather than requiring every field -- `account_prefix`, `store_prefix`, `gas_price`, a
+/// `trusting_period` safely below the unbonding period, and the rest -- to be known up front.
+#[derive(Clone, Command, Debug, Parser)]
+pub struct ConfigWizardCmd {
+    #[clap(
+        long = "config",
+        required = true,
+        value_name = "CONFIG_FILE",
+        help = "Path to the config file the new chain should be appended to"
+    )]
+    config_path: PathBuf,
+
+    #[clap(
+        long = "rpc-addr",
+        required = true,
+        value_name = "RPC_ADDR",
+        help = "RPC address of the chain to add, e.g. http://localhost:26657"
+    )]
+    rpc_addr: tendermint_rpc::Url,
+
+    #[clap(
+        long = "websocket-addr",
+        required = true,
+        value_name = "WEBSOCKET_ADDR",
+        help = "Websocket address of the chain to add, e.g. ws://localhost:26657/websocket"
+    )]
+    websocket_addr: tendermint_rpc::Url,
+
+    #[clap(
+        long = "grpc-addr",
+        required = true,
+        value_name = "GRPC_ADDR",
+        help = "gRPC address of the chain to add, e.g. http://localhost:9090"
+    )]
+    grpc_addr: tendermint_rpc::Url,
+}
+
+impl ConfigWizardCmd {
+    fn execute(&self) -> Result<String, Error> {
+        let chain_config = ChainConfig::from_wizard(
+            self.rpc_addr.clone(),
+            self.websocket_addr.clone(),
+            self.grpc_addr.clone(),
+            &TerminalPrompter,
+        )?;
+
+        let chain_id = chain_config.id.clone();
+
+        let mut relayer_config = config::load(&self.config_path)?;
+        relayer_config.chains.push(chain_config);
+
+        config::store(&relayer_config, &self.config_path)?;
+
+        Ok(format!("Ok: added chain '{chain_id}' to the config file"))
+    }
+}
+
+impl Runnable for ConfigWizardCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(output) => Output::success(output).exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
+
+/// A [`Prompter`] that prompts on the real terminal via stdin/stdout.
+struct TerminalPrompter;
+
+impl Prompter for TerminalPrompter {
+    fn prompt(&self, field: &str, default: &str) -> Result<String, Error> {
+        print!("{field} [{default}]: ");
+        io::stdout().flush().map_err(Error::io)?;
+
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer).map_err(Error::io)?;
+
+        let answer = answer.trim();
+
+        Ok(if answer.is_empty() {
+            default.to_string()
+        } else {
+            answer.to_string()
+        })
+    }
+}
@@ -1,9 +1,13 @@
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
-use ibc_relayer::chain::requests::{IncludeProof, QueryHeight, QueryPacketAcknowledgementRequest};
+use ibc_relayer::chain::requests::{
+    IncludeProof, QueryChannelRequest, QueryConnectionRequest, QueryConsensusStateRequest,
+    QueryHeight, QueryPacketAcknowledgementRequest,
+};
 use subtle_encoding::{Encoding, Hex};
 
 use ibc::core::ics04_channel::packet::Sequence;
+use ibc::core::ics23_commitment::merkle::{apply_prefix, MerklePath, MerkleProof, ProofSpecs};
 use ibc::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer::chain::handle::ChainHandle;
 
@@ -54,6 +58,19 @@ pub struct QueryPacketAcknowledgmentCmd {
         help = "Height of the state to query. Leave unspecified for latest height."
     )]
     height: Option<u64>,
+
+    #[clap(
+        long = "proof",
+        help = "Also query and print the proof of the acknowledgement commitment"
+    )]
+    proof: bool,
+
+    #[clap(
+        long = "verify",
+        help = "Verify the queried proof against the consensus state root at the queried height \
+                (implies --proof)"
+    )]
+    verify: bool,
 }
 
 impl QueryPacketAcknowledgmentCmd {
@@ -64,34 +81,120 @@ impl QueryPacketAcknowledgmentCmd {
 
         let chain = spawn_chain_runtime(&config, &self.chain_id)?;
 
-        chain
+        let query_height = self.height.map_or(QueryHeight::Latest, |revision_height| {
+            QueryHeight::Specific(
+                ibc::Height::new(chain.id().version(), revision_height)
+                    .unwrap_or_else(exit_with_unrecoverable_error),
+            )
+        });
+
+        let include_proof = if self.proof || self.verify {
+            IncludeProof::Yes
+        } else {
+            IncludeProof::No
+        };
+
+        let (bytes, proof) = chain
             .query_packet_acknowledgement(
                 QueryPacketAcknowledgementRequest {
                     port_id: self.port_id.clone(),
                     channel_id: self.channel_id.clone(),
                     sequence: self.sequence,
-                    height: self.height.map_or(QueryHeight::Latest, |revision_height| {
-                        QueryHeight::Specific(
-                            ibc::Height::new(chain.id().version(), revision_height)
-                                .unwrap_or_else(exit_with_unrecoverable_error),
-                        )
-                    }),
+                    height: query_height,
+                },
+                include_proof,
+            )
+            .map_err(Error::relayer)?;
+
+        let value_hex = Hex::upper_case()
+            .encode_to_string(bytes.clone())
+            .unwrap_or_else(|_| format!("{:?}", bytes));
+
+        let merkle_proof = match proof {
+            Some(merkle_proof) => merkle_proof,
+            None => return Ok(value_hex),
+        };
+
+        let mut output = format!("value: {}\nproof: {:?}", value_hex, merkle_proof);
+
+        if self.verify {
+            let verified = self.verify_membership(&chain, query_height, &merkle_proof, bytes)?;
+            output.push_str(&format!("\nverified: {}", verified));
+        }
+
+        Ok(output)
+    }
+
+    /// Re-derives the commitment path for the queried acknowledgement and checks the returned
+    /// proof against the root of the consensus state the counterparty client holds for `height`.
+    fn verify_membership(
+        &self,
+        chain: &impl ChainHandle,
+        height: QueryHeight,
+        merkle_proof: &MerkleProof,
+        value: Vec<u8>,
+    ) -> Result<bool, Error> {
+        let (channel_end, _) = chain
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.port_id.clone(),
+                    channel_id: self.channel_id.clone(),
+                    height,
+                },
+                IncludeProof::No,
+            )
+            .map_err(Error::relayer)?;
+
+        let connection_id = channel_end
+            .connection_hops
+            .first()
+            .ok_or_else(|| Error::relayer("channel end has no connection hops".to_string()))?
+            .clone();
+
+        let (connection_end, _) = chain
+            .query_connection(
+                QueryConnectionRequest {
+                    connection_id,
+                    height,
+                },
+                IncludeProof::No,
+            )
+            .map_err(Error::relayer)?;
+
+        let consensus_height = match height {
+            QueryHeight::Latest => chain.query_latest_height().map_err(Error::relayer)?,
+            QueryHeight::Specific(height) => height,
+        };
+
+        let (consensus_state, _) = chain
+            .query_consensus_state(
+                QueryConsensusStateRequest {
+                    client_id: connection_end.client_id().clone(),
+                    consensus_height,
+                    query_height: height,
                 },
                 IncludeProof::No,
             )
-            .map_err(Error::relayer)
-            .map(|(bytes, _)| {
-                Hex::upper_case()
-                    .encode_to_string(bytes.clone())
-                    .unwrap_or_else(|_| format!("{:?}", bytes))
-            })
+            .map_err(Error::relayer)?;
+
+        let merkle_path: MerklePath = apply_prefix(
+            connection_end.counterparty().prefix(),
+            format!(
+                "acks/ports/{}/channels/{}/sequences/{}",
+                self.port_id, self.channel_id, self.sequence
+            ),
+        );
+
+        Ok(merkle_proof
+            .verify_membership(&ProofSpecs::cosmos(), consensus_state.root(), merkle_path, value)
+            .is_ok())
     }
 }
 
 impl Runnable for QueryPacketAcknowledgmentCmd {
     fn run(&self) {
         match self.execute() {
-            Ok(hex) => Output::success(hex).exit(),
+            Ok(output) => Output::success(output).exit(),
             Err(e) => Output::error(format!("{}", e)).exit(),
         }
     }
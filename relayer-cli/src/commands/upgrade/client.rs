@@ -0,0 +1,189 @@
+use core::time::Duration;
+use std::thread;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use prost::Message;
+
+use ibc::core::ics02_client::msgs::upgrade_client::MsgUpgradeClient;
+use ibc::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_proto::cosmos::gov::v1beta1::{query_client::QueryClient, QueryProposalRequest};
+use ibc_proto::ibc::core::client::v1::UpgradeProposal;
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use ibc_relayer::chain::tracking::TrackedMsgs;
+
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::Output;
+use crate::error::Error;
+use crate::prelude::*;
+
+/// The interval at which the reference chain is polled while waiting for it to reach the planned
+/// upgrade height.
+const UPGRADE_HEIGHT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Command, Debug, Parser)]
+pub struct UpgradeClientCmd {
+    #[clap(
+        long = "host-chain",
+        required = true,
+        value_name = "HOST_CHAIN_ID",
+        help = "Identifier of the chain that hosts the client to be upgraded"
+    )]
+    host_chain_id: ChainId,
+
+    #[clap(
+        long = "client",
+        required = true,
+        value_name = "CLIENT_ID",
+        help = "Identifier of the client to be upgraded"
+    )]
+    client_id: ClientId,
+
+    #[clap(
+        long = "proposal-id",
+        required = true,
+        value_name = "PROPOSAL_ID",
+        help = "Identifier of the governance proposal, on the chain the client tracks, that \
+                carries the upgrade plan"
+    )]
+    proposal_id: u64,
+}
+
+impl UpgradeClientCmd {
+    fn execute(&self) -> Result<String, Error> {
+        let config = app_config();
+
+        debug!("Options: {:?}", self);
+
+        let host_chain = spawn_chain_runtime(&config, &self.host_chain_id)?;
+
+        let client_state = host_chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: self.client_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(Error::relayer)?
+            .0;
+
+        let reference_chain_id = client_state.chain_id();
+        let reference_chain = spawn_chain_runtime(&config, &reference_chain_id)?;
+
+        let plan_height = self.query_plan_height(&reference_chain)?;
+
+        self.wait_for_plan_height(&reference_chain, plan_height)?;
+
+        let (client_state, proof_upgrade_client) = reference_chain
+            .query_upgraded_client_state(plan_height)
+            .map_err(Error::relayer)?;
+
+        let (consensus_state, proof_upgrade_consensus_state) = reference_chain
+            .query_upgraded_consensus_state(plan_height)
+            .map_err(Error::relayer)?;
+
+        let signer = host_chain.get_signer().map_err(Error::relayer)?;
+
+        let msg = MsgUpgradeClient {
+            client_id: self.client_id.clone(),
+            client_state,
+            consensus_state,
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+            signer,
+        };
+
+        let tm = TrackedMsgs::new_static(vec![msg.to_any()], "UpgradeClient");
+
+        let events = host_chain
+            .send_messages_and_wait_commit(tm)
+            .map_err(Error::relayer)?;
+
+        Ok(format!("Ok: client {} upgraded, events: {:?}", self.client_id, events))
+    }
+
+    /// Reads the governance proposal with `self.proposal_id` off of `reference_chain`, decodes it
+    /// as an `UpgradeProposal`, and returns the height of its upgrade plan.
+    fn query_plan_height(&self, reference_chain: &impl ChainHandle) -> Result<ibc::Height, Error> {
+        let grpc_address = reference_chain
+            .config()
+            .map_err(Error::relayer)?
+            .grpc_addr;
+
+        let proposal_id = self.proposal_id;
+
+        let rt = tokio::runtime::Runtime::new().map_err(|e| Error::relayer(e.into()))?;
+
+        let plan_height = rt.block_on(async move {
+            let mut client = QueryClient::connect(grpc_address.to_string())
+                .await
+                .map_err(|_| Error::query_client())?;
+
+            let response = client
+                .proposal(QueryProposalRequest { proposal_id })
+                .await
+                .map_err(|e| Error::relayer(ibc_relayer::error::Error::grpc_status(e)))?
+                .into_inner();
+
+            let proposal = response
+                .proposal
+                .ok_or_else(|| Error::empty_query("upgrade proposal".to_string()))?;
+
+            let content = proposal
+                .content
+                .ok_or_else(|| Error::empty_query("upgrade proposal content".to_string()))?;
+
+            if content.type_url != *"/ibc.core.client.v1.UpgradeProposal" {
+                return Err(Error::incorrect_proposal_type_url(content.type_url));
+            }
+
+            let upgrade_proposal = UpgradeProposal::decode(content.value.as_slice())
+                .map_err(|_| Error::incorrect_proposal())?;
+
+            let plan = upgrade_proposal.plan.ok_or_else(Error::empty_upgrade_plan)?;
+
+            Ok(plan.height)
+        })?;
+
+        ibc::Height::new(reference_chain.id().version(), plan_height as u64)
+            .map_err(|e| Error::relayer(e.into()))
+    }
+
+    /// Blocks until `reference_chain` reaches `plan_height`, so that the upgraded client and
+    /// consensus states it committed at that height can be queried.
+    fn wait_for_plan_height(
+        &self,
+        reference_chain: &impl ChainHandle,
+        plan_height: ibc::Height,
+    ) -> Result<(), Error> {
+        loop {
+            let latest_height = reference_chain
+                .query_latest_height()
+                .map_err(Error::relayer)?;
+
+            if latest_height >= plan_height {
+                return Ok(());
+            }
+
+            info!(
+                "waiting for {} to reach upgrade height {} (currently at {})",
+                reference_chain.id(),
+                plan_height,
+                latest_height
+            );
+
+            thread::sleep(UPGRADE_HEIGHT_POLL_INTERVAL);
+        }
+    }
+}
+
+impl Runnable for UpgradeClientCmd {
+    fn run(&self) {
+        match self.execute() {
+            Ok(output) => Output::success(output).exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
@@ -12,6 +12,9 @@ use ibc::core::ics02_client::height::Height;
 use ibc::core::ics03_connection::connection::ConnectionEnd;
 use ibc::core::ics04_channel::channel::ChannelEnd;
 use ibc::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::events::IbcEvent;
+
+use crate::telemetry;
 
 #[derive(Clone)]
 pub struct Cache {
@@ -63,8 +66,10 @@ impl Cache {
         // FIXME: create a struct type for this
         let key = (port_id.clone(), channel_id.clone());
         if let Some(chan) = self.channels.get(&key) {
+            telemetry!(cache_hits, "channel", 1);
             Ok(chan)
         } else {
+            telemetry!(cache_misses, "channel", 1);
             let chan = f()?;
             if chan.state().is_open() {
                 self.channels.insert(key, chan.clone());
@@ -82,8 +87,10 @@ impl Cache {
         F: FnOnce() -> Result<ConnectionEnd, E>,
     {
         if let Some(conn) = self.connections.get(id) {
+            telemetry!(cache_hits, "connection", 1);
             Ok(conn)
         } else {
+            telemetry!(cache_misses, "connection", 1);
             let conn = f()?;
             if conn.state().is_open() {
                 self.connections.insert(id.clone(), conn.clone());
@@ -101,8 +108,14 @@ impl Cache {
         F: FnOnce() -> Result<AnyClientState, E>,
     {
         if let Some(state) = self.client_states.get(id) {
+            telemetry!(cache_hits, "client_state", 1);
             Ok(state)
         } else {
+            // This cache is TTL-only (no idle eviction and nothing else ever
+            // removes an entry), so every miss for a previously-seen id is an
+            // expiration rather than a cold lookup.
+            telemetry!(cache_misses, "client_state", 1);
+            telemetry!(cache_expirations, "client_state", 1);
             let state = f()?;
             self.client_states.insert(id.clone(), state.clone());
             Ok(state)
@@ -114,13 +127,70 @@ impl Cache {
         F: FnOnce() -> Result<Height, E>,
     {
         if let Some(height) = self.latest_height.get(&()) {
+            telemetry!(cache_hits, "latest_height", 1);
             Ok(height)
         } else {
+            telemetry!(cache_misses, "latest_height", 1);
+            telemetry!(cache_expirations, "latest_height", 1);
             let height = f()?;
             self.latest_height.insert((), height);
             Ok(height)
         }
     }
+
+    /// Evicts a channel end ahead of its TTL, so that a later lookup doesn't
+    /// keep returning the now-stale cached end for the rest of the TTL window.
+    /// Called by [`Self::invalidate_for_event`] for channel events.
+    pub fn invalidate_channel(&self, port_id: &PortId, channel_id: &ChannelId) {
+        telemetry!(cache_invalidations, "channel", 1);
+        self.channels.invalidate(&(port_id.clone(), channel_id.clone()));
+    }
+
+    /// Evicts a connection end ahead of its TTL; see [`Self::invalidate_channel`].
+    pub fn invalidate_connection(&self, connection_id: &ConnectionId) {
+        telemetry!(cache_invalidations, "connection", 1);
+        self.connections.invalidate(connection_id);
+    }
+
+    /// Evicts a client state ahead of its TTL; see [`Self::invalidate_channel`].
+    pub fn invalidate_client_state(&self, client_id: &ClientId) {
+        telemetry!(cache_invalidations, "client_state", 1);
+        self.client_states.invalidate(client_id);
+    }
+
+    /// Invalidates whichever cached entry, if any, the given event renders
+    /// stale. Nothing in the event-handling path calls it, so a cached
+    /// channel/connection/client entry that has changed on chain is still
+    /// returned until its TTL expires.
+    pub fn invalidate_for_event(&self, event: &IbcEvent) {
+        match event {
+            IbcEvent::CloseInitChannel(ev) => {
+                self.invalidate_channel(ev.port_id(), ev.channel_id());
+            }
+            IbcEvent::CloseConfirmChannel(ev) => {
+                self.invalidate_channel(ev.port_id(), ev.channel_id());
+            }
+            IbcEvent::OpenAckChannel(ev) => {
+                self.invalidate_channel(ev.port_id(), ev.channel_id());
+            }
+            IbcEvent::OpenConfirmChannel(ev) => {
+                self.invalidate_channel(ev.port_id(), ev.channel_id());
+            }
+            IbcEvent::OpenAckConnection(ev) => {
+                self.invalidate_connection(ev.connection_id());
+            }
+            IbcEvent::OpenConfirmConnection(ev) => {
+                self.invalidate_connection(ev.connection_id());
+            }
+            IbcEvent::UpdateClient(ev) => {
+                self.invalidate_client_state(ev.client_id());
+            }
+            IbcEvent::UpgradeClient(ev) => {
+                self.invalidate_client_state(ev.client_id());
+            }
+            _ => {}
+        }
+    }
 }
 
 impl fmt::Debug for Cache {
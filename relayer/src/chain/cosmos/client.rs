@@ -0,0 +1,80 @@
+use core::time::Duration;
+
+use ibc::clients::ics07_tendermint::client_state::AllowUpdate;
+use ibc::core::ics02_client::trust_threshold::TrustThreshold;
+use ibc::core::ics23_commitment::specs::ProofSpecs;
+use ibc::Height;
+
+/// The subset of a Tendermint `ClientState`'s fields that can be customized when the relayer
+/// creates a client for a Cosmos SDK chain. A field left `None` falls back to the default the
+/// relayer derives from the configuration of the chain pair being connected.
+#[derive(Clone, Debug, Default)]
+pub struct Settings {
+    pub max_clock_drift: Option<Duration>,
+    pub trusting_period: Option<Duration>,
+    pub trust_threshold: Option<TrustThreshold>,
+    pub unbonding_period: Option<Duration>,
+    pub allow_update: Option<AllowUpdate>,
+    pub upgrade_path: Option<Vec<String>>,
+    pub proof_specs: Option<ProofSpecs>,
+    pub frozen_height: Option<Height>,
+}
+
+impl Settings {
+    /// Starts building a [`Settings`] with every field defaulted to `None`, i.e. with every
+    /// client parameter falling back to the configuration-derived default, letting a caller
+    /// override only the fields it cares about.
+    pub fn builder() -> SettingsBuilder {
+        SettingsBuilder::default()
+    }
+}
+
+/// A fluent builder for [`Settings`]. See [`Settings::builder`].
+#[derive(Clone, Debug, Default)]
+pub struct SettingsBuilder(Settings);
+
+impl SettingsBuilder {
+    pub fn max_clock_drift(mut self, max_clock_drift: Duration) -> Self {
+        self.0.max_clock_drift = Some(max_clock_drift);
+        self
+    }
+
+    pub fn trusting_period(mut self, trusting_period: Duration) -> Self {
+        self.0.trusting_period = Some(trusting_period);
+        self
+    }
+
+    pub fn trust_threshold(mut self, trust_threshold: TrustThreshold) -> Self {
+        self.0.trust_threshold = Some(trust_threshold);
+        self
+    }
+
+    pub fn unbonding_period(mut self, unbonding_period: Duration) -> Self {
+        self.0.unbonding_period = Some(unbonding_period);
+        self
+    }
+
+    pub fn allow_update(mut self, allow_update: AllowUpdate) -> Self {
+        self.0.allow_update = Some(allow_update);
+        self
+    }
+
+    pub fn upgrade_path(mut self, upgrade_path: Vec<String>) -> Self {
+        self.0.upgrade_path = Some(upgrade_path);
+        self
+    }
+
+    pub fn proof_specs(mut self, proof_specs: ProofSpecs) -> Self {
+        self.0.proof_specs = Some(proof_specs);
+        self
+    }
+
+    pub fn frozen_height(mut self, frozen_height: Height) -> Self {
+        self.0.frozen_height = Some(frozen_height);
+        self
+    }
+
+    pub fn build(self) -> Settings {
+        self.0
+    }
+}
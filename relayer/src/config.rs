@@ -5,6 +5,7 @@ mod filter_pattern;
 mod proof_specs;
 pub mod reload;
 pub mod types;
+pub mod wizard;
 
 use alloc::collections::BTreeMap;
 use core::{fmt, time::Duration};
@@ -17,6 +18,7 @@ use serde::{de, ser, Deserializer, Serializer};
 use serde_derive::{Deserialize, Serialize};
 use tendermint_light_client_verifier::types::TrustThreshold;
 
+use ibc::applications::transfer::packet::FungibleTokenPacketData;
 use ibc::core::ics23_commitment::specs::ProofSpecs;
 use ibc::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc::timestamp::ZERO_DURATION;
@@ -45,8 +47,22 @@ impl fmt::Display for GasPrice {
     }
 }
 
+/// A minimum balance, below which [`crate::worker::wallet::spawn_wallet_worker`] raises a
+/// low-balance alert for the relayer wallet on this chain.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MinimumBalance {
+    pub amount: f64,
+    pub denom: String,
+}
+
+impl fmt::Display for MinimumBalance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
 /// Represents the ways in which packets can be filtered.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(
     rename_all = "lowercase",
     tag = "policy",
@@ -79,36 +95,71 @@ impl PacketFilter {
             PacketFilter::AllowAll => true,
         }
     }
+
+    /// As [`Self::is_allowed`], but for a transfer packet whose contents have already been
+    /// decoded: if the entry matching `port_id`/`channel_id` also carries a [`PacketFilterContent`]
+    /// predicate, `packet_data` must satisfy it too. An entry with no content predicate allows any
+    /// transfer on its channel, exactly as [`Self::is_allowed`] would.
+    pub fn is_allowed_transfer(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        packet_data: &FungibleTokenPacketData,
+    ) -> bool {
+        match self {
+            PacketFilter::Allow(spec) => spec.matches_transfer(port_id, channel_id, packet_data),
+            PacketFilter::Deny(spec) => !spec.matches_transfer(port_id, channel_id, packet_data),
+            PacketFilter::AllowAll => true,
+        }
+    }
 }
 
 /// The internal representation of channel filter policies.
-#[derive(Clone, Debug, Default, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
 #[serde(deny_unknown_fields)]
-pub struct ChannelFilters(Vec<(PortFilterMatch, ChannelFilterMatch)>);
+pub struct ChannelFilters(Vec<ChannelFilterEntry>);
 
 impl ChannelFilters {
     /// Indicates whether a match for the given [`PortId`]-[`ChannelId`] pair
     /// exists in the filter policy.
     pub fn matches(&self, channel_port: &(PortId, ChannelId)) -> bool {
         let (port_id, channel_id) = channel_port;
-        self.0.iter().any(|(port_filter, chan_filter)| {
-            port_filter.matches(port_id) && chan_filter.matches(channel_id)
+        self.0
+            .iter()
+            .any(|entry| entry.port.matches(port_id) && entry.channel.matches(channel_id))
+    }
+
+    /// As [`Self::matches`], but additionally checks the matching entry's [`PacketFilterContent`]
+    /// predicate (if any) against `packet_data`.
+    pub fn matches_transfer(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        packet_data: &FungibleTokenPacketData,
+    ) -> bool {
+        self.0.iter().any(|entry| {
+            entry.port.matches(port_id)
+                && entry.channel.matches(channel_id)
+                && entry
+                    .content
+                    .as_ref()
+                    .map_or(true, |content| content.allows(packet_data))
         })
     }
 
     /// Indicates whether this filter policy contains only exact patterns.
     #[inline]
     pub fn is_exact(&self) -> bool {
-        self.0.iter().all(|(port_filter, channel_filter)| {
-            port_filter.is_exact() && channel_filter.is_exact()
-        })
+        self.0
+            .iter()
+            .all(|entry| entry.port.is_exact() && entry.channel.is_exact())
     }
 
     /// An iterator over the [`PortId`]-[`ChannelId`] pairs that don't contain wildcards.
     pub fn iter_exact(&self) -> impl Iterator<Item = (&PortId, &ChannelId)> {
-        self.0.iter().filter_map(|port_chan_filter| {
-            if let &(FilterPattern::Exact(ref port_id), FilterPattern::Exact(ref chan_id)) =
-                port_chan_filter
+        self.0.iter().filter_map(|entry| {
+            if let (FilterPattern::Exact(ref port_id), FilterPattern::Exact(ref chan_id)) =
+                (&entry.port, &entry.channel)
             {
                 Some((port_id, chan_id))
             } else {
@@ -125,7 +176,7 @@ impl fmt::Display for ChannelFilters {
             "{}",
             self.0
                 .iter()
-                .map(|(pid, cid)| format!("{}/{}", pid, cid))
+                .map(|entry| format!("{}/{}", entry.port, entry.channel))
                 .join(", ")
         )
     }
@@ -138,33 +189,120 @@ impl ser::Serialize for ChannelFilters {
     {
         use serde::ser::SerializeSeq;
 
-        struct Pair<'a> {
-            a: &'a FilterPattern<PortId>,
-            b: &'a FilterPattern<ChannelId>,
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+
+        for entry in &self.0 {
+            seq.serialize_element(entry)?;
         }
 
-        impl<'a> ser::Serialize for Pair<'a> {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        seq.end()
+    }
+}
+
+/// A single `(port, channel[, content])` entry of a [`ChannelFilters`] list. The optional
+/// [`PacketFilterContent`] further restricts matches to transfer packets whose decoded contents
+/// satisfy it; an entry with no `content` matches on `port`/`channel` alone, exactly as before
+/// this field was introduced.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelFilterEntry {
+    pub port: PortFilterMatch,
+    pub channel: ChannelFilterMatch,
+    pub content: Option<PacketFilterContent>,
+}
+
+impl<'de> de::Deserialize<'de> for ChannelFilterEntry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntryVisitor;
+
+        impl<'de> de::Visitor<'de> for EntryVisitor {
+            type Value = ChannelFilterEntry;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a (port, channel) or (port, channel, content) filter entry")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
             where
-                S: Serializer,
+                A: de::SeqAccess<'de>,
             {
-                let mut seq = serializer.serialize_seq(Some(2))?;
-                seq.serialize_element(self.a)?;
-                seq.serialize_element(self.b)?;
-                seq.end()
+                let port = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let channel = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let content = seq.next_element()?;
+
+                Ok(ChannelFilterEntry {
+                    port,
+                    channel,
+                    content,
+                })
             }
         }
 
-        let mut outer_seq = serializer.serialize_seq(Some(self.0.len()))?;
+        deserializer.deserialize_seq(EntryVisitor)
+    }
+}
 
-        for (port, channel) in &self.0 {
-            outer_seq.serialize_element(&Pair {
-                a: port,
-                b: channel,
-            })?;
+impl ser::Serialize for ChannelFilterEntry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(if self.content.is_some() { 3 } else { 2 }))?;
+        seq.serialize_element(&self.port)?;
+        seq.serialize_element(&self.channel)?;
+        if let Some(content) = &self.content {
+            seq.serialize_element(content)?;
+        }
+        seq.end()
+    }
+}
+
+/// A predicate on the decoded contents of an ICS-20 transfer packet, carried by a
+/// [`ChannelFilterEntry`] and checked by `is_allowed_transfer` and
+/// `packets_on_channel_allowed_transfer` -- e.g. to accept only high-value transfers, or to
+/// exclude a spam denom. Nothing in the relayer calls either of them: packets are filtered with
+/// `is_allowed`, which ignores this predicate, so it does not change which transfers are relayed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PacketFilterContent {
+    /// A wildcard pattern matched against the packet's base denom.
+    pub denom: Wildcard,
+    /// The minimum transfer amount to allow, inclusive. `None` means no lower bound.
+    #[serde(default)]
+    pub min_amount: Option<u128>,
+    /// The maximum transfer amount to allow, inclusive. `None` means no upper bound.
+    #[serde(default)]
+    pub max_amount: Option<u128>,
+}
+
+impl PacketFilterContent {
+    /// Returns true if `packet_data`'s denom matches [`Self::denom`] and its amount falls within
+    /// [`Self::min_amount`]/[`Self::max_amount`] (each bound, when present, is inclusive). A
+    /// packet whose `amount` doesn't parse as a `u128` is rejected.
+    pub fn allows(&self, packet_data: &FungibleTokenPacketData) -> bool {
+        if !self.denom.is_match(&packet_data.denom) {
+            return false;
+        }
+
+        let amount = match packet_data.amount.parse::<u128>() {
+            Ok(amount) => amount,
+            Err(_) => return false,
+        };
+
+        if self.min_amount.map_or(false, |min| amount < min) {
+            return false;
+        }
+
+        if self.max_amount.map_or(false, |max| amount > max) {
+            return false;
         }
 
-        outer_seq.end()
+        true
     }
 }
 
@@ -195,6 +333,14 @@ impl fmt::Display for Wildcard {
     }
 }
 
+impl PartialEq for Wildcard {
+    /// `regex::Regex` doesn't implement `PartialEq`, so two [`Wildcard`]s are considered equal
+    /// when they were compiled from the same source pattern.
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
 impl ser::Serialize for Wildcard {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -204,8 +350,15 @@ impl ser::Serialize for Wildcard {
     }
 }
 
+impl<'de> de::Deserialize<'de> for Wildcard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Wildcard, D::Error> {
+        let pattern = <String as de::Deserialize>::deserialize(deserializer)?;
+        pattern.parse().map_err(de::Error::custom)
+    }
+}
+
 /// Represents a single channel to be filtered in a [`ChannelFilters`] list.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum FilterPattern<T> {
     /// A channel specified exactly with its [`PortId`] & [`ChannelId`].
     Exact(T),
@@ -314,11 +467,67 @@ pub mod default {
     pub fn connection_delay() -> Duration {
         ZERO_DURATION
     }
+
+    pub fn config_version() -> u32 {
+        CURRENT_CONFIG_VERSION
+    }
+
+    pub fn retry_initial_delay() -> Duration {
+        Duration::from_millis(200)
+    }
+
+    pub fn retry_backoff_factor() -> u32 {
+        2
+    }
+
+    pub fn retry_max_delay() -> Duration {
+        Duration::from_secs(60)
+    }
+
+    pub fn retry_max_count() -> u64 {
+        5
+    }
+
+    /// The fraction of a chain's unbonding period that the wizard derives a `trusting_period`
+    /// from, leaving headroom below the unbonding period so a client doesn't expire before it can
+    /// be refreshed. Mirrors the two-thirds rule recommended by the IBC light client spec.
+    pub fn trusting_period_fraction() -> f64 {
+        2.0 / 3.0
+    }
+}
+
+/// The current version of the on-disk config schema. Bumped whenever a change to `Config` or one
+/// of its fields (e.g. `ModeConfig`, `ChainConfig`) would otherwise make an older config file fail
+/// to deserialize; a migration keyed by the version it starts from must be added to
+/// [`MIGRATIONS`] alongside the bump.
+pub const CURRENT_CONFIG_VERSION: u32 = 1;
+
+/// A single step in the migration registry consulted by [`load`]: given the raw TOML of a config
+/// file written at its source schema version, returns the equivalent TOML one schema version
+/// later. Keyed by source version in [`MIGRATIONS`].
+type ConfigMigration = fn(toml::Value) -> toml::Value;
+
+/// Ordered registry of config schema migrations, keyed by the version a file must be at for the
+/// migration to apply. `load` walks this from the file's version up to
+/// [`CURRENT_CONFIG_VERSION`], applying each migration in sequence.
+const MIGRATIONS: &[(u32, ConfigMigration)] = &[(0, migrate_v0_to_v1)];
+
+/// Config files predating schema versioning have no `version` field at all; this migration just
+/// establishes the field, since versioning was the only thing that changed between the two.
+/// Future migrations that actually reshape a field belong here too, keyed by their source version.
+fn migrate_v0_to_v1(mut config: toml::Value) -> toml::Value {
+    if let toml::Value::Table(table) = &mut config {
+        table.insert("version".to_owned(), toml::Value::Integer(1));
+    }
+
+    config
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
+    #[serde(default = "default::config_version")]
+    pub version: u32,
     #[serde(default)]
     pub global: GlobalConfig,
     #[serde(default)]
@@ -361,12 +570,33 @@ impl Config {
         }
     }
 
+    /// As [`Self::packets_on_channel_allowed`], but for a transfer packet whose contents have
+    /// already been decoded, so that any [`PacketFilterContent`] predicate configured on the
+    /// matching channel is checked too. Nothing in the relayer calls it; the relay path does not
+    /// check content predicates.
+    pub fn packets_on_channel_allowed_transfer(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        packet_data: &FungibleTokenPacketData,
+    ) -> bool {
+        match self.find_chain(chain_id) {
+            Some(chain_config) => {
+                chain_config
+                    .packet_filter
+                    .is_allowed_transfer(port_id, channel_id, packet_data)
+            }
+            None => false,
+        }
+    }
+
     pub fn chains_map(&self) -> BTreeMap<&ChainId, &ChainConfig> {
         self.chains.iter().map(|c| (&c.id, c)).collect()
     }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ModeConfig {
     pub clients: Clients,
@@ -399,12 +629,16 @@ impl Default for ModeConfig {
                 clear_interval: default::clear_packets_interval(),
                 clear_on_start: true,
                 tx_confirmation: true,
+                retry_initial_delay: default::retry_initial_delay(),
+                retry_backoff_factor: default::retry_backoff_factor(),
+                retry_max_delay: default::retry_max_delay(),
+                retry_max_count: default::retry_max_count(),
             },
         }
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Clients {
     pub enabled: bool,
@@ -414,19 +648,19 @@ pub struct Clients {
     pub misbehaviour: bool,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Connections {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Channels {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Packets {
     pub enabled: bool,
@@ -436,6 +670,19 @@ pub struct Packets {
     pub clear_on_start: bool,
     #[serde(default = "default::tx_confirmation")]
     pub tx_confirmation: bool,
+    /// Initial delay before the first retry of a failed packet command.
+    #[serde(default = "default::retry_initial_delay", with = "humantime_serde")]
+    pub retry_initial_delay: Duration,
+    /// Factor by which the retry delay grows after each failed attempt.
+    #[serde(default = "default::retry_backoff_factor")]
+    pub retry_backoff_factor: u32,
+    /// Upper bound on the backoff delay, regardless of the backoff factor. The random jitter is
+    /// added on top of it, so a retry can wait up to 1.5 times this value.
+    #[serde(default = "default::retry_max_delay", with = "humantime_serde")]
+    pub retry_max_delay: Duration,
+    /// Number of retries attempted before giving up and aborting the worker.
+    #[serde(default = "default::retry_max_count")]
+    pub retry_max_count: u64,
 }
 
 impl Default for Packets {
@@ -445,6 +692,10 @@ impl Default for Packets {
             clear_interval: default::clear_packets_interval(),
             clear_on_start: false,
             tx_confirmation: default::tx_confirmation(),
+            retry_initial_delay: default::retry_initial_delay(),
+            retry_backoff_factor: default::retry_backoff_factor(),
+            retry_max_delay: default::retry_max_delay(),
+            retry_max_count: default::retry_max_count(),
         }
     }
 }
@@ -608,43 +859,199 @@ pub struct ChainConfig {
     pub packet_filter: PacketFilter,
     #[serde(default)]
     pub address_type: AddressType,
+    #[serde(default)]
+    pub min_wallet_balance: Option<MinimumBalance>,
 }
 
-/// Attempt to load and parse the TOML config file as a `Config`.
+/// The on-disk serialization format of a config file. [`load`]/[`store`] detect this from the
+/// file's extension; [`load_with_format`]/[`store_with_format`] take it explicitly for callers
+/// reading from or writing to a stream (e.g. stdin, a socket) that has no extension to detect.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Yaml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// Detects the format from `path`'s extension, falling back to [`ConfigFormat::Toml`] -- the
+    /// only format the relayer supported before this enum existed -- for a missing or
+    /// unrecognized extension.
+    pub fn from_path(path: impl AsRef<Path>) -> ConfigFormat {
+        match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => ConfigFormat::Yaml,
+            Some("json") => ConfigFormat::Json,
+            _ => ConfigFormat::Toml,
+        }
+    }
+}
+
+/// Attempt to load and parse the config file at `path` as a `Config`, detecting its
+/// [`ConfigFormat`] from the file extension. See [`load_with_format`] for the parsing details.
 pub fn load(path: impl AsRef<Path>) -> Result<Config, Error> {
-    let config_toml = std::fs::read_to_string(&path).map_err(Error::io)?;
+    let format = ConfigFormat::from_path(&path);
+    let content = std::fs::read_to_string(&path).map_err(Error::io)?;
+
+    load_with_format(&content, format)
+}
+
+/// Parses `content` as a `Config` in the given `format`.
+///
+/// For [`ConfigFormat::Toml`], the content is first parsed as a generic [`toml::Value`] so its
+/// `version` can be read without going through `Config`'s `deny_unknown_fields` deserialization,
+/// which would reject a config whose shape has since changed. A config with no `version` field
+/// predates schema versioning entirely and is treated as version `0`. Each applicable migration
+/// in [`MIGRATIONS`] is then applied in sequence until the value is at
+/// [`CURRENT_CONFIG_VERSION`], at which point it's deserialized into `Config` for real. A file
+/// whose version is *newer* than this binary supports can't be migrated backward, so that case is
+/// reported distinctly from a decode error. YAML and JSON configs postdate schema versioning, so
+/// they're deserialized directly with no migration step.
+pub fn load_with_format(content: &str, format: ConfigFormat) -> Result<Config, Error> {
+    match format {
+        ConfigFormat::Toml => {
+            let mut value = toml::from_str::<toml::Value>(content).map_err(Error::decode)?;
+
+            let mut version = value
+                .get("version")
+                .and_then(toml::Value::as_integer)
+                .map_or(0, |v| v as u32);
+
+            if version > CURRENT_CONFIG_VERSION {
+                return Err(Error::unsupported_version(version, CURRENT_CONFIG_VERSION));
+            }
 
-    let config = toml::from_str::<Config>(&config_toml[..]).map_err(Error::decode)?;
+            while version < CURRENT_CONFIG_VERSION {
+                let migration = MIGRATIONS
+                    .iter()
+                    .find_map(|(from, migrate)| (*from == version).then_some(migrate))
+                    .unwrap_or_else(|| {
+                        panic!("no migration registered from config version {version}")
+                    });
 
-    Ok(config)
+                value = migration(value);
+                version += 1;
+            }
+
+            value.try_into::<Config>().map_err(Error::decode)
+        }
+        ConfigFormat::Yaml => serde_yaml::from_str(content).map_err(Error::decode_yaml),
+        ConfigFormat::Json => serde_json::from_str(content).map_err(Error::decode_json),
+    }
 }
 
-/// Serialize the given `Config` as TOML to the given config file.
+/// Serialize the given `Config` to the given config file, detecting its [`ConfigFormat`] from the
+/// file extension, and always writing the current schema [`CURRENT_CONFIG_VERSION`] regardless of
+/// what `config.version` happens to hold.
 pub fn store(config: &Config, path: impl AsRef<Path>) -> Result<(), Error> {
+    let format = ConfigFormat::from_path(&path);
+
     let mut file = if path.as_ref().exists() {
-        fs::OpenOptions::new().write(true).truncate(true).open(path)
+        fs::OpenOptions::new().write(true).truncate(true).open(&path)
     } else {
-        File::create(path)
+        File::create(&path)
     }
     .map_err(Error::io)?;
 
-    store_writer(config, &mut file)
+    store_with_format(config, format, &mut file)
 }
 
-/// Serialize the given `Config` as TOML to the given writer.
-pub(crate) fn store_writer(config: &Config, mut writer: impl Write) -> Result<(), Error> {
-    let toml_config = toml::to_string_pretty(&config).map_err(Error::encode)?;
+/// Serialize the given `Config` to the given writer in the given `format`, always writing the
+/// current schema [`CURRENT_CONFIG_VERSION`] regardless of what `config.version` happens to hold.
+pub fn store_with_format(
+    config: &Config,
+    format: ConfigFormat,
+    mut writer: impl Write,
+) -> Result<(), Error> {
+    let config = Config {
+        version: CURRENT_CONFIG_VERSION,
+        ..config.clone()
+    };
+
+    let serialized = match format {
+        ConfigFormat::Toml => toml::to_string_pretty(&config).map_err(Error::encode)?,
+        ConfigFormat::Yaml => serde_yaml::to_string(&config).map_err(Error::encode_yaml)?,
+        ConfigFormat::Json => {
+            serde_json::to_string_pretty(&config).map_err(Error::encode_json)?
+        }
+    };
 
-    writeln!(writer, "{}", toml_config).map_err(Error::io)?;
+    writeln!(writer, "{}", serialized).map_err(Error::io)?;
 
     Ok(())
 }
 
+/// Serialize the given `Config` as TOML to the given writer. Kept for callers that only ever
+/// wrote TOML before [`ConfigFormat`] existed; prefer [`store_with_format`] for new code.
+pub(crate) fn store_writer(config: &Config, writer: impl Write) -> Result<(), Error> {
+    store_with_format(config, ConfigFormat::Toml, writer)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{load, store_writer, ChannelFilters, FilterPattern, PacketFilter};
+    use super::{load_with_format, store_with_format, Config, ConfigFormat};
+    use super::CURRENT_CONFIG_VERSION;
     use test_log::test;
 
+    fn load_toml_str(name: &str, toml_content: &str) -> Result<super::Config, super::Error> {
+        let path = std::env::temp_dir().join(format!("hermes-config-test-{name}.toml"));
+        std::fs::write(&path, toml_content).expect("could not write temp config file");
+
+        let result = load(&path);
+        let _ = std::fs::remove_file(&path);
+        result
+    }
+
+    #[test]
+    fn legacy_config_without_version_migrates_to_current() {
+        let config = load_toml_str("legacy", "").expect("could not parse legacy config");
+        assert_eq!(config.version, CURRENT_CONFIG_VERSION);
+    }
+
+    #[test]
+    fn config_version_newer_than_supported_is_rejected() {
+        let err = load_toml_str("future", "version = 999\n")
+            .expect_err("expected an unsupported version error");
+        assert!(err.to_string().contains("999"));
+    }
+
+    #[test]
+    fn config_round_trips_through_every_format() {
+        for format in [ConfigFormat::Toml, ConfigFormat::Yaml, ConfigFormat::Json] {
+            let config = Config::default();
+
+            let mut buffer = Vec::new();
+            store_with_format(&config, format, &mut buffer)
+                .unwrap_or_else(|e| panic!("could not store as {:?}: {}", format, e));
+
+            let content = String::from_utf8(buffer).expect("stored config was not valid UTF-8");
+
+            let round_tripped = load_with_format(&content, format)
+                .unwrap_or_else(|e| panic!("could not load back as {:?}: {}", format, e));
+
+            assert_eq!(round_tripped.version, CURRENT_CONFIG_VERSION);
+            assert_eq!(round_tripped.mode, config.mode);
+        }
+    }
+
+    #[test]
+    fn format_is_detected_from_extension() {
+        assert_eq!(
+            ConfigFormat::from_path("config.yaml"),
+            ConfigFormat::Yaml
+        );
+        assert_eq!(ConfigFormat::from_path("config.yml"), ConfigFormat::Yaml);
+        assert_eq!(
+            ConfigFormat::from_path("config.json"),
+            ConfigFormat::Json
+        );
+        assert_eq!(
+            ConfigFormat::from_path("config.toml"),
+            ConfigFormat::Toml
+        );
+        assert_eq!(ConfigFormat::from_path("config"), ConfigFormat::Toml);
+    }
+
     #[test]
     fn parse_valid_config() {
         let path = concat!(
@@ -690,17 +1097,20 @@ mod tests {
     fn serialize_packet_filter_policy() {
         use std::str::FromStr;
 
+        use super::ChannelFilterEntry;
         use ibc::core::ics24_host::identifier::{ChannelId, PortId};
 
         let filter_policy = ChannelFilters(vec![
-            (
-                FilterPattern::Exact(PortId::from_str("transfer").unwrap()),
-                FilterPattern::Exact(ChannelId::from_str("channel-0").unwrap()),
-            ),
-            (
-                FilterPattern::Wildcard("ica*".parse().unwrap()),
-                FilterPattern::Wildcard("*".parse().unwrap()),
-            ),
+            ChannelFilterEntry {
+                port: FilterPattern::Exact(PortId::from_str("transfer").unwrap()),
+                channel: FilterPattern::Exact(ChannelId::from_str("channel-0").unwrap()),
+                content: None,
+            },
+            ChannelFilterEntry {
+                port: FilterPattern::Wildcard("ica*".parse().unwrap()),
+                channel: FilterPattern::Wildcard("*".parse().unwrap()),
+                content: None,
+            },
         ]);
 
         let fp = PacketFilter::Allow(filter_policy);
@@ -708,4 +1118,35 @@ mod tests {
 
         println!("{}", toml_str);
     }
+
+    #[test]
+    fn deserialize_packet_filter_policy_with_content() {
+        use super::PacketFilterContent;
+
+        let toml_content = r#"
+            policy = 'allow'
+            list = [
+              ['transfer', 'channel-0', { denom = 'uatom', min_amount = 1000 }],
+              ['transfer', 'channel-1'],
+            ]
+            "#;
+
+        let filter_policy: PacketFilter =
+            toml::from_str(toml_content).expect("could not parse filter policy");
+
+        let channels = match filter_policy {
+            PacketFilter::Allow(channels) => channels,
+            _ => panic!("expected an allow policy"),
+        };
+
+        assert_eq!(
+            channels.0[0].content,
+            Some(PacketFilterContent {
+                denom: "uatom".parse().unwrap(),
+                min_amount: Some(1000),
+                max_amount: None,
+            })
+        );
+        assert_eq!(channels.0[1].content, None);
+    }
 }
@@ -0,0 +1,50 @@
+use flex_error::{define_error, TraceError};
+
+define_error! {
+    Error {
+        Io
+            [TraceError<std::io::Error>]
+            |_| { "I/O error" },
+
+        Decode
+            [TraceError<toml::de::Error>]
+            |_| { "invalid configuration" },
+
+        Encode
+            [TraceError<toml::ser::Error>]
+            |_| { "invalid configuration" },
+
+        DecodeYaml
+            [TraceError<serde_yaml::Error>]
+            |_| { "invalid configuration" },
+
+        EncodeYaml
+            [TraceError<serde_yaml::Error>]
+            |_| { "invalid configuration" },
+
+        DecodeJson
+            [TraceError<serde_json::Error>]
+            |_| { "invalid configuration" },
+
+        EncodeJson
+            [TraceError<serde_json::Error>]
+            |_| { "invalid configuration" },
+
+        Watch
+            [TraceError<notify::Error>]
+            |_| { "failed to watch the configuration file for changes" },
+
+        Wizard
+            { reason: String }
+            |e| { format!("config wizard failed: {}", e.reason) },
+
+        UnsupportedVersion
+            { found: u32, supported: u32 }
+            |e| {
+                format!(
+                    "config file has schema version {} but this binary only supports up to version {}; upgrade the relayer binary",
+                    e.found, e.supported,
+                )
+            },
+    }
+}
@@ -0,0 +1,204 @@
+//! Filesystem-watcher-driven hot reload of the relayer config.
+//!
+//! [`watch`] observes the config file for changes and, on each one, loads the new [`Config`] and
+//! diffs it against the one currently live in a [`SharedConfig`] instead of swapping it wholesale.
+//! The diff is reduced to a minimal set of [`ConfigUpdate`]s describing exactly which subsystems
+//! must react, so the supervisor can restart only what a given edit actually touches -- e.g. only
+//! respawning packet workers when a chain's `packet_filter` changed, rather than tearing down
+//! every chain runtime on every edit.
+
+use std::path::Path;
+use std::sync::mpsc::{channel, Receiver};
+
+use ibc::core::ics24_host::identifier::ChainId;
+use notify::{RecursiveMode, Watcher};
+
+use super::{load, ChainConfig, Config, SharedConfig};
+
+/// A minimal, classified description of what changed between two successive loads of the config
+/// file, as computed by [`diff`]. Each variant names the subsystem the change affects; nothing in
+/// the relayer consumes these updates or restarts a subsystem in response to them.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigUpdate {
+    /// A chain was added; its runtime should be spawned.
+    ChainAdded(ChainId),
+    /// A chain was removed; its runtime should be shut down.
+    ChainRemoved(ChainId),
+    /// An existing chain's `packet_filter` changed; its packet workers should be respawned.
+    PacketFilterChanged(ChainId),
+    /// An existing chain's `gas_price` or `max_gas` changed; new tx building should pick up the
+    /// updated values, but nothing needs to restart.
+    GasSettingsChanged(ChainId),
+    /// The global `mode` flags changed; the affected class of worker (clients, connections,
+    /// channels, or packets) should be (re)spawned or torn down relayer-wide.
+    ModeChanged,
+}
+
+/// Diffs `new` against `old`, classifying each chain in [`Config::chains_map`] as added, removed,
+/// or modified, and breaking a modification down into the specific [`ConfigUpdate`]s it implies.
+/// A chain present in both with no relevant field changed yields no update for it.
+pub fn diff(old: &Config, new: &Config) -> Vec<ConfigUpdate> {
+    let old_chains = old.chains_map();
+    let new_chains = new.chains_map();
+
+    let mut updates = Vec::new();
+
+    for (&id, &new_chain) in &new_chains {
+        match old_chains.get(id) {
+            None => updates.push(ConfigUpdate::ChainAdded(id.clone())),
+            Some(&old_chain) => updates.extend(diff_chain(old_chain, new_chain)),
+        }
+    }
+
+    for &id in old_chains.keys() {
+        if !new_chains.contains_key(id) {
+            updates.push(ConfigUpdate::ChainRemoved(id.clone()));
+        }
+    }
+
+    if old.mode != new.mode {
+        updates.push(ConfigUpdate::ModeChanged);
+    }
+
+    updates
+}
+
+/// Breaks down what changed between two [`ChainConfig`]s for the same [`ChainId`] into the
+/// specific [`ConfigUpdate`]s it implies. `old` and `new` are assumed to share a `ChainId`.
+fn diff_chain(old: &ChainConfig, new: &ChainConfig) -> Vec<ConfigUpdate> {
+    let mut updates = Vec::new();
+
+    if old.packet_filter != new.packet_filter {
+        updates.push(ConfigUpdate::PacketFilterChanged(new.id.clone()));
+    }
+
+    if old.gas_price != new.gas_price || old.max_gas != new.max_gas {
+        updates.push(ConfigUpdate::GasSettingsChanged(new.id.clone()));
+    }
+
+    updates
+}
+
+/// Watches `path` for changes and, on each one, loads the new config, diffs it against whatever
+/// is currently live in `shared_config`, swaps `shared_config` in place, and sends every
+/// [`ConfigUpdate`] the diff produced down the returned channel. Reads that fail to parse (e.g. a
+/// transient, partially-written file) are logged and skipped rather than propagated, so a single
+/// bad write doesn't tear down the watcher.
+pub fn watch(
+    path: impl AsRef<Path>,
+    shared_config: SharedConfig,
+) -> Result<Receiver<ConfigUpdate>, super::Error> {
+    let (update_tx, update_rx) = channel();
+    let (event_tx, event_rx) = channel();
+
+    let mut watcher = notify::recommended_watcher(event_tx).map_err(super::Error::watch)?;
+
+    watcher
+        .watch(path.as_ref(), RecursiveMode::NonRecursive)
+        .map_err(super::Error::watch)?;
+
+    let path = path.as_ref().to_path_buf();
+
+    std::thread::spawn(move || {
+        // Keep the watcher alive for the lifetime of the thread; it stops delivering events
+        // (and `event_rx` closes) once dropped.
+        let _watcher = watcher;
+
+        for event in event_rx {
+            if event.is_err() {
+                continue;
+            }
+
+            let new_config = match load(&path) {
+                Ok(config) => config,
+                Err(e) => {
+                    tracing::warn!("failed to reload config from {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+
+            let updates = {
+                let mut config = shared_config.write().expect("poisoned config lock");
+                let updates = diff(&config, &new_config);
+                *config = new_config;
+                updates
+            };
+
+            for update in updates {
+                if update_tx.send(update).is_err() {
+                    // No one is listening anymore; stop watching.
+                    return;
+                }
+            }
+        }
+    });
+
+    Ok(update_rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Config;
+    use super::{diff, ConfigUpdate};
+
+    fn config_with_chain(id: &str) -> Config {
+        let toml_str = format!(
+            r#"
+            [[chains]]
+            id = '{id}'
+            rpc_addr = 'http://localhost:26657'
+            websocket_addr = 'ws://localhost:26657/websocket'
+            grpc_addr = 'http://localhost:9090'
+            account_prefix = 'cosmos'
+            key_name = 'testkey'
+            store_prefix = 'ibc'
+
+            [chains.gas_price]
+            price = 0.025
+            denom = 'stake'
+            "#
+        );
+
+        toml::from_str(&toml_str).expect("could not parse minimal chain config")
+    }
+
+    #[test]
+    fn diff_detects_added_and_removed_chains() {
+        let old = config_with_chain("chain-a");
+        let new = config_with_chain("chain-b");
+
+        let updates = diff(&old, &new);
+        assert_eq!(updates.len(), 2);
+        assert!(updates.contains(&ConfigUpdate::ChainAdded("chain-b".parse().unwrap())));
+        assert!(updates.contains(&ConfigUpdate::ChainRemoved("chain-a".parse().unwrap())));
+    }
+
+    #[test]
+    fn diff_detects_gas_changes() {
+        let old = config_with_chain("chain-a");
+        let mut new = old.clone();
+        new.chains[0].max_gas = Some(500_000);
+
+        let updates = diff(&old, &new);
+        assert_eq!(
+            updates,
+            vec![ConfigUpdate::GasSettingsChanged("chain-a".parse().unwrap())]
+        );
+    }
+
+    #[test]
+    fn diff_detects_mode_changes() {
+        let old = Config::default();
+        let mut new = old.clone();
+        new.mode.clients.enabled = !new.mode.clients.enabled;
+
+        let updates = diff(&old, &new);
+        assert_eq!(updates, vec![ConfigUpdate::ModeChanged]);
+    }
+
+    #[test]
+    fn diff_yields_nothing_for_identical_configs() {
+        let config = config_with_chain("chain-a");
+        assert!(diff(&config, &config).is_empty());
+    }
+}
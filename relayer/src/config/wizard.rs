@@ -0,0 +1,132 @@
+//! An interactive wizard for building a [`ChainConfig`] without hand-writing every field.
+//!
+//! Hand-writing a [`ChainConfig`] block requires knowing `account_prefix`, `store_prefix`,
+//! `gas_price`, and a `trusting_period` safely below the chain's unbonding period up front, which
+//! is error prone. [`ChainConfig::from_wizard`] instead queries the chain itself for its
+//! [`ChainId`] and staking parameters, and asks a [`Prompter`] for everything else,
+//! `account_prefix` included: the bech32 prefix is not detected. Each prompt is pre-filled with a
+//! fixed default (e.g. `cosmos` for the account prefix); only the fields that are not prompted for
+//! take their values from the [`super::default`] module.
+
+use core::time::Duration;
+
+use ibc::core::ics24_host::identifier::ChainId;
+use ibc_proto::cosmos::staking::v1beta1::{query_client::QueryClient, QueryParamsRequest};
+use tendermint_rpc::{Client, HttpClient};
+
+use super::{default, AddressType, ChainConfig, Error, GasPrice, MaxMsgNum, MaxTxSize, Memo};
+use crate::keyring::Store;
+
+/// Drives the interactive part of [`ChainConfig::from_wizard`]: every field that can't be
+/// autodetected from the chain is asked for through this trait instead of being hard-coded,
+/// so the CLI can back it with a real terminal prompt while tests back it with canned answers.
+pub trait Prompter {
+    /// Prompts for `field`, showing `default` as the value accepted on an empty answer, and
+    /// returns whatever the user entered (or `default`, unchanged, if they accepted it).
+    fn prompt(&self, field: &str, default: &str) -> Result<String, Error>;
+}
+
+impl ChainConfig {
+    /// Builds a [`ChainConfig`] for the chain served at `rpc_addr`/`grpc_addr`: queries the node
+    /// for its [`ChainId`] and staking parameters, derives a `trusting_period` at
+    /// [`default::trusting_period_fraction`] of the chain's unbonding period, and prompts
+    /// `prompter` for the remaining fields. Doesn't touch disk or `websocket_addr`; append the
+    /// result to a [`super::Config`]'s `chains` and call [`super::store`] to persist it.
+    pub fn from_wizard(
+        rpc_addr: tendermint_rpc::Url,
+        websocket_addr: tendermint_rpc::Url,
+        grpc_addr: tendermint_rpc::Url,
+        prompter: &dyn Prompter,
+    ) -> Result<ChainConfig, Error> {
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| Error::wizard(format!("could not start async runtime: {e}")))?;
+
+        let (id, unbonding_period) = rt.block_on(detect_chain_params(&rpc_addr, &grpc_addr))?;
+
+        let trusting_period = Duration::from_secs(
+            (unbonding_period.as_secs() as f64 * default::trusting_period_fraction()) as u64,
+        );
+
+        let account_prefix = prompter.prompt("account_prefix", "cosmos")?;
+        let key_name = prompter.prompt("key_name", "wallet")?;
+        let store_prefix = prompter.prompt("store_prefix", "ibc")?;
+        let max_gas = prompter.prompt("max_gas", "400000")?;
+        let gas_adjustment = prompter.prompt("gas_adjustment", "0.1")?;
+        let gas_price_price = prompter.prompt("gas_price.price", "0.025")?;
+        let gas_price_denom = prompter.prompt("gas_price.denom", "stake")?;
+
+        Ok(ChainConfig {
+            id,
+            rpc_addr,
+            websocket_addr,
+            grpc_addr,
+            rpc_timeout: default::rpc_timeout(),
+            account_prefix,
+            key_name,
+            key_store_type: Store::default(),
+            store_prefix,
+            default_gas: None,
+            max_gas: parse_field("max_gas", &max_gas)?,
+            gas_adjustment: parse_field("gas_adjustment", &gas_adjustment)?,
+            fee_granter: None,
+            max_msg_num: MaxMsgNum::default(),
+            max_tx_size: MaxTxSize::default(),
+            clock_drift: default::clock_drift(),
+            max_block_time: default::max_block_time(),
+            trusting_period: Some(trusting_period),
+            memo_prefix: Memo::default(),
+            proof_specs: Default::default(),
+            trust_threshold: Default::default(),
+            gas_price: GasPrice::new(
+                parse_field("gas_price.price", &gas_price_price)?,
+                gas_price_denom,
+            ),
+            packet_filter: Default::default(),
+            address_type: AddressType::default(),
+            min_wallet_balance: None,
+        })
+    }
+}
+
+/// Queries `rpc_addr` for the chain's [`ChainId`] and `grpc_addr` for its staking parameters,
+/// returning the chain id and its configured unbonding period.
+async fn detect_chain_params(
+    rpc_addr: &tendermint_rpc::Url,
+    grpc_addr: &tendermint_rpc::Url,
+) -> Result<(ChainId, Duration), Error> {
+    let rpc_client = HttpClient::new(rpc_addr.clone())
+        .map_err(|e| Error::wizard(format!("could not connect to {rpc_addr}: {e}")))?;
+
+    let status = rpc_client
+        .status()
+        .await
+        .map_err(|e| Error::wizard(format!("could not query status from {rpc_addr}: {e}")))?;
+
+    let id = ChainId::from_string(status.node_info.network.as_str());
+
+    let mut staking_client = QueryClient::connect(grpc_addr.to_string())
+        .await
+        .map_err(|e| Error::wizard(format!("could not connect to {grpc_addr}: {e}")))?;
+
+    let params = staking_client
+        .params(QueryParamsRequest {})
+        .await
+        .map_err(|e| Error::wizard(format!("could not query staking params: {e}")))?
+        .into_inner()
+        .params
+        .ok_or_else(|| Error::wizard("staking module returned no params".to_string()))?;
+
+    let unbonding_time = params
+        .unbonding_time
+        .ok_or_else(|| Error::wizard("staking params had no unbonding_time".to_string()))?;
+
+    let unbonding_period = Duration::from_secs(unbonding_time.seconds.max(0) as u64);
+
+    Ok((id, unbonding_period))
+}
+
+fn parse_field<T: core::str::FromStr>(field: &str, value: &str) -> Result<T, Error> {
+    value
+        .parse()
+        .map_err(|_| Error::wizard(format!("could not parse `{field}` from {value:?}")))
+}
@@ -4,6 +4,7 @@ use core::convert::TryFrom;
 use tendermint_rpc::{event::Event as RpcEvent, event::EventData as RpcEventData};
 
 use ibc::core::ics02_client::{events as ClientEvents, height::Height};
+use ibc::core::ics03_connection::events as ConnectionEvents;
 use ibc::core::ics04_channel::events as ChannelEvents;
 use ibc::core::ics24_host::identifier::ChainId;
 use ibc::events::IbcEvent;
@@ -112,7 +113,14 @@ use super::IbcEventWithHeight;
 /// ```
 /// {Begin,End}Block events however do not have any such `message.action` associated with them, so
 /// this doesn't work. For this reason, we extract block events in the following order ->
-/// OpenInit -> OpenTry -> OpenAck -> OpenConfirm -> SendPacket -> CloseInit -> CloseConfirm.
+/// CreateClient -> UpdateClient -> ClientMisbehaviour -> connection OpenInit -> OpenTry -> OpenAck
+/// -> OpenConfirm -> channel OpenInit -> OpenTry -> OpenAck -> OpenConfirm -> SendPacket ->
+/// WriteAcknowledgement -> AcknowledgePacket -> TimeoutPacket -> TimeoutOnClose -> CloseInit ->
+/// CloseConfirm.
+///
+/// This ordering heuristic is only used as a fallback for Tendermint nodes old enough not to
+/// report `result_begin_block`/`result_end_block` on the `NewBlock` event; see
+/// [`get_all_events`].
 pub fn get_all_events(
     chain_id: &ChainId,
     result: RpcEvent,
@@ -126,7 +134,11 @@ pub fn get_all_events(
     let events = events.ok_or("missing events")?;
 
     match data {
-        RpcEventData::NewBlock { block, .. } if query == queries::new_block().to_string() => {
+        RpcEventData::NewBlock {
+            block,
+            result_begin_block,
+            result_end_block,
+        } if query == queries::new_block().to_string() => {
             let height = Height::new(
                 ChainId::chain_version(chain_id.to_string().as_str()),
                 u64::from(block.as_ref().ok_or("tx.height")?.header.height),
@@ -137,7 +149,41 @@ pub fn get_all_events(
                 ClientEvents::NewBlock::new(height).into(),
                 height,
             ));
-            events_with_height.append(&mut extract_block_events(height, &events));
+
+            let begin_block_events = result_begin_block
+                .as_ref()
+                .map(|result| result.events.as_slice())
+                .unwrap_or(&[]);
+            let end_block_events = result_end_block
+                .as_ref()
+                .map(|result| result.events.as_slice())
+                .unwrap_or(&[]);
+
+            if begin_block_events.is_empty() && end_block_events.is_empty() {
+                // Older Tendermint versions don't populate `result_{begin,end}_block`; fall back
+                // to the flattened `events` map, which can no longer tell begin-block and
+                // end-block events apart and so has to guess at their relative order.
+                events_with_height.append(&mut extract_block_events(height, &events));
+            } else {
+                // Begin-block events logically precede every tx in the block, and end-block
+                // events follow them; walking the two slices in order preserves that relation
+                // without having to hard-code the shape of any particular event sequence.
+                for abci_event in begin_block_events.iter().chain(end_block_events.iter()) {
+                    if let Some(client_event) = events::client::try_from_tx(abci_event) {
+                        tracing::trace!("extracted ibc_client event {}", client_event);
+                        events_with_height
+                            .push(IbcEventWithHeight::new(client_event.event, height));
+                    }
+                    if let Some(conn_event) = events::connection::try_from_tx(abci_event) {
+                        tracing::trace!("extracted ibc_connection event {}", conn_event);
+                        events_with_height.push(IbcEventWithHeight::new(conn_event.event, height));
+                    }
+                    if let Some(chan_event) = events::channel::try_from_tx(abci_event) {
+                        tracing::trace!("extracted ibc_channel event {}", chan_event);
+                        events_with_height.push(IbcEventWithHeight::new(chan_event.event, height));
+                    }
+                }
+            }
         }
         RpcEventData::Tx { tx_result } => {
             let height = Height::new(
@@ -221,6 +267,41 @@ fn extract_block_events(
     }
 
     let mut events: Vec<IbcEventWithHeight> = vec![];
+    append_events::<ClientEvents::CreateClient>(
+        &mut events,
+        extract_events(height, block_events, "create_client", "client_id"),
+        height,
+    );
+    append_events::<ClientEvents::UpdateClient>(
+        &mut events,
+        extract_events(height, block_events, "update_client", "client_id"),
+        height,
+    );
+    append_events::<ClientEvents::ClientMisbehaviour>(
+        &mut events,
+        extract_events(height, block_events, "client_misbehaviour", "client_id"),
+        height,
+    );
+    append_events::<ConnectionEvents::OpenInit>(
+        &mut events,
+        extract_events(height, block_events, "connection_open_init", "connection_id"),
+        height,
+    );
+    append_events::<ConnectionEvents::OpenTry>(
+        &mut events,
+        extract_events(height, block_events, "connection_open_try", "connection_id"),
+        height,
+    );
+    append_events::<ConnectionEvents::OpenAck>(
+        &mut events,
+        extract_events(height, block_events, "connection_open_ack", "connection_id"),
+        height,
+    );
+    append_events::<ConnectionEvents::OpenConfirm>(
+        &mut events,
+        extract_events(height, block_events, "connection_open_confirm", "connection_id"),
+        height,
+    );
     append_events::<ChannelEvents::OpenInit>(
         &mut events,
         extract_events(height, block_events, "channel_open_init", "channel_id"),
@@ -246,6 +327,34 @@ fn extract_block_events(
         extract_events(height, block_events, "send_packet", "packet_data"),
         height,
     );
+    append_events::<ChannelEvents::WriteAcknowledgement>(
+        &mut events,
+        extract_events(
+            height,
+            block_events,
+            "write_acknowledgement",
+            "packet_sequence",
+        ),
+        height,
+    );
+    append_events::<ChannelEvents::AcknowledgePacket>(
+        &mut events,
+        extract_events(height, block_events, "acknowledge_packet", "packet_sequence"),
+        height,
+    );
+    // `TimeoutPacket` and `TimeoutOnClose` share the same `timeout_packet` ABCI event type; each
+    // `TryFrom<RawObject>` impl decides for itself whether the event represents an ordinary
+    // timeout or one triggered by the counterparty channel having closed.
+    append_events::<ChannelEvents::TimeoutPacket>(
+        &mut events,
+        extract_events(height, block_events, "timeout_packet", "packet_sequence"),
+        height,
+    );
+    append_events::<ChannelEvents::TimeoutOnClose>(
+        &mut events,
+        extract_events(height, block_events, "timeout_packet", "packet_sequence"),
+        height,
+    );
     append_events::<ChannelEvents::CloseInit>(
         &mut events,
         extract_events(height, block_events, "channel_close_init", "channel_id"),
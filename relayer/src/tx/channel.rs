@@ -1,17 +1,24 @@
 use prost_types::Any;
 
+use ibc_proto::ibc::core::channel::v1::MsgChannelCloseConfirm as RawMsgChannelCloseConfirm;
+use ibc_proto::ibc::core::channel::v1::MsgChannelCloseInit as RawMsgChannelCloseInit;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenAck as RawMsgChannelOpenAck;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenConfirm as RawMsgChannelOpenConfirm;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenInit as RawMsgChannelOpenInit;
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenTry as RawMsgChannelOpenTry;
 use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
 
+use ibc::events::IbcEvent;
 use ibc::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use ibc::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
 use ibc::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
 use ibc::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
 use ibc::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
 use ibc::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
 use ibc::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::ics26_routing::context::Router;
+use ibc::ics26_routing::handler::lookup_module_by_port;
 use ibc::tx_msg::Msg;
 use ibc::Height as ICSHeight;
 
@@ -41,6 +48,29 @@ pub struct ChannelOpenInitOptions {
     pub ordering: Order,
 }
 
+/// Picks the single channel handshake event out of the full event log a transaction produced
+/// (which also carries, e.g., the `UpdateClient` event from a prepended client update message),
+/// so that callers can drive the next handshake step from it without re-querying chain state.
+fn extract_channel_event(
+    events: Vec<IbcEvent>,
+    err: impl Fn(String) -> Kind,
+) -> Result<IbcEvent, Error> {
+    events
+        .into_iter()
+        .find(|event| {
+            matches!(
+                event,
+                IbcEvent::OpenInitChannel(_)
+                    | IbcEvent::OpenTryChannel(_)
+                    | IbcEvent::OpenAckChannel(_)
+                    | IbcEvent::OpenConfirmChannel(_)
+                    | IbcEvent::CloseInitChannel(_)
+                    | IbcEvent::CloseConfirmChannel(_)
+            )
+        })
+        .ok_or_else(|| err("missing channel event".to_string()).into())
+}
+
 pub fn build_chan_init(
     dest_chain: &mut CosmosSDKChain,
     src_chain: &CosmosSDKChain,
@@ -88,7 +118,7 @@ pub fn build_chan_init(
     Ok(vec![new_msg.to_any::<RawMsgChannelOpenInit>()])
 }
 
-pub fn build_chan_init_and_send(opts: &ChannelOpenInitOptions) -> Result<String, Error> {
+pub fn build_chan_init_and_send(opts: &ChannelOpenInitOptions) -> Result<IbcEvent, Error> {
     // Get the source and destination chains.
     let src_chain = &CosmosSDKChain::from_config(opts.clone().src_chain_config)?;
     let dest_chain = &mut CosmosSDKChain::from_config(opts.clone().dest_chain_config)?;
@@ -99,7 +129,11 @@ pub fn build_chan_init_and_send(opts: &ChannelOpenInitOptions) -> Result<String,
         .get_key()
         .map_err(|e| Kind::KeyBase.context(e))?;
 
-    Ok(dest_chain.send(new_msgs, key, "".to_string(), 0)?)
+    let events = dest_chain.send(new_msgs, key, "".to_string(), 0)?;
+
+    extract_channel_event(events, |reason| {
+        Kind::ChanOpenInit(opts.dest_channel_id.clone(), reason)
+    })
 }
 
 #[derive(Clone, Debug)]
@@ -210,6 +244,7 @@ pub fn build_chan_try(
     dest_chain: &mut CosmosSDKChain,
     src_chain: &CosmosSDKChain,
     opts: &ChannelOpenOptions,
+    router: &mut impl Router,
 ) -> Result<Vec<Any>, Error> {
     // Check that the destination chain will accept the message, i.e. it does not have the channel
     let dest_expected_channel =
@@ -255,12 +290,48 @@ pub fn build_chan_try(
     let counterparty =
         Counterparty::new(opts.src_port_id.clone(), Some(opts.src_channel_id.clone()));
 
+    // Let the application module bound to the destination port negotiate the version it is
+    // willing to accept, instead of blindly copying the port-derived default.
+    let proposed_version = src_chain.module_version(&opts.src_port_id);
+
+    let module_id = lookup_module_by_port(router, &opts.dest_port_id).map_err(|e| {
+        Kind::ChanOpenTry(
+            opts.dest_channel_id.clone(),
+            "no module registered for destination port".into(),
+        )
+        .context(e)
+    })?;
+
+    let module = router.get_route_mut(&module_id).ok_or_else(|| {
+        Kind::ChanOpenTry(
+            opts.dest_channel_id.clone(),
+            "module not found for destination port".into(),
+        )
+    })?;
+
+    let version = module
+        .on_chan_open_try(
+            opts.ordering,
+            &[opts.dest_connection_id.clone()],
+            &opts.dest_port_id,
+            &opts.dest_channel_id,
+            &counterparty,
+            &proposed_version,
+        )
+        .map_err(|e| {
+            Kind::ChanOpenTry(
+                opts.dest_channel_id.clone(),
+                "destination module rejected the proposed version".into(),
+            )
+            .context(e)
+        })?;
+
     let channel = ChannelEnd::new(
         State::Init,
         opts.ordering,
         counterparty,
         vec![opts.dest_connection_id.clone()],
-        dest_chain.module_version(&opts.dest_port_id),
+        version,
     );
 
     // Get signer
@@ -274,7 +345,7 @@ pub fn build_chan_try(
         channel_id: opts.dest_channel_id.clone(),
         counterparty_chosen_channel_id: src_channel.counterparty().channel_id,
         channel,
-        counterparty_version: src_chain.module_version(&opts.src_port_id),
+        counterparty_version: proposed_version,
         proofs: src_chain.build_channel_proofs(
             &opts.src_port_id,
             &opts.src_channel_id,
@@ -290,24 +361,32 @@ pub fn build_chan_try(
     Ok(msgs)
 }
 
-pub fn build_chan_try_and_send(opts: &ChannelOpenOptions) -> Result<String, Error> {
+pub fn build_chan_try_and_send(
+    opts: &ChannelOpenOptions,
+    router: &mut impl Router,
+) -> Result<IbcEvent, Error> {
     // Get the source and destination chains.
     let src_chain = &CosmosSDKChain::from_config(opts.clone().src_chain_config)?;
     let dest_chain = &mut CosmosSDKChain::from_config(opts.clone().dest_chain_config)?;
 
-    let new_msgs = build_chan_try(dest_chain, src_chain, opts)?;
+    let new_msgs = build_chan_try(dest_chain, src_chain, opts, router)?;
     let key = dest_chain
         .keybase()
         .get_key()
         .map_err(|e| Kind::KeyBase.context(e))?;
 
-    Ok(dest_chain.send(new_msgs, key, "".to_string(), 0)?)
+    let events = dest_chain.send(new_msgs, key, "".to_string(), 0)?;
+
+    extract_channel_event(events, |reason| {
+        Kind::ChanOpenTry(opts.dest_channel_id.clone(), reason)
+    })
 }
 
 pub fn build_chan_ack(
     dest_chain: &mut CosmosSDKChain,
     src_chain: &CosmosSDKChain,
     opts: &ChannelOpenOptions,
+    router: &mut impl Router,
 ) -> Result<Vec<Any>, Error> {
     // Check that the destination chain will accept the message
     let dest_expected_channel =
@@ -350,6 +429,35 @@ pub fn build_chan_ack(
         ics_target_height,
     )?;
 
+    // Let the application module bound to the destination port validate the version the source
+    // chain's channel has already settled on, instead of blindly reusing the port's default.
+    let counterparty_version = src_channel.version().to_string();
+
+    let module_id = lookup_module_by_port(router, &opts.dest_port_id).map_err(|e| {
+        Kind::ChanOpenAck(
+            opts.dest_channel_id.clone(),
+            "no module registered for destination port".into(),
+        )
+        .context(e)
+    })?;
+
+    let module = router.get_route_mut(&module_id).ok_or_else(|| {
+        Kind::ChanOpenAck(
+            opts.dest_channel_id.clone(),
+            "module not found for destination port".into(),
+        )
+    })?;
+
+    module
+        .on_chan_open_ack(&opts.dest_port_id, &opts.dest_channel_id, &counterparty_version)
+        .map_err(|e| {
+            Kind::ChanOpenAck(
+                opts.dest_channel_id.clone(),
+                "destination module rejected the negotiated version".into(),
+            )
+            .context(e)
+        })?;
+
     // Get signer
     let signer = dest_chain
         .get_signer()
@@ -360,7 +468,7 @@ pub fn build_chan_ack(
         port_id: opts.dest_port_id.clone(),
         channel_id: opts.dest_channel_id.clone(),
         counterparty_channel_id: opts.src_channel_id.clone(),
-        counterparty_version: src_chain.module_version(&opts.dest_port_id),
+        counterparty_version,
         proofs: src_chain.build_channel_proofs(
             &opts.src_port_id,
             &opts.src_channel_id,
@@ -376,18 +484,25 @@ pub fn build_chan_ack(
     Ok(msgs)
 }
 
-pub fn build_chan_ack_and_send(opts: &ChannelOpenOptions) -> Result<String, Error> {
+pub fn build_chan_ack_and_send(
+    opts: &ChannelOpenOptions,
+    router: &mut impl Router,
+) -> Result<IbcEvent, Error> {
     // Get the source and destination chains.
     let src_chain = &CosmosSDKChain::from_config(opts.clone().src_chain_config)?;
     let dest_chain = &mut CosmosSDKChain::from_config(opts.clone().dest_chain_config)?;
 
-    let new_msgs = build_chan_ack(dest_chain, src_chain, opts)?;
+    let new_msgs = build_chan_ack(dest_chain, src_chain, opts, router)?;
     let key = dest_chain
         .keybase()
         .get_key()
         .map_err(|e| Kind::KeyBase.context(e))?;
 
-    Ok(dest_chain.send(new_msgs, key, "".to_string(), 0)?)
+    let events = dest_chain.send(new_msgs, key, "".to_string(), 0)?;
+
+    extract_channel_event(events, |reason| {
+        Kind::ChanOpenAck(opts.dest_channel_id.clone(), reason)
+    })
 }
 
 pub fn build_chan_confirm(
@@ -459,7 +574,7 @@ pub fn build_chan_confirm(
     Ok(msgs)
 }
 
-pub fn build_chan_confirm_and_send(opts: &ChannelOpenOptions) -> Result<String, Error> {
+pub fn build_chan_confirm_and_send(opts: &ChannelOpenOptions) -> Result<IbcEvent, Error> {
     // Get the source and destination chains.
     let src_chain = &CosmosSDKChain::from_config(opts.clone().src_chain_config)?;
     let dest_chain = &mut CosmosSDKChain::from_config(opts.clone().dest_chain_config)?;
@@ -470,5 +585,191 @@ pub fn build_chan_confirm_and_send(opts: &ChannelOpenOptions) -> Result<String,
         .get_key()
         .map_err(|e| Kind::KeyBase.context(e))?;
 
-    Ok(dest_chain.send(new_msgs, key, "".to_string(), 0)?)
+    let events = dest_chain.send(new_msgs, key, "".to_string(), 0)?;
+
+    extract_channel_event(events, |reason| {
+        Kind::ChanOpenConfirm(opts.dest_channel_id.clone(), reason)
+    })
+}
+
+/// Checks that the channel on the source chain is closed and that the channel on the
+/// destination chain is `Open` or `TryOpen`, as required before submitting a
+/// `MsgChannelCloseConfirm` on the destination chain.
+fn validate_channel_close_confirm(
+    channel_id: ChannelId,
+    src_channel: &ChannelEnd,
+    dest_channel: &ChannelEnd,
+) -> Result<(), Error> {
+    if *src_channel.state() != State::Closed {
+        return Err(Kind::ChanCloseConfirm(
+            channel_id,
+            "channel on source chain is not closed".into(),
+        )
+        .into());
+    }
+
+    if *dest_channel.state() != State::Open && *dest_channel.state() != State::TryOpen {
+        return Err(Kind::ChanCloseConfirm(
+            channel_id,
+            "channel on destination chain is not open or try-open".into(),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+pub fn build_chan_close_init(
+    dest_chain: &mut CosmosSDKChain,
+    _src_chain: &CosmosSDKChain,
+    opts: &ChannelOpenOptions,
+) -> Result<Vec<Any>, Error> {
+    // Check that the destination chain will accept the message, i.e. it has the channel in a
+    // state from which it can be closed.
+    let dest_channel = dest_chain
+        .query_channel(
+            &opts.dest_port_id,
+            &opts.dest_channel_id,
+            ICSHeight::default(),
+        )
+        .map_err(|e| {
+            Kind::ChanCloseInit(
+                opts.dest_channel_id.clone(),
+                "channel does not exist on destination".into(),
+            )
+            .context(e)
+        })?;
+
+    if *dest_channel.state() == State::Closed {
+        return Err(Kind::ChanCloseInit(
+            opts.dest_channel_id.clone(),
+            "channel is already closed".into(),
+        )
+        .into());
+    }
+
+    // Get the signer from key seed file
+    let signer = dest_chain
+        .get_signer()
+        .map_err(|e| Kind::KeyBase.context(e))?;
+
+    // Build the domain type message
+    let new_msg = MsgChannelCloseInit {
+        port_id: opts.dest_port_id.clone(),
+        channel_id: opts.dest_channel_id.clone(),
+        signer,
+    };
+
+    Ok(vec![new_msg.to_any::<RawMsgChannelCloseInit>()])
+}
+
+pub fn build_chan_close_init_and_send(opts: &ChannelOpenOptions) -> Result<IbcEvent, Error> {
+    // Get the source and destination chains.
+    let src_chain = &CosmosSDKChain::from_config(opts.clone().src_chain_config)?;
+    let dest_chain = &mut CosmosSDKChain::from_config(opts.clone().dest_chain_config)?;
+
+    let new_msgs = build_chan_close_init(dest_chain, src_chain, opts)?;
+    let key = dest_chain
+        .keybase()
+        .get_key()
+        .map_err(|e| Kind::KeyBase.context(e))?;
+
+    let events = dest_chain.send(new_msgs, key, "".to_string(), 0)?;
+
+    extract_channel_event(events, |reason| {
+        Kind::ChanCloseInit(opts.dest_channel_id.clone(), reason)
+    })
+}
+
+pub fn build_chan_close_confirm(
+    dest_chain: &mut CosmosSDKChain,
+    src_chain: &CosmosSDKChain,
+    opts: &ChannelOpenOptions,
+) -> Result<Vec<Any>, Error> {
+    let src_channel = src_chain
+        .query_channel(
+            &opts.src_port_id,
+            &opts.src_channel_id,
+            ICSHeight::default(),
+        )
+        .map_err(|e| {
+            Kind::ChanCloseConfirm(
+                opts.dest_channel_id.clone(),
+                "channel does not exist on source".into(),
+            )
+            .context(e)
+        })?;
+
+    let dest_channel = dest_chain
+        .query_channel(
+            &opts.dest_port_id,
+            &opts.dest_channel_id,
+            ICSHeight::default(),
+        )
+        .map_err(|e| {
+            Kind::ChanCloseConfirm(
+                opts.dest_channel_id.clone(),
+                "channel does not exist on destination".into(),
+            )
+            .context(e)
+        })?;
+
+    // Check that the source channel is closed and the destination channel can still accept the
+    // confirmation, i.e. it is `Open` or `TryOpen`.
+    validate_channel_close_confirm(opts.dest_channel_id.clone(), &src_channel, &dest_channel)?;
+
+    // Retrieve the connection
+    let dest_connection =
+        dest_chain.query_connection(&opts.dest_connection_id.clone(), ICSHeight::default())?;
+
+    let ics_target_height = src_chain.query_latest_height()?;
+
+    // Build message to update client on destination
+    let mut msgs = build_update_client(
+        dest_chain,
+        src_chain,
+        dest_connection.client_id().clone(),
+        ics_target_height,
+    )?;
+
+    // Get signer
+    let signer = dest_chain
+        .get_signer()
+        .map_err(|e| Kind::KeyBase.context(e))?;
+
+    // Build the domain type message
+    let new_msg = MsgChannelCloseConfirm {
+        port_id: opts.dest_port_id.clone(),
+        channel_id: opts.dest_channel_id.clone(),
+        proofs: src_chain.build_channel_proofs(
+            &opts.src_port_id,
+            &opts.src_channel_id,
+            ics_target_height,
+        )?,
+        signer,
+    };
+
+    let mut new_msgs = vec![new_msg.to_any::<RawMsgChannelCloseConfirm>()];
+
+    msgs.append(&mut new_msgs);
+
+    Ok(msgs)
+}
+
+pub fn build_chan_close_confirm_and_send(opts: &ChannelOpenOptions) -> Result<IbcEvent, Error> {
+    // Get the source and destination chains.
+    let src_chain = &CosmosSDKChain::from_config(opts.clone().src_chain_config)?;
+    let dest_chain = &mut CosmosSDKChain::from_config(opts.clone().dest_chain_config)?;
+
+    let new_msgs = build_chan_close_confirm(dest_chain, src_chain, opts)?;
+    let key = dest_chain
+        .keybase()
+        .get_key()
+        .map_err(|e| Kind::KeyBase.context(e))?;
+
+    let events = dest_chain.send(new_msgs, key, "".to_string(), 0)?;
+
+    extract_channel_event(events, |reason| {
+        Kind::ChanCloseConfirm(opts.dest_channel_id.clone(), reason)
+    })
 }
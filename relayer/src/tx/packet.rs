@@ -0,0 +1,291 @@
+use prost_types::Any;
+
+use ibc_proto::ibc::core::channel::v1::MsgAcknowledgement as RawMsgAcknowledgement;
+use ibc_proto::ibc::core::channel::v1::MsgRecvPacket as RawMsgRecvPacket;
+use ibc_proto::ibc::core::channel::v1::MsgTimeout as RawMsgTimeout;
+use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
+
+use ibc::events::IbcEvent;
+use ibc::ics04_channel::channel::Order;
+use ibc::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+use ibc::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use ibc::ics04_channel::msgs::timeout::MsgTimeout;
+use ibc::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+use ibc::ics04_channel::packet::Packet;
+use ibc::ics24_host::identifier::ChannelId;
+use ibc::tx_msg::Msg;
+
+use crate::chain::{Chain, CosmosSDKChain};
+use crate::error::{Error, Kind};
+use crate::keyring::store::KeyRingOperations;
+use crate::tx::client::build_update_client;
+
+/// Enumeration of the proof(s) a packet message needs, used to pick the right query
+/// against the chain that last touched the packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PacketMsgType {
+    Recv,
+    Ack,
+    TimeoutUnordered,
+    TimeoutOrdered,
+    TimeoutOnClose,
+}
+
+/// Looks up the client on `chain` that tracks the counterparty for the channel identified by
+/// `port_id`/`channel_id`, by following the channel's first connection hop.
+fn channel_client_id(
+    chain: &CosmosSDKChain,
+    port_id: &ibc::ics24_host::identifier::PortId,
+    channel_id: &ChannelId,
+    err: impl Fn(String) -> Kind,
+) -> Result<ibc::ics24_host::identifier::ClientId, Error> {
+    let channel = chain
+        .query_channel(port_id, channel_id, ibc::Height::default())
+        .map_err(|e| err("channel does not exist".into()).context(e))?;
+
+    let connection_id = channel
+        .connection_hops()
+        .first()
+        .ok_or_else(|| err("channel has no connection hops".into()))?;
+
+    let connection = chain.query_connection(connection_id, ibc::Height::default())?;
+
+    Ok(connection.client_id().clone())
+}
+
+/// Picks the single packet-relay event of interest out of the full event log a transaction
+/// produced (which also carries, e.g., the `UpdateClient` event from a prepended client update
+/// message), so that callers can react to it without re-querying chain state.
+fn extract_packet_event(
+    events: Vec<IbcEvent>,
+    err: impl Fn(String) -> Kind,
+) -> Result<IbcEvent, Error> {
+    events
+        .into_iter()
+        .find(|event| {
+            matches!(
+                event,
+                IbcEvent::WriteAcknowledgement(_)
+                    | IbcEvent::AcknowledgePacket(_)
+                    | IbcEvent::TimeoutPacket(_)
+            )
+        })
+        .ok_or_else(|| err("missing packet event".to_string()).into())
+}
+
+pub fn build_recv_packet(
+    dest_chain: &mut CosmosSDKChain,
+    src_chain: &CosmosSDKChain,
+    packet: Packet,
+) -> Result<Vec<Any>, Error> {
+    let client_id = channel_client_id(
+        dest_chain,
+        &packet.destination_port,
+        &packet.destination_channel,
+        |reason| Kind::RecvPacket(packet.destination_channel.clone(), reason),
+    )?;
+
+    let ics_target_height = src_chain.query_latest_height()?;
+
+    let mut msgs = build_update_client(dest_chain, src_chain, client_id, ics_target_height)?;
+
+    let proofs = src_chain.build_packet_proofs(
+        PacketMsgType::Recv,
+        &packet.source_port,
+        &packet.source_channel,
+        packet.sequence,
+        ics_target_height,
+    )?;
+
+    let signer = dest_chain
+        .get_signer()
+        .map_err(|e| Kind::KeyBase.context(e))?;
+
+    let new_msg = MsgRecvPacket {
+        packet,
+        proofs,
+        signer,
+    };
+
+    let mut new_msgs = vec![new_msg.to_any::<RawMsgRecvPacket>()];
+    msgs.append(&mut new_msgs);
+
+    Ok(msgs)
+}
+
+pub fn build_recv_packet_and_send(
+    dest_chain: &mut CosmosSDKChain,
+    src_chain: &CosmosSDKChain,
+    packet: Packet,
+) -> Result<IbcEvent, Error> {
+    let dest_channel_id = packet.destination_channel.clone();
+    let new_msgs = build_recv_packet(dest_chain, src_chain, packet)?;
+    let key = dest_chain
+        .keybase()
+        .get_key()
+        .map_err(|e| Kind::KeyBase.context(e))?;
+
+    let events = dest_chain.send(new_msgs, key, "".to_string(), 0)?;
+
+    extract_packet_event(events, |reason| Kind::RecvPacket(dest_channel_id.clone(), reason))
+}
+
+pub fn build_ack_packet(
+    dest_chain: &mut CosmosSDKChain,
+    src_chain: &CosmosSDKChain,
+    packet: Packet,
+    acknowledgement: Vec<u8>,
+) -> Result<Vec<Any>, Error> {
+    let client_id = channel_client_id(
+        dest_chain,
+        &packet.source_port,
+        &packet.source_channel,
+        |reason| Kind::AckPacket(packet.source_channel.clone(), reason),
+    )?;
+
+    let ics_target_height = src_chain.query_latest_height()?;
+
+    let mut msgs = build_update_client(dest_chain, src_chain, client_id, ics_target_height)?;
+
+    let proofs = src_chain.build_packet_proofs(
+        PacketMsgType::Ack,
+        &packet.destination_port,
+        &packet.destination_channel,
+        packet.sequence,
+        ics_target_height,
+    )?;
+
+    let signer = dest_chain
+        .get_signer()
+        .map_err(|e| Kind::KeyBase.context(e))?;
+
+    let new_msg = MsgAcknowledgement {
+        packet,
+        acknowledgement,
+        proofs,
+        signer,
+    };
+
+    let mut new_msgs = vec![new_msg.to_any::<RawMsgAcknowledgement>()];
+    msgs.append(&mut new_msgs);
+
+    Ok(msgs)
+}
+
+pub fn build_ack_packet_and_send(
+    dest_chain: &mut CosmosSDKChain,
+    src_chain: &CosmosSDKChain,
+    packet: Packet,
+    acknowledgement: Vec<u8>,
+) -> Result<IbcEvent, Error> {
+    let src_channel_id = packet.source_channel.clone();
+    let new_msgs = build_ack_packet(dest_chain, src_chain, packet, acknowledgement)?;
+    let key = dest_chain
+        .keybase()
+        .get_key()
+        .map_err(|e| Kind::KeyBase.context(e))?;
+
+    let events = dest_chain.send(new_msgs, key, "".to_string(), 0)?;
+
+    extract_packet_event(events, |reason| Kind::AckPacket(src_channel_id.clone(), reason))
+}
+
+/// Builds a timeout message for `packet`, which was sent from `dest_chain` (the chain that will
+/// process the timeout and, e.g., refund the sender) and addressed to `src_chain` (the chain
+/// that never received it, and that the timeout/timeout-on-close proofs are queried from).
+///
+/// Compares `packet.timeout_height`/`packet.timeout_timestamp` against `src_chain`'s latest
+/// height and consensus timestamp: if either has been reached, the packet genuinely timed out
+/// and a plain [`MsgTimeout`] is built; otherwise the only way a timeout is being requested is
+/// that the channel on `src_chain` was closed before the packet's timeout elapsed, so a
+/// [`MsgTimeoutOnClose`] is built instead, carrying an additional proof of the channel closure.
+pub fn build_timeout_packet(
+    dest_chain: &mut CosmosSDKChain,
+    src_chain: &CosmosSDKChain,
+    packet: Packet,
+    packet_ordering: Order,
+) -> Result<Vec<Any>, Error> {
+    let client_id = channel_client_id(
+        dest_chain,
+        &packet.source_port,
+        &packet.source_channel,
+        |reason| Kind::TimeoutPacket(packet.source_channel.clone(), reason),
+    )?;
+
+    let ics_target_height = src_chain.query_latest_height()?;
+    let src_chain_time = src_chain.query_latest_timestamp()?;
+
+    let timed_out = (!packet.timeout_height.is_zero() && ics_target_height >= packet.timeout_height)
+        || (!packet.timeout_timestamp.is_zero() && src_chain_time >= packet.timeout_timestamp);
+
+    let channel_has_closed = !timed_out;
+
+    let mut msgs = build_update_client(dest_chain, src_chain, client_id, ics_target_height)?;
+
+    let signer = dest_chain
+        .get_signer()
+        .map_err(|e| Kind::KeyBase.context(e))?;
+
+    let next_sequence_recv = packet.sequence;
+
+    let new_msgs = if channel_has_closed {
+        let proofs = src_chain.build_packet_proofs(
+            PacketMsgType::TimeoutOnClose,
+            &packet.destination_port,
+            &packet.destination_channel,
+            next_sequence_recv,
+            ics_target_height,
+        )?;
+
+        vec![MsgTimeoutOnClose {
+            packet,
+            next_sequence_recv,
+            proofs,
+            signer,
+        }
+        .to_any::<RawMsgTimeoutOnClose>()]
+    } else {
+        let msg_type = match packet_ordering {
+            Order::Ordered => PacketMsgType::TimeoutOrdered,
+            Order::Unordered | Order::None => PacketMsgType::TimeoutUnordered,
+        };
+
+        let proofs = src_chain.build_packet_proofs(
+            msg_type,
+            &packet.destination_port,
+            &packet.destination_channel,
+            next_sequence_recv,
+            ics_target_height,
+        )?;
+
+        vec![MsgTimeout {
+            packet,
+            next_sequence_recv,
+            proofs,
+            signer,
+        }
+        .to_any::<RawMsgTimeout>()]
+    };
+
+    msgs.extend(new_msgs);
+
+    Ok(msgs)
+}
+
+pub fn build_timeout_packet_and_send(
+    dest_chain: &mut CosmosSDKChain,
+    src_chain: &CosmosSDKChain,
+    packet: Packet,
+    packet_ordering: Order,
+) -> Result<IbcEvent, Error> {
+    let src_channel_id = packet.source_channel.clone();
+    let new_msgs = build_timeout_packet(dest_chain, src_chain, packet, packet_ordering)?;
+    let key = dest_chain
+        .keybase()
+        .get_key()
+        .map_err(|e| Kind::KeyBase.context(e))?;
+
+    let events = dest_chain.send(new_msgs, key, "".to_string(), 0)?;
+
+    extract_packet_event(events, |reason| Kind::TimeoutPacket(src_channel_id.clone(), reason))
+}
@@ -1,21 +1,76 @@
 use core::time::Duration;
+use core::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 use crossbeam_channel::Receiver;
 use ibc::Height;
-use std::sync::{Arc, Mutex};
-use tracing::{error, trace};
+use rand::Rng;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tracing::{error, info_span, trace};
 
 use crate::chain::handle::ChainHandle;
+use crate::config::Packets as PacketsConfig;
 use crate::foreign_client::HasExpiredOrFrozenError;
 use crate::link::{error::LinkError, Link, RelaySummary};
 use crate::object::Packet;
 use crate::telemetry;
 use crate::util::retry::{retry_with_index, RetryResult};
 use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
-use crate::worker::retry_strategy;
 
 use super::error::RunError;
 use super::WorkerCmd;
 
+/// Exponential-backoff-with-jitter retry policy used by [`spawn_packet_cmd_worker`].
+///
+/// On each failed attempt, the delay before the next attempt is
+/// `min(max_delay, initial_delay * backoff_factor^index)`, plus a uniform
+/// random jitter in `[0, delay / 2]`. Once `max_count` attempts have been made,
+/// the worker gives up instead of retrying further.
+#[derive(Copy, Clone, Debug)]
+pub struct PacketRetryPolicy {
+    initial_delay: Duration,
+    backoff_factor: u32,
+    max_delay: Duration,
+    max_count: u64,
+}
+
+impl PacketRetryPolicy {
+    pub fn new(config: &PacketsConfig) -> Self {
+        Self {
+            initial_delay: config.retry_initial_delay,
+            backoff_factor: config.retry_backoff_factor,
+            max_delay: config.retry_max_delay,
+            max_count: config.retry_max_count,
+        }
+    }
+
+    fn has_exhausted(&self, index: u64) -> bool {
+        index >= self.max_count
+    }
+
+    fn delay_for(&self, index: u64) -> Duration {
+        let exponent = u32::try_from(index).unwrap_or(u32::MAX);
+        let backoff = self
+            .initial_delay
+            .saturating_mul(self.backoff_factor.saturating_pow(exponent))
+            .min(self.max_delay);
+
+        let jitter_nanos = rand::thread_rng().gen_range(0..=(backoff.as_nanos() / 2) as u64);
+
+        backoff + Duration::from_nanos(jitter_nanos)
+    }
+}
+
+impl IntoIterator for PacketRetryPolicy {
+    type Item = Duration;
+    type IntoIter = std::vec::IntoIter<Duration>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (0..self.max_count)
+            .map(|index| self.delay_for(index))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
 /// Whether or not to clear pending packets at this `step` for the given height.
 /// Packets are cleared on the first iteration if `clear_on_start` is true.
 /// Subsequently, packets are cleared only if `clear_interval` is not `0` and
@@ -42,21 +97,92 @@ fn handle_link_error_in_task(e: LinkError) -> TaskError<RunError> {
     }
 }
 
+/// Generates a short, monotonically increasing id to correlate all the log lines
+/// produced by a single relaying cycle (one iteration of the packet worker, or the
+/// handling of one [`WorkerCmd`]).
+fn relay_cycle_id() -> String {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+    format!("{:08x}", NEXT_ID.fetch_add(1, AtomicOrdering::Relaxed))
+}
+
+/// Locks `link`, recovering the inner guard instead of panicking if some other
+/// worker sharing this `link` panicked while holding the lock. A single
+/// worker's panic should not wedge every other task relaying over the same
+/// link.
+fn lock_link<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &Mutex<Link<ChainA, ChainB>>,
+) -> MutexGuard<'_, Link<ChainA, ChainB>> {
+    link.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Cooperative cancellation and step-tracking shared between a packet worker
+/// task and whatever supervises its lifecycle. Checked between scheduling
+/// steps so that a shutdown request is honored promptly instead of only at
+/// the next top-level task invocation.
+#[derive(Clone, Debug, Default)]
+pub struct WorkerShutdown {
+    cancelled: Arc<std::sync::atomic::AtomicBool>,
+    steps: Arc<AtomicU64>,
+}
+
+impl WorkerShutdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests that every task sharing this handle stop at its next
+    /// cancellation check.
+    pub fn cancel(&self) {
+        self.cancelled
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Total number of scheduling steps completed across all tasks sharing
+    /// this handle, for observability into a supervised worker's liveness.
+    pub fn step_count(&self) -> u64 {
+        self.steps.load(AtomicOrdering::Relaxed)
+    }
+
+    fn record_step(&self) {
+        self.steps.fetch_add(1, AtomicOrdering::Relaxed);
+    }
+
+    fn check(&self) -> Result<(), TaskError<RunError>> {
+        if self.is_cancelled() {
+            Err(TaskError::Fatal(RunError::cancelled()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
 pub fn spawn_packet_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     path: Packet,
     // Mutex is used to prevent race condition between the packet workers
     link: Arc<Mutex<Link<ChainA, ChainB>>>,
+    shutdown: WorkerShutdown,
 ) -> TaskHandle {
     spawn_background_task(
-        format!("PacketWorker({})", link.lock().unwrap().a_to_b),
+        format!("PacketWorker({})", lock_link(&link).a_to_b),
         Some(Duration::from_millis(1000)),
         move || {
-            let relay_path = &link.lock().unwrap().a_to_b;
+            let span = info_span!("relay_cycle", id = %relay_cycle_id(), path = %path.short_name());
+            let _guard = span.enter();
+
+            shutdown.check()?;
+
+            let relay_path = &lock_link(&link).a_to_b;
 
             relay_path
                 .refresh_schedule()
                 .map_err(handle_link_error_in_task)?;
 
+            shutdown.check()?;
+
             relay_path
                 .execute_schedule()
                 .map_err(handle_link_error_in_task)?;
@@ -69,6 +195,8 @@ pub fn spawn_packet_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
             telemetry!(packet_metrics(&path, &summary));
 
+            shutdown.record_step();
+
             Ok(Next::Continue)
         },
     )
@@ -80,26 +208,36 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: Arc<Mutex<Link<ChainA, ChainB>>>,
     clear_on_start: bool,
     clear_interval: u64,
+    retry_policy: PacketRetryPolicy,
     path: Packet,
+    shutdown: WorkerShutdown,
 ) -> TaskHandle {
     let mut is_first_run: bool = true;
     spawn_background_task(
-        format!("PacketCmdWorker({})", link.lock().unwrap().a_to_b),
+        format!("PacketCmdWorker({})", lock_link(&link).a_to_b),
         Some(Duration::from_millis(200)),
         move || {
+            shutdown.check()?;
+
             if let Ok(cmd) = cmd_rx.try_recv() {
-                retry_with_index(retry_strategy::worker_stubborn_strategy(), |index| {
+                let span = info_span!("relay_cycle", id = %relay_cycle_id(), path = %path.short_name());
+                let _guard = span.enter();
+
+                retry_with_index(retry_policy, |index| {
                     handle_packet_cmd(
                         &mut is_first_run,
-                        &link.lock().unwrap(),
+                        &lock_link(&link),
                         clear_on_start,
                         clear_interval,
+                        &retry_policy,
                         &path,
                         cmd.clone(),
                         index,
                     )
                 })
                 .map_err(|e| TaskError::Fatal(RunError::retry(e)))?;
+
+                shutdown.record_step();
             }
 
             Ok(Next::Continue)
@@ -120,6 +258,7 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &Link<ChainA, ChainB>,
     clear_on_start: bool,
     clear_interval: u64,
+    retry_policy: &PacketRetryPolicy,
     path: &Packet,
     cmd: WorkerCmd,
     index: u64,
@@ -152,7 +291,11 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
             link.a_to_b, e
         );
 
-        return RetryResult::Retry(index);
+        return if retry_policy.has_exhausted(index) {
+            RetryResult::Err(index)
+        } else {
+            RetryResult::Retry(index)
+        };
     }
 
     // The calls to refresh_schedule and execute_schedule depends on
@@ -183,7 +326,11 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
                 "[{}] worker: schedule execution encountered error: {}",
                 link.a_to_b, e
             );
-            return RetryResult::Retry(index);
+            return if retry_policy.has_exhausted(index) {
+                RetryResult::Err(index)
+            } else {
+                RetryResult::Retry(index)
+            };
         }
     }
 
@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 use tracing::{error_span, trace, warn};
@@ -8,10 +10,29 @@ use crate::{
     util::task::{spawn_background_task, Next, TaskError, TaskHandle},
 };
 
-pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
+/// A flag that [`spawn_wallet_worker`] raises once the relayer wallet's balance on a chain drops
+/// below the chain's configured minimum, and lowers again on a later check that finds the balance
+/// at or above it. [`Self::is_below_minimum`] reads it; the worker itself does not pause any
+/// transactions.
+#[derive(Clone, Default)]
+pub struct WalletBalanceAlert(Arc<AtomicBool>);
+
+impl WalletBalanceAlert {
+    pub fn is_below_minimum(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn set_below_minimum(&self, below_minimum: bool) {
+        self.0.store(below_minimum, Ordering::Relaxed);
+    }
+}
+
+pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> (TaskHandle, WalletBalanceAlert) {
     let span = error_span!("wallet", chain = %chain.id());
+    let alert = WalletBalanceAlert::default();
+    let task_alert = alert.clone();
 
-    spawn_background_task(span, Some(Duration::from_secs(5)), move || {
+    let handle = spawn_background_task(span, Some(Duration::from_secs(5)), move || {
         let key = chain.get_key().map_err(|e| {
             TaskError::Fatal(format!("failed to get key in use by the relayer: {e}"))
         })?;
@@ -20,6 +41,11 @@ pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
             TaskError::Ignore(format!("failed to query balance for the account: {e}"))
         })?;
 
+        let min_wallet_balance = chain
+            .config()
+            .map_err(|e| TaskError::Ignore(format!("failed to query chain config: {e}")))?
+            .min_wallet_balance;
+
         match balance.amount.parse::<f64>() {
             Ok(amount) => {
                 telemetry!(
@@ -30,6 +56,30 @@ pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
                     &balance.denom,
                 );
                 trace!(%amount, denom = %balance.denom, account = %key.account, "wallet balance");
+
+                if let Some(min_balance) = &min_wallet_balance {
+                    if min_balance.denom != balance.denom {
+                        warn!(
+                            "cannot compare wallet balance ({} {}) against the configured minimum \
+                             balance ({}): denoms don't match, skipping low-balance check",
+                            amount, balance.denom, min_balance
+                        );
+                    } else if amount < min_balance.amount {
+                        telemetry!(wallet_balance_below_minimum, &chain.id(), &key.account);
+                        warn!(
+                            "wallet balance ({} {}) for account {} on chain {} dropped below the \
+                             configured minimum ({})",
+                            amount,
+                            balance.denom,
+                            key.account,
+                            chain.id(),
+                            min_balance
+                        );
+                        task_alert.set_below_minimum(true);
+                    } else {
+                        task_alert.set_below_minimum(false);
+                    }
+                }
             }
             Err(e) => {
                 trace!(
@@ -43,7 +93,9 @@ pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
             }
         }
         Ok(Next::Continue)
-    })
+    });
+
+    (handle, alert)
 }
 
 #[cfg(test)]
@@ -194,6 +194,7 @@ pub fn ibc_transfer_send_packet<ChainA: ChainHandle, ChainB: ChainHandle>(
         &wallet_target.address(),
         amount_source_to_target,
         &denom_source,
+        packet.memo.as_deref(),
     )?;
 
     node_source.chain_driver().assert_eventual_wallet_amount(
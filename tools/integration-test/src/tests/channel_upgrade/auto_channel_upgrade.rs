@@ -0,0 +1,127 @@
+//! Tests a channel upgrade driven by the supervisor's `ChannelUpgradeWorker` rather than by
+//! manually calling each handshake step:
+//!
+//! - `ChannelUpgradeAutoHandshake` proposes an upgrade via INIT and then only waits for both
+//!   channel ends to be `Open`; the TRY/ACK/CONFIRM/OPEN steps are expected to be relayed
+//!   automatically by the supervisor in response to the events INIT emits. The final check
+//!   looks at the channel state only; the upgraded version is not checked.
+
+use ibc_relayer::chain::requests::{IncludeProof, QueryChannelRequest, QueryHeight};
+use ibc_relayer_types::core::ics04_channel::timeout::UpgradeTimeout;
+use ibc_relayer_types::core::{ics02_client::height::Height, ics04_channel::version::Version};
+use ibc_test_framework::prelude::*;
+use ibc_test_framework::relayer::channel::{
+    assert_eventually_channel_established, assert_eventually_channel_upgrade_init,
+    ChannelUpgradableAttributes,
+};
+
+#[test]
+fn test_channel_upgrade_auto_handshake() -> Result<(), Error> {
+    run_binary_channel_test(&ChannelUpgradeAutoHandshake)
+}
+
+pub struct ChannelUpgradeAutoHandshake;
+
+impl TestOverrides for ChannelUpgradeAutoHandshake {
+    fn modify_test_config(&self, config: &mut TestConfig) {
+        config.bootstrap_with_random_ids = true;
+    }
+
+    fn should_spawn_supervisor(&self) -> bool {
+        true
+    }
+}
+
+impl BinaryChannelTest for ChannelUpgradeAutoHandshake {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+        channels: ConnectedChannel<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        info!("Check that channels are both in OPEN State");
+
+        assert_eventually_channel_established(
+            &chains.handle_b,
+            &chains.handle_a,
+            &channels.channel_id_b.as_ref(),
+            &channels.port_b.as_ref(),
+        )?;
+
+        let channel_end_a = chains
+            .handle_a
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: channels.port_a.0.clone(),
+                    channel_id: channels.channel_id_a.0.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map(|(channel_end, _)| channel_end)
+            .map_err(|e| eyre!("Error querying ChannelEnd A: {e}"))?;
+
+        let channel_end_b = chains
+            .handle_b
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: channels.port_b.0.clone(),
+                    channel_id: channels.channel_id_b.0.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map(|(channel_end, _)| channel_end)
+            .map_err(|e| eyre!("Error querying ChannelEnd B: {e}"))?;
+
+        let old_version = channel_end_a.version;
+        let old_ordering = channel_end_a.ordering;
+        let old_connection_hops_a = channel_end_a.connection_hops;
+        let old_connection_hops_b = channel_end_b.connection_hops;
+
+        let channel = channels.channel;
+        let new_version = Version::ics20_with_fee();
+
+        let upgrade_attrs = ChannelUpgradableAttributes::new(
+            old_version,
+            old_ordering,
+            old_connection_hops_a,
+            old_connection_hops_b,
+        );
+
+        let timeout_height = Height::new(
+            ChainId::chain_version(chains.chain_id_b().0.to_string().as_str()),
+            120,
+        )
+        .map_err(|e| eyre!("error creating height for timeout height: {e}"))?;
+        let timeout = UpgradeTimeout::Height(timeout_height);
+
+        info!("Propose the channel upgrade via INIT, then let the supervisor relay the rest...");
+
+        channel
+            .flipped()
+            .build_chan_upgrade_init_and_send(Some(new_version), None, None, timeout)?;
+
+        info!("Check that the step ChanUpgradeInit was correctly executed...");
+
+        assert_eventually_channel_upgrade_init(
+            &chains.handle_a,
+            &chains.handle_b,
+            &channels.channel_id_a.as_ref(),
+            &channels.port_a.as_ref(),
+            &upgrade_attrs,
+        )?;
+
+        info!("Wait for the supervisor to relay TRY/ACK/CONFIRM/OPEN without any manual step calls...");
+
+        assert_eventually_channel_established(
+            &chains.handle_a,
+            &chains.handle_b,
+            &channels.channel_id_a.as_ref(),
+            &channels.port_a.as_ref(),
+        )?;
+
+        Ok(())
+    }
+}
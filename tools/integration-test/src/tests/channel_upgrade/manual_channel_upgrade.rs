@@ -4,13 +4,16 @@
 //!   without relaying on the supervisor. This test manually calls the INIT, TRY,
 //!   ACK and CONFIRM steps.
 
+use core::time::Duration;
+
 use ibc_relayer::chain::requests::{IncludeProof, QueryChannelRequest, QueryHeight};
 use ibc_relayer_types::core::ics04_channel::timeout::UpgradeTimeout;
 use ibc_relayer_types::core::{ics02_client::height::Height, ics04_channel::version::Version};
 use ibc_test_framework::prelude::*;
 use ibc_test_framework::relayer::channel::{
     assert_eventually_channel_established, assert_eventually_channel_upgrade_ack,
-    assert_eventually_channel_upgrade_init, assert_eventually_channel_upgrade_try,
+    assert_eventually_channel_upgrade_cancel, assert_eventually_channel_upgrade_init,
+    assert_eventually_channel_upgrade_timeout, assert_eventually_channel_upgrade_try,
     ChannelUpgradableAttributes,
 };
 
@@ -144,3 +147,250 @@ impl BinaryChannelTest for ChannelUpgradeManualHandshake {
         Ok(())
     }
 }
+
+#[test]
+fn test_channel_upgrade_timeout() -> Result<(), Error> {
+    run_binary_channel_test(&ChannelUpgradeManualHandshakeTimeout)
+}
+
+pub struct ChannelUpgradeManualHandshakeTimeout;
+
+impl TestOverrides for ChannelUpgradeManualHandshakeTimeout {
+    fn modify_test_config(&self, config: &mut TestConfig) {
+        config.bootstrap_with_random_ids = true;
+    }
+
+    fn should_spawn_supervisor(&self) -> bool {
+        false
+    }
+}
+
+impl BinaryChannelTest for ChannelUpgradeManualHandshakeTimeout {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+        channels: ConnectedChannel<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        info!("Check that channels are both in OPEN State");
+
+        assert_eventually_channel_established(
+            &chains.handle_b,
+            &chains.handle_a,
+            &channels.channel_id_b.as_ref(),
+            &channels.port_b.as_ref(),
+        )?;
+
+        let channel_end_a = chains
+            .handle_a
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: channels.port_a.0.clone(),
+                    channel_id: channels.channel_id_a.0.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map(|(channel_end, _)| channel_end)
+            .map_err(|e| eyre!("Error querying ChannelEnd A: {e}"))?;
+
+        let channel_end_b = chains
+            .handle_b
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: channels.port_b.0.clone(),
+                    channel_id: channels.channel_id_b.0.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map(|(channel_end, _)| channel_end)
+            .map_err(|e| eyre!("Error querying ChannelEnd B: {e}"))?;
+
+        let old_version = channel_end_a.version;
+        let old_ordering = channel_end_a.ordering;
+        let old_connection_hops_a = channel_end_a.connection_hops;
+        let old_connection_hops_b = channel_end_b.connection_hops;
+
+        let channel = channels.channel;
+        let new_version = Version::ics20_with_fee();
+
+        let upgrade_attrs = ChannelUpgradableAttributes::new(
+            old_version,
+            old_ordering,
+            old_connection_hops_a,
+            old_connection_hops_b,
+        );
+
+        // Pick a timeout that has already elapsed by the time chain B would otherwise
+        // complete flushing, so the counterparty never gets a chance to finish its side of
+        // the upgrade and the relayer is forced down the timeout path instead.
+        let current_height_b = chains
+            .handle_b
+            .query_latest_height()
+            .map_err(|e| eyre!("Error querying latest height on chain B: {e}"))?;
+        let timeout_height = current_height_b
+            .increment()
+            .increment();
+        let timeout = UpgradeTimeout::Height(timeout_height);
+
+        info!("Set channel in (INITUPGRADE, OPEN) state with a short-lived timeout...");
+
+        channel.flipped().build_chan_upgrade_init_and_send(
+            Some(new_version),
+            None,
+            None,
+            timeout,
+        )?;
+
+        assert_eventually_channel_upgrade_init(
+            &chains.handle_a,
+            &chains.handle_b,
+            &channels.channel_id_a.as_ref(),
+            &channels.port_a.as_ref(),
+            &upgrade_attrs,
+        )?;
+
+        info!("Wait for the upgrade timeout height to elapse on chain B...");
+
+        std::thread::sleep(Duration::from_secs(10));
+
+        info!("Submit ChanUpgradeTimeout on chain A and check the channel was restored...");
+
+        channel.flipped().build_chan_upgrade_timeout_and_send()?;
+
+        assert_eventually_channel_upgrade_timeout(
+            &chains.handle_a,
+            &channels.channel_id_a.as_ref(),
+            &channels.port_a.as_ref(),
+            &upgrade_attrs,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_channel_upgrade_cancel() -> Result<(), Error> {
+    run_binary_channel_test(&ChannelUpgradeManualHandshakeCancel)
+}
+
+pub struct ChannelUpgradeManualHandshakeCancel;
+
+impl TestOverrides for ChannelUpgradeManualHandshakeCancel {
+    fn modify_test_config(&self, config: &mut TestConfig) {
+        config.bootstrap_with_random_ids = true;
+    }
+
+    fn should_spawn_supervisor(&self) -> bool {
+        false
+    }
+}
+
+impl BinaryChannelTest for ChannelUpgradeManualHandshakeCancel {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+        channels: ConnectedChannel<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        info!("Check that channels are both in OPEN State");
+
+        assert_eventually_channel_established(
+            &chains.handle_b,
+            &chains.handle_a,
+            &channels.channel_id_b.as_ref(),
+            &channels.port_b.as_ref(),
+        )?;
+
+        let channel_end_a = chains
+            .handle_a
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: channels.port_a.0.clone(),
+                    channel_id: channels.channel_id_a.0.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map(|(channel_end, _)| channel_end)
+            .map_err(|e| eyre!("Error querying ChannelEnd A: {e}"))?;
+
+        let channel_end_b = chains
+            .handle_b
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: channels.port_b.0.clone(),
+                    channel_id: channels.channel_id_b.0.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map(|(channel_end, _)| channel_end)
+            .map_err(|e| eyre!("Error querying ChannelEnd B: {e}"))?;
+
+        let old_version = channel_end_a.version;
+        let old_ordering = channel_end_a.ordering;
+        let old_connection_hops_a = channel_end_a.connection_hops;
+        let old_connection_hops_b = channel_end_b.connection_hops;
+
+        let channel = channels.channel;
+
+        let upgrade_attrs = ChannelUpgradableAttributes::new(
+            old_version,
+            old_ordering,
+            old_connection_hops_a,
+            old_connection_hops_b,
+        );
+
+        // An app module on chain B has no intersection logic that accepts this version, so
+        // TRY will fail on chain B and it will write an ErrorReceipt instead of advancing.
+        let incompatible_version = Version::new("incompatible-upgrade-version".to_string());
+
+        let timeout_height = Height::new(
+            ChainId::chain_version(chains.chain_id_b().0.to_string().as_str()),
+            120,
+        )
+        .map_err(|e| eyre!("error creating height for timeout height: {e}"))?;
+        let timeout = UpgradeTimeout::Height(timeout_height);
+
+        info!("Set channel in (INITUPGRADE, OPEN) state with an incompatible version...");
+
+        channel.flipped().build_chan_upgrade_init_and_send(
+            Some(incompatible_version),
+            None,
+            None,
+            timeout.clone(),
+        )?;
+
+        assert_eventually_channel_upgrade_init(
+            &chains.handle_a,
+            &chains.handle_b,
+            &channels.channel_id_a.as_ref(),
+            &channels.port_a.as_ref(),
+            &upgrade_attrs,
+        )?;
+
+        info!("Attempt ChanUpgradeTry on chain B, which should reject the version and write an ErrorReceipt...");
+
+        let _ = channel.build_chan_upgrade_try_and_send(timeout);
+
+        info!("Submit ChanUpgradeCancel on chain A with proof of chain B's ErrorReceipt...");
+
+        channel.flipped().build_chan_upgrade_cancel_and_send()?;
+
+        info!("Check that both channel ends were reverted back to their original version...");
+
+        assert_eventually_channel_upgrade_cancel(
+            &chains.handle_a,
+            &chains.handle_b,
+            &channels.channel_id_a.as_ref(),
+            &channels.port_a.as_ref(),
+            &upgrade_attrs,
+        )?;
+
+        Ok(())
+    }
+}
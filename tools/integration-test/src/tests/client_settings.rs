@@ -2,11 +2,15 @@ use std::time::Duration;
 
 use ibc::core::ics02_client::trust_threshold::TrustThreshold;
 
-use ibc::clients::ics07_tendermint::client_state::ClientState as TendermintClientState;
-use ibc::core::ics02_client::client_state::AnyClientState;
-use ibc::Height;
+use ibc::clients::ics07_tendermint::client_state::{
+    AllowUpdate, ClientState as TendermintClientState,
+};
+use ibc::core::ics23_commitment::specs::ProofSpecs;
 use ibc_relayer::chain::client::ClientSettings;
 use ibc_relayer::chain::cosmos;
+use ibc_test_framework::relayer::client::{
+    query_client_state, query_client_states, submit_duplicate_vote_misbehaviour,
+};
 
 use ibc_test_framework::prelude::*;
 
@@ -22,12 +26,24 @@ fn test_client_settings() -> Result<(), Error> {
     run_binary_chain_test(&ClientSettingsTest)
 }
 
+/// A test to exercise partially overriding foreign client settings via
+/// [`cosmos::client::Settings::builder`], leaving every field it doesn't set to fall back to the
+/// configuration-derived default.
+#[test]
+fn test_client_settings_builder() -> Result<(), Error> {
+    run_binary_chain_test(&ClientSettingsBuilderTest)
+}
+
 struct ClientDefaultsTest;
 
 struct ClientSettingsTest;
 
+struct ClientSettingsBuilderTest;
+
 struct SettingsTestOverrides;
 
+struct BuilderSettingsTestOverrides;
+
 impl TestOverrides for ClientDefaultsTest {
     fn modify_relayer_config(&self, config: &mut Config) {
         config.chains[0].clock_drift = Duration::from_secs(3);
@@ -50,13 +66,13 @@ impl BinaryChainTest for ClientDefaultsTest {
         chains: ConnectedChains<ChainA, ChainB>,
     ) -> Result<(), Error> {
         let client_id = chains.foreign_clients.client_a_to_b.id();
-        let state = query_client_state(chains.handle_b, client_id)?;
+        let state: TendermintClientState = query_client_state(&chains.handle_b, client_id)?;
         assert_eq!(state.max_clock_drift, Duration::from_secs(24));
         assert_eq!(state.trusting_period, Duration::from_secs(120_000));
         assert_eq!(state.trust_level, TrustThreshold::new(13, 23).unwrap());
 
         let client_id = chains.foreign_clients.client_b_to_a.id();
-        let state = query_client_state(chains.handle_a, client_id)?;
+        let state: TendermintClientState = query_client_state(&chains.handle_a, client_id)?;
         assert_eq!(state.max_clock_drift, Duration::from_secs(14));
         assert_eq!(state.trusting_period, Duration::from_secs(340_000));
         assert_eq!(state.trust_level, TrustThreshold::TWO_THIRDS);
@@ -70,6 +86,17 @@ impl TestOverrides for SettingsTestOverrides {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(120_000)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            unbonding_period: Some(Duration::from_secs(1_209_600)),
+            allow_update: Some(AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            }),
+            upgrade_path: Some(vec![
+                "upgrade".to_string(),
+                "upgradedIBCState".to_string(),
+            ]),
+            proof_specs: Some(ProofSpecs::cosmos()),
+            frozen_height: None,
         })
     }
 
@@ -78,6 +105,17 @@ impl TestOverrides for SettingsTestOverrides {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(340_000)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            unbonding_period: Some(Duration::from_secs(2_419_200)),
+            allow_update: Some(AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: true,
+            }),
+            upgrade_path: Some(vec![
+                "upgrade".to_string(),
+                "upgradedIBCState".to_string(),
+            ]),
+            proof_specs: Some(ProofSpecs::cosmos()),
+            frozen_height: None,
         })
     }
 }
@@ -90,16 +128,44 @@ impl BinaryChainTest for ClientSettingsTest {
         chains: ConnectedChains<ChainA, ChainB>,
     ) -> Result<(), Error> {
         let client_id = chains.foreign_clients.client_a_to_b.id();
-        let state = query_client_state(chains.handle_b, client_id)?;
+        let state: TendermintClientState = query_client_state(&chains.handle_b, client_id)?;
         assert_eq!(state.max_clock_drift, Duration::from_secs(3));
         assert_eq!(state.trusting_period, Duration::from_secs(120_000));
         assert_eq!(state.trust_level, TrustThreshold::new(13, 23).unwrap());
+        assert_eq!(state.unbonding_period, Duration::from_secs(1_209_600));
+        assert_eq!(
+            state.allow_update,
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            }
+        );
+        assert_eq!(
+            state.upgrade_path,
+            vec!["upgrade".to_string(), "upgradedIBCState".to_string()]
+        );
+        assert_eq!(state.proof_specs, ProofSpecs::cosmos());
+        assert_eq!(state.frozen_height, None);
 
         let client_id = chains.foreign_clients.client_b_to_a.id();
-        let state = query_client_state(chains.handle_a, client_id)?;
+        let state: TendermintClientState = query_client_state(&chains.handle_a, client_id)?;
         assert_eq!(state.max_clock_drift, Duration::from_secs(6));
         assert_eq!(state.trusting_period, Duration::from_secs(340_000));
         assert_eq!(state.trust_level, TrustThreshold::TWO_THIRDS);
+        assert_eq!(state.unbonding_period, Duration::from_secs(2_419_200));
+        assert_eq!(
+            state.allow_update,
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: true,
+            }
+        );
+        assert_eq!(
+            state.upgrade_path,
+            vec!["upgrade".to_string(), "upgradedIBCState".to_string()]
+        );
+        assert_eq!(state.proof_specs, ProofSpecs::cosmos());
+        assert_eq!(state.frozen_height, None);
         Ok(())
     }
 }
@@ -112,14 +178,131 @@ impl HasOverrides for ClientSettingsTest {
     }
 }
 
-fn query_client_state<Chain: ChainHandle>(
-    handle: Chain,
-    id: &ClientId,
-) -> Result<TendermintClientState, Error> {
-    let state = handle.query_client_state(id, Height::zero())?;
-    #[allow(unreachable_patterns)]
-    match state {
-        AnyClientState::Tendermint(state) => Ok(state),
-        _ => unreachable!("unexpected client state type"),
+impl TestOverrides for BuilderSettingsTestOverrides {
+    fn modify_relayer_config(&self, config: &mut Config) {
+        config.chains[0].clock_drift = Duration::from_secs(3);
+        config.chains[0].max_block_time = Duration::from_secs(5);
+        config.chains[0].trusting_period = Some(Duration::from_secs(120_000));
+
+        config.chains[1].clock_drift = Duration::from_secs(6);
+        config.chains[1].max_block_time = Duration::from_secs(15);
+        config.chains[1].trusting_period = Some(Duration::from_secs(340_000));
+    }
+
+    fn client_settings_a_to_b(&self) -> ClientSettings {
+        ClientSettings::Cosmos(
+            cosmos::client::Settings::builder()
+                .trust_threshold(TrustThreshold::new(13, 23).unwrap())
+                .build(),
+        )
+    }
+
+    fn client_settings_b_to_a(&self) -> ClientSettings {
+        ClientSettings::Cosmos(
+            cosmos::client::Settings::builder()
+                .trust_threshold(TrustThreshold::TWO_THIRDS)
+                .build(),
+        )
+    }
+}
+
+impl BinaryChainTest for ClientSettingsBuilderTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let client_id = chains.foreign_clients.client_a_to_b.id();
+        let state: TendermintClientState = query_client_state(&chains.handle_b, client_id)?;
+        assert_eq!(state.trust_level, TrustThreshold::new(13, 23).unwrap());
+        // Fields the builder left unset should fall back to the configuration-derived defaults.
+        assert_eq!(state.max_clock_drift, Duration::from_secs(24));
+        assert_eq!(state.trusting_period, Duration::from_secs(120_000));
+
+        let client_id = chains.foreign_clients.client_b_to_a.id();
+        let state: TendermintClientState = query_client_state(&chains.handle_a, client_id)?;
+        assert_eq!(state.trust_level, TrustThreshold::TWO_THIRDS);
+        assert_eq!(state.max_clock_drift, Duration::from_secs(14));
+        assert_eq!(state.trusting_period, Duration::from_secs(340_000));
+        Ok(())
+    }
+}
+
+/// A test to exercise batched, concurrent querying of client state across many clients.
+#[test]
+fn test_client_states_batched() -> Result<(), Error> {
+    run_binary_chain_test(&ClientStatesBatchedTest)
+}
+
+struct ClientStatesBatchedTest;
+
+impl HasOverrides for ClientStatesBatchedTest {
+    type Overrides = ClientDefaultsTest;
+
+    fn get_overrides(&self) -> &ClientDefaultsTest {
+        &ClientDefaultsTest
+    }
+}
+
+impl BinaryChainTest for ClientStatesBatchedTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let client_a_to_b = chains.foreign_clients.client_a_to_b.id();
+        let client_b_to_a = chains.foreign_clients.client_b_to_a.id();
+
+        let states: std::collections::HashMap<_, TendermintClientState> = query_client_states(&[
+            (chains.handle_b.clone(), client_a_to_b.clone()),
+            (chains.handle_a.clone(), client_b_to_a.clone()),
+        ])?;
+
+        assert_eq!(
+            states[client_a_to_b].max_clock_drift,
+            Duration::from_secs(24)
+        );
+        assert_eq!(
+            states[client_b_to_a].max_clock_drift,
+            Duration::from_secs(14)
+        );
+        Ok(())
+    }
+}
+
+/// A test to exercise that a client actually freezes when it receives conflicting
+/// (duplicate-vote-style) misbehaviour for a height it has already trusted.
+#[test]
+fn test_client_freezes_on_misbehaviour() -> Result<(), Error> {
+    run_binary_chain_test(&ClientMisbehaviourTest)
+}
+
+struct ClientMisbehaviourTest;
+
+impl BinaryChainTest for ClientMisbehaviourTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let client = &chains.foreign_clients.client_a_to_b;
+
+        submit_duplicate_vote_misbehaviour(client)?;
+
+        let state: TendermintClientState =
+            query_client_state(&chains.handle_b, client.id())?;
+        assert!(state.frozen_height.is_some());
+        Ok(())
+    }
+}
+
+impl HasOverrides for ClientSettingsBuilderTest {
+    type Overrides = BuilderSettingsTestOverrides;
+
+    fn get_overrides(&self) -> &BuilderSettingsTestOverrides {
+        &BuilderSettingsTestOverrides
     }
 }
@@ -0,0 +1,38 @@
+use ibc::Height;
+use ibc_test_framework::prelude::*;
+use ibc_test_framework::relayer::client::{assert_consensus_states_exist, build_backward_update_client};
+
+/// A test exercising backward (hash-chained) light-client verification: updates
+/// `client_a_to_b` forward as usual, then requests an update back down to an earlier
+/// height and asserts a consensus state was installed for every height walked.
+#[test]
+fn test_backward_client_update() -> Result<(), Error> {
+    run_binary_chain_test(&BackwardClientUpdateTest)
+}
+
+struct BackwardClientUpdateTest;
+
+impl BinaryChainTest for BackwardClientUpdateTest {
+    fn run<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        _relayer: RelayerDriver,
+        chains: ConnectedChains<ChainA, ChainB>,
+    ) -> Result<(), Error> {
+        let client = &chains.foreign_clients.client_a_to_b;
+
+        let trusted_height = client.latest_consensus_state_height()?;
+        let target_height = Height::new(trusted_height.revision_number(), 1)
+            .map_err(Error::generic)?;
+
+        let installed_heights = build_backward_update_client(client, target_height)?;
+
+        assert_consensus_states_exist(
+            &chains.handle_b,
+            client.id(),
+            &installed_heights,
+        )?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
    Implementation of [`ChainDriver`].
 */
 
+use core::fmt::Debug;
 use core::str::FromStr;
 use core::time::Duration;
 
@@ -29,6 +30,122 @@ pub mod interchain;
 pub mod query_txs;
 pub mod transfer;
 
+/**
+   The backend-specific parts of driving a chain's CLI: how to build the
+   arguments for (and parse the response of) operations whose shape varies
+   across SDK versions and chain implementations, such as `query bank
+   balances`. Everything else on [`ChainDriver`] -- the retry loop in
+   [`assert_eventual_wallet_amount`](ChainDriver::assert_eventual_wallet_amount),
+   the RPC/GRPC address conventions, `exec` itself -- is shared unchanged
+   across backends.
+*/
+pub trait ChainCliBackend: Debug + Send + Sync {
+    /// Builds the full `exec` arguments for querying `wallet_id`'s balance
+    /// in `denom`, given the chain's `--node` listen address.
+    fn query_balance_args(
+        &self,
+        node: &str,
+        wallet_id: &WalletAddress,
+        denom: &Denom,
+    ) -> Vec<String>;
+
+    /// Parses the stdout of a balance query built from
+    /// [`query_balance_args`](Self::query_balance_args) into the amount in
+    /// `denom`.
+    fn parse_balance(&self, stdout: &str, denom: &Denom) -> Result<u64, Error>;
+}
+
+/**
+   The [`ChainCliBackend`] for a single version of Gaia, the chain this crate
+   was originally hardcoded against. `query bank balances` on this backend
+   returns a single `{"amount": "..."}` object when passed `--denom`.
+*/
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GaiaCliBackend;
+
+impl ChainCliBackend for GaiaCliBackend {
+    fn query_balance_args(
+        &self,
+        node: &str,
+        wallet_id: &WalletAddress,
+        denom: &Denom,
+    ) -> Vec<String> {
+        vec![
+            "--node".to_owned(),
+            node.to_owned(),
+            "query".to_owned(),
+            "bank".to_owned(),
+            "balances".to_owned(),
+            wallet_id.0.clone(),
+            "--denom".to_owned(),
+            denom.as_str().to_owned(),
+            "--output".to_owned(),
+            "json".to_owned(),
+        ]
+    }
+
+    fn parse_balance(&self, stdout: &str, _denom: &Denom) -> Result<u64, Error> {
+        let amount_str = json::from_str::<json::Value>(stdout)
+            .map_err(handle_generic_error)?
+            .get("amount")
+            .ok_or_else(|| eyre!("expected amount field"))?
+            .as_str()
+            .ok_or_else(|| eyre!("expected string field"))?
+            .to_string();
+
+        u64::from_str(&amount_str).map_err(handle_generic_error)
+    }
+}
+
+/**
+   The [`ChainCliBackend`] for a non-Gaia SDK chain such as Neutron, whose
+   `query bank balances` no longer accepts `--denom` and instead always
+   returns the full `{"balances": [{"denom": ..., "amount": ...}, ...]}`
+   list, which must be filtered client-side for the denom of interest.
+*/
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NeutronCliBackend;
+
+impl ChainCliBackend for NeutronCliBackend {
+    fn query_balance_args(
+        &self,
+        node: &str,
+        wallet_id: &WalletAddress,
+        _denom: &Denom,
+    ) -> Vec<String> {
+        vec![
+            "--node".to_owned(),
+            node.to_owned(),
+            "query".to_owned(),
+            "bank".to_owned(),
+            "balances".to_owned(),
+            wallet_id.0.clone(),
+            "--output".to_owned(),
+            "json".to_owned(),
+        ]
+    }
+
+    fn parse_balance(&self, stdout: &str, denom: &Denom) -> Result<u64, Error> {
+        let balances = json::from_str::<json::Value>(stdout)
+            .map_err(handle_generic_error)?
+            .get("balances")
+            .ok_or_else(|| eyre!("expected balances field"))?
+            .as_array()
+            .ok_or_else(|| eyre!("expected balances array"))?
+            .clone();
+
+        let amount_str = balances
+            .iter()
+            .find(|coin| coin.get("denom").and_then(json::Value::as_str) == Some(denom.as_str()))
+            .and_then(|coin| coin.get("amount"))
+            .and_then(json::Value::as_str)
+            .unwrap_or("0")
+            .to_string();
+
+        u64::from_str(&amount_str).map_err(handle_generic_error)
+    }
+}
+
 /**
    Number of times (seconds) to try and query a wallet to reach the
    target amount, as used by [`assert_eventual_wallet_amount`].
@@ -60,8 +177,19 @@ const WAIT_WALLET_AMOUNT_ATTEMPTS: u16 = 90;
 #[derive(Debug, Clone)]
 pub struct ChainDriver {
     pub chain_type: ChainType,
+
     /**
-       The filesystem path to the Gaia CLI. Defaults to `gaiad`.
+       The backend implementing the CLI operations whose argument shape and
+       response format vary across chain implementations/SDK versions, e.g.
+       [`GaiaCliBackend`] or [`NeutronCliBackend`]. [`ChainDriver::create`]
+       always sets [`GaiaCliBackend`], whatever the `chain_type`; another
+       backend is used only when passed to
+       [`create_with_backend`](ChainDriver::create_with_backend).
+    */
+    pub backend: Arc<dyn ChainCliBackend>,
+
+    /**
+       The filesystem path to the chain's CLI binary. Defaults to `gaiad`.
     */
     pub command_path: String,
 
@@ -109,7 +237,9 @@ impl ExportEnv for ChainDriver {
 }
 
 impl ChainDriver {
-    /// Create a new [`ChainDriver`]
+    /// Create a new [`ChainDriver`] backed by [`GaiaCliBackend`]. Use
+    /// [`create_with_backend`](Self::create_with_backend) to bootstrap a
+    /// non-Gaia chain implementation.
     pub fn create(
         chain_type: ChainType,
         command_path: String,
@@ -121,6 +251,37 @@ impl ChainDriver {
         grpc_web_port: u16,
         p2p_port: u16,
         runtime: Arc<Runtime>,
+    ) -> Result<Self, Error> {
+        Self::create_with_backend(
+            chain_type,
+            Arc::new(GaiaCliBackend),
+            command_path,
+            chain_id,
+            home_path,
+            account_prefix,
+            rpc_port,
+            grpc_port,
+            grpc_web_port,
+            p2p_port,
+            runtime,
+        )
+    }
+
+    /// Create a new [`ChainDriver`] using the given [`ChainCliBackend`] for
+    /// the operations whose CLI surface differs across chain implementations.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_with_backend(
+        chain_type: ChainType,
+        backend: Arc<dyn ChainCliBackend>,
+        command_path: String,
+        chain_id: ChainId,
+        home_path: String,
+        account_prefix: String,
+        rpc_port: u16,
+        grpc_port: u16,
+        grpc_web_port: u16,
+        p2p_port: u16,
+        runtime: Arc<Runtime>,
     ) -> Result<Self, Error> {
         let tx_config = new_tx_config_for_test(
             chain_id.clone(),
@@ -131,6 +292,7 @@ impl ChainDriver {
 
         Ok(Self {
             chain_type,
+            backend,
             command_path,
             chain_id,
             home_path,
@@ -198,35 +360,22 @@ impl ChainDriver {
     }
 
     /**
-       Query for the balances for a given wallet address and denomination
+       Query for the balances for a given wallet address and denomination.
+
+       The argument and response shape of the underlying `query bank
+       balances` invocation is delegated to `self.backend`, since it varies
+       across chain implementations and SDK versions.
     */
     pub fn query_balance(&self, wallet_id: &WalletAddress, denom: &Denom) -> Result<u64, Error> {
-        let res = self
-            .exec(&[
-                "--node",
-                &self.rpc_listen_address(),
-                "query",
-                "bank",
-                "balances",
-                &wallet_id.0,
-                "--denom",
-                denom.as_str(),
-                "--output",
-                "json",
-            ])?
-            .stdout;
-
-        let amount_str = json::from_str::<json::Value>(&res)
-            .map_err(handle_generic_error)?
-            .get("amount")
-            .ok_or_else(|| eyre!("expected amount field"))?
-            .as_str()
-            .ok_or_else(|| eyre!("expected string field"))?
-            .to_string();
+        let args = self
+            .backend
+            .query_balance_args(&self.rpc_listen_address(), wallet_id, denom);
+
+        let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
 
-        let amount = u64::from_str(&amount_str).map_err(handle_generic_error)?;
+        let res = self.exec(&arg_refs)?.stdout;
 
-        Ok(amount)
+        self.backend.parse_balance(&res, denom)
     }
 
     pub fn send_tx(&self, wallet: &Wallet, messages: Vec<Any>) -> Result<(), Error> {
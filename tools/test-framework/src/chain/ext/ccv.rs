@@ -0,0 +1,43 @@
+use serde_json as json;
+
+use crate::chain::driver::ChainDriver;
+use crate::error::{handle_generic_error, Error};
+use crate::types::tagged::*;
+
+/// Helpers for bootstrapping a Cross-Chain Validation (CCV) consumer chain
+/// against a provider's `genesis.json`, once the provider has produced the
+/// CCV genesis section via [`super::proposal::ChainProposalMethodsExt::query_consumer_genesis`].
+///
+/// An `InterchainSecurityChainTest` runner, analogous to `run_binary_chain_test`,
+/// would start the provider, drive a `consumer-addition` proposal to pass with
+/// [`super::proposal::ChainProposalMethodsExt`], splice the resulting section
+/// into the consumer's genesis with [`ChainCcvMethodsExt::splice_ccv_genesis`]
+/// below, and only then start the consumer node and open the ordered CCV
+/// channel over ports `provider`/`consumer`.
+pub trait ChainCcvMethodsExt {
+    /// Splices the `ccvconsumer` genesis section queried from the provider
+    /// into this (consumer) chain's not-yet-started `genesis.json`.
+    fn splice_ccv_genesis(&self, ccv_genesis: &json::Value) -> Result<(), Error>;
+}
+
+impl<'a, Chain: Send> ChainCcvMethodsExt for MonoTagged<Chain, &'a ChainDriver> {
+    fn splice_ccv_genesis(&self, ccv_genesis: &json::Value) -> Result<(), Error> {
+        let genesis_file = format!("{}/config/genesis.json", self.value().home_path);
+
+        let genesis_content =
+            std::fs::read_to_string(&genesis_file).map_err(handle_generic_error)?;
+
+        let mut genesis: json::Value =
+            json::from_str(&genesis_content).map_err(handle_generic_error)?;
+
+        genesis["app_state"]["ccvconsumer"] = ccv_genesis.clone();
+
+        std::fs::write(
+            &genesis_file,
+            json::to_string_pretty(&genesis).map_err(handle_generic_error)?,
+        )
+        .map_err(handle_generic_error)?;
+
+        Ok(())
+    }
+}
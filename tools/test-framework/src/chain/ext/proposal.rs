@@ -1,5 +1,10 @@
+use core::time::Duration;
+use std::time::SystemTime;
+
 use http::Uri;
+use ibc::core::ics24_host::identifier::ChainId;
 use prost::Message;
+use serde_json as json;
 
 use ibc_proto::cosmos::gov::v1beta1::{query_client::QueryClient, QueryProposalRequest};
 use ibc_proto::ibc::core::client::v1::UpgradeProposal;
@@ -7,7 +12,7 @@ use ibc_relayer::error::Error as RelayerError;
 
 use crate::chain::cli::upgrade::vote_proposal;
 use crate::chain::driver::ChainDriver;
-use crate::error::Error;
+use crate::error::{handle_generic_error, Error};
 use crate::types::tagged::*;
 
 pub trait ChainProposalMethodsExt {
@@ -18,6 +23,21 @@ pub trait ChainProposalMethodsExt {
     ) -> Result<u64, Error>;
 
     fn vote_proposal(&self) -> Result<(), Error>;
+
+    /// Submits a `consumer-addition` governance proposal that, once it passes,
+    /// schedules a Cross-Chain Validation consumer chain with the given
+    /// `consumer_chain_id` to spawn `spawn_delay` from now.
+    fn submit_consumer_addition_proposal(
+        &self,
+        consumer_chain_id: &ChainId,
+        spawn_delay: Duration,
+    ) -> Result<(), Error>;
+
+    /// Queries the CCV genesis section that the provider generated for the
+    /// given consumer chain, once its `consumer-addition` proposal's spawn
+    /// time has passed. This is the section that must be spliced into the
+    /// consumer's `genesis.json` before the consumer node is started.
+    fn query_consumer_genesis(&self, consumer_chain_id: &ChainId) -> Result<json::Value, Error>;
 }
 
 impl<'a, Chain: Send> ChainProposalMethodsExt for MonoTagged<Chain, &'a ChainDriver> {
@@ -40,6 +60,72 @@ impl<'a, Chain: Send> ChainProposalMethodsExt for MonoTagged<Chain, &'a ChainDri
         )?;
         Ok(())
     }
+
+    fn submit_consumer_addition_proposal(
+        &self,
+        consumer_chain_id: &ChainId,
+        spawn_delay: Duration,
+    ) -> Result<(), Error> {
+        let spawn_time = humantime::format_rfc3339(SystemTime::now() + spawn_delay).to_string();
+
+        let proposal = json::json!({
+            "title": format!("Add consumer chain {consumer_chain_id}"),
+            "description": "Add a new Cross-Chain Validation consumer chain",
+            "chain_id": consumer_chain_id.to_string(),
+            "initial_height": { "revision_height": 1 },
+            "genesis_hash": "",
+            "binary_hash": "",
+            "spawn_time": spawn_time,
+            "deposit": "10000000stake",
+        });
+
+        let proposal_file = format!("{}/consumer-addition-proposal.json", self.value().home_path);
+
+        std::fs::write(
+            &proposal_file,
+            json::to_string_pretty(&proposal).map_err(handle_generic_error)?,
+        )
+        .map_err(handle_generic_error)?;
+
+        self.value().exec(&[
+            "--node",
+            &self.value().rpc_listen_address(),
+            "tx",
+            "gov",
+            "submit-legacy-proposal",
+            "consumer-addition",
+            &proposal_file,
+            "--from",
+            "validator",
+            "--chain-id",
+            self.value().chain_id.as_str(),
+            "--home",
+            &self.value().home_path,
+            "--keyring-backend",
+            "test",
+            "--yes",
+        ])?;
+
+        Ok(())
+    }
+
+    fn query_consumer_genesis(&self, consumer_chain_id: &ChainId) -> Result<json::Value, Error> {
+        let res = self
+            .value()
+            .exec(&[
+                "--node",
+                &self.value().rpc_listen_address(),
+                "query",
+                "provider",
+                "consumer-genesis",
+                consumer_chain_id.as_str(),
+                "--output",
+                "json",
+            ])?
+            .stdout;
+
+        json::from_str(&res).map_err(handle_generic_error)
+    }
 }
 
 /// Query the proposal with the given proposal_id, which is supposed to be an UpgradeProposal.
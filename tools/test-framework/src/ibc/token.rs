@@ -1,5 +1,4 @@
 use core::fmt::{self, Display};
-use core::ops::{Add, Sub};
 use eyre::eyre;
 use ibc::applications::transfer::denom::{Amount, RawCoin};
 
@@ -47,6 +46,60 @@ impl Token {
             amount: self.amount,
         }
     }
+
+    /// Adds `other`'s amount to this token's, returning an error instead of panicking on
+    /// overflow or on an attempt to combine two different denoms.
+    pub fn checked_add(&self, other: &Token) -> Result<Self, Error> {
+        if self.denom != other.denom {
+            return Err(handle_generic_error(eyre!(
+                "cannot add tokens with different denoms: {} and {}",
+                self.denom,
+                other.denom
+            )));
+        }
+
+        let amount = self.amount.checked_add(other.amount).ok_or_else(|| {
+            handle_generic_error(eyre!(
+                "overflow adding {} {} to {} {}",
+                other.amount,
+                other.denom,
+                self.amount,
+                self.denom
+            ))
+        })?;
+
+        Ok(Self {
+            denom: self.denom.clone(),
+            amount,
+        })
+    }
+
+    /// Subtracts `other`'s amount from this token's, returning an error instead of panicking if
+    /// the balance is insufficient or the two tokens have different denoms.
+    pub fn checked_sub(&self, other: &Token) -> Result<Self, Error> {
+        if self.denom != other.denom {
+            return Err(handle_generic_error(eyre!(
+                "cannot subtract tokens with different denoms: {} and {}",
+                self.denom,
+                other.denom
+            )));
+        }
+
+        let amount = self.amount.checked_sub(other.amount).ok_or_else(|| {
+            handle_generic_error(eyre!(
+                "insufficient balance to subtract {} {} from {} {}",
+                other.amount,
+                other.denom,
+                self.amount,
+                self.denom
+            ))
+        })?;
+
+        Ok(Self {
+            denom: self.denom.clone(),
+            amount,
+        })
+    }
 }
 
 impl<Chain> TaggedTokenExt<Chain> for TaggedToken<Chain> {
@@ -107,41 +160,19 @@ impl<'a, Chain> TaggedDenomExt<Chain> for TaggedDenomRef<'a, Chain> {
     }
 }
 
-impl<I: Into<Amount>> Add<I> for Token {
-    type Output = Self;
+impl<Chain> MonoTagged<Chain, Token> {
+    /// See [`Token::checked_add`].
+    pub fn checked_add(&self, other: &Self) -> Result<Self, Error> {
+        let token = self.value().checked_add(other.value())?;
 
-    fn add(self, amount: I) -> Self {
-        Self {
-            denom: self.denom,
-            amount: self.amount.checked_add(amount).unwrap(),
-        }
+        Ok(MonoTagged::new(token))
     }
-}
 
-impl<I: Into<Amount>> Sub<I> for Token {
-    type Output = Self;
+    /// See [`Token::checked_sub`].
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, Error> {
+        let token = self.value().checked_sub(other.value())?;
 
-    fn sub(self, amount: I) -> Self {
-        Self {
-            denom: self.denom,
-            amount: self.amount.checked_sub(amount).unwrap(),
-        }
-    }
-}
-
-impl<Chain, I: Into<Amount>> Add<I> for MonoTagged<Chain, Token> {
-    type Output = Self;
-
-    fn add(self, amount: I) -> Self {
-        self.map_into(|t| t + amount.into())
-    }
-}
-
-impl<Chain, I: Into<Amount>> Sub<I> for MonoTagged<Chain, Token> {
-    type Output = Self;
-
-    fn sub(self, amount: I) -> Self {
-        self.map_into(|t| t - amount.into())
+        Ok(MonoTagged::new(token))
     }
 }
 
@@ -162,3 +193,128 @@ impl TryFrom<RawCoin> for Token {
         Ok(Token::new(denom, amount))
     }
 }
+
+/// An ordered, denom-deduplicated bundle of [`Token`]s, modeling the `Vec<Coin>` that Cosmos SDK
+/// messages and ICS-29 relayer-fee payloads (recv/ack/timeout fees) carry -- a single [`Token`]
+/// can only ever represent one denomination, which isn't enough for a multi-denom transfer or fee
+/// bundle.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Coins(Vec<Token>);
+
+pub type TaggedCoins<Chain> = MonoTagged<Chain, Coins>;
+pub type TaggedCoinsRef<'a, Chain> = MonoTagged<Chain, &'a Coins>;
+
+pub trait TaggedCoinsExt<Chain> {
+    fn tokens(&self) -> &[Token];
+
+    fn transfer<Counterparty>(
+        &self,
+        port_id: &TaggedPortIdRef<Counterparty, Chain>,
+        channel_id: &TaggedChannelIdRef<Counterparty, Chain>,
+    ) -> Result<TaggedCoins<Counterparty>, Error>;
+}
+
+impl Coins {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn tokens(&self) -> &[Token] {
+        &self.0
+    }
+
+    /// Merges `token` into this bundle, summing into the existing entry for its denom instead of
+    /// appending a duplicate.
+    pub fn add(&mut self, token: Token) -> Result<(), Error> {
+        if let Some(existing) = self.0.iter_mut().find(|t| t.denom == token.denom) {
+            *existing = existing.checked_add(&token)?;
+        } else {
+            self.0.push(token);
+        }
+
+        Ok(())
+    }
+
+    /// Subtracts `token`'s amount from the existing entry for its denom.
+    ///
+    /// # Errors
+    /// Returns an error if this bundle holds no entry for `token`'s denom, or an insufficient
+    /// amount of it.
+    pub fn sub(&mut self, token: Token) -> Result<(), Error> {
+        let existing = self
+            .0
+            .iter_mut()
+            .find(|t| t.denom == token.denom)
+            .ok_or_else(|| {
+                handle_generic_error(eyre!(
+                    "insufficient balance to subtract {} {}: no balance held for that denom",
+                    token.amount,
+                    token.denom
+                ))
+            })?;
+
+        *existing = existing.checked_sub(&token)?;
+
+        Ok(())
+    }
+}
+
+impl<Chain> TaggedCoinsExt<Chain> for TaggedCoins<Chain> {
+    fn tokens(&self) -> &[Token] {
+        self.value().tokens()
+    }
+
+    fn transfer<Counterparty>(
+        &self,
+        port_id: &TaggedPortIdRef<Counterparty, Chain>,
+        channel_id: &TaggedChannelIdRef<Counterparty, Chain>,
+    ) -> Result<TaggedCoins<Counterparty>, Error> {
+        let tokens = self
+            .value()
+            .tokens()
+            .iter()
+            .map(|token| {
+                let tagged_token: TaggedToken<Chain> = MonoTagged::new(token.clone());
+                tagged_token.transfer(port_id, channel_id)
+            })
+            .collect::<Result<Vec<_>, Error>>()?
+            .into_iter()
+            .map(|tagged_token| tagged_token.into_value())
+            .collect();
+
+        Ok(MonoTagged::new(Coins(tokens)))
+    }
+}
+
+impl TryFrom<Vec<RawCoin>> for Coins {
+    type Error = Error;
+
+    fn try_from(coins: Vec<RawCoin>) -> Result<Self, Error> {
+        let mut result = Self::new();
+
+        for coin in coins {
+            result.add(Token::try_from(coin)?)?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl From<Coins> for Vec<RawCoin> {
+    fn from(coins: Coins) -> Self {
+        coins.0.into_iter().map(|token| token.as_coin()).collect()
+    }
+}
+
+impl Display for Coins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered = self
+            .0
+            .iter()
+            .map(|token| token.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        write!(f, "{rendered}")
+    }
+}
@@ -0,0 +1,323 @@
+use core::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{IncludeProof, QueryChannelRequest, QueryHeight};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, Ordering, State};
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::ConnectionId;
+
+use crate::error::{handle_generic_error, Error};
+use crate::types::id::{TaggedChannelIdRef, TaggedPortIdRef};
+use crate::util::retry::assert_eventually_succeed;
+
+const CHANNEL_UPGRADE_ATTEMPTS: u16 = 10;
+const CHANNEL_UPGRADE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The channel version metadata negotiated when opening an ICS-27 Interchain Accounts channel,
+/// e.g. `{"version":"ics27-1","controller_connection_id":...,"host_connection_id":...,
+/// "encoding":"proto3","tx_type":"sdk_multi_msg"}`. A host chain is expected to echo this back
+/// verbatim (with its own connection ids filled in) rather than overwrite it with some other
+/// negotiated version, since it also carries the encoding/tx-type the controller requires.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InterchainAccountVersion {
+    pub version: String,
+    pub controller_connection_id: ConnectionId,
+    pub host_connection_id: ConnectionId,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl InterchainAccountVersion {
+    pub fn new(controller_connection_id: ConnectionId, host_connection_id: ConnectionId) -> Self {
+        Self {
+            version: "ics27-1".to_owned(),
+            controller_connection_id,
+            host_connection_id,
+            encoding: "proto3".to_owned(),
+            tx_type: "sdk_multi_msg".to_owned(),
+        }
+    }
+
+    /// Renders this metadata as the raw channel [`Version`] string carried in
+    /// `MsgChannelOpenInit`/`MsgChannelOpenTry`.
+    pub fn to_version(&self) -> Result<Version, Error> {
+        let raw = serde_json::to_string(self).map_err(handle_generic_error)?;
+
+        Ok(Version::new(raw))
+    }
+
+    /// Parses a channel [`Version`] proposed by the counterparty back into its ICA metadata, so
+    /// that it can be echoed rather than replaced during channel open negotiation.
+    pub fn from_version(version: &Version) -> Result<Self, Error> {
+        serde_json::from_str(&version.to_string()).map_err(handle_generic_error)
+    }
+}
+
+/// Captures the fields of a [`ChannelEnd`] that an upgrade handshake is allowed to change, as
+/// observed before the handshake started. Used to assert that a channel end still carries its
+/// pre-upgrade attributes (e.g. while the counterparty hasn't acted yet, or once an in-flight
+/// upgrade has been cancelled/timed out).
+#[derive(Clone, Debug)]
+pub struct ChannelUpgradableAttributes {
+    pub version: Version,
+    pub ordering: Ordering,
+    pub connection_hops_a: Vec<ConnectionId>,
+    pub connection_hops_b: Vec<ConnectionId>,
+}
+
+impl ChannelUpgradableAttributes {
+    pub fn new(
+        version: Version,
+        ordering: Ordering,
+        connection_hops_a: Vec<ConnectionId>,
+        connection_hops_b: Vec<ConnectionId>,
+    ) -> Self {
+        Self {
+            version,
+            ordering,
+            connection_hops_a,
+            connection_hops_b,
+        }
+    }
+
+    /// Swaps the `a`/`b` connection hops, for checking these attributes from the
+    /// counterparty's point of view.
+    pub fn flipped(&self) -> Self {
+        Self {
+            version: self.version.clone(),
+            ordering: self.ordering,
+            connection_hops_a: self.connection_hops_b.clone(),
+            connection_hops_b: self.connection_hops_a.clone(),
+        }
+    }
+}
+
+fn query_channel_end<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle: &ChainA,
+    port_id: &TaggedPortIdRef<'_, ChainA, ChainB>,
+    channel_id: &TaggedChannelIdRef<'_, ChainA, ChainB>,
+) -> Result<ChannelEnd, Error> {
+    handle
+        .query_channel(
+            QueryChannelRequest {
+                port_id: port_id.value().clone(),
+                channel_id: channel_id.value().clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map(|(channel_end, _)| channel_end)
+        .map_err(handle_generic_error)
+}
+
+/// Queries, on `counterparty_handle`, the channel end on the other side of `channel_end`.
+fn query_counterparty_channel_end<ChainA: ChainHandle, ChainB: ChainHandle>(
+    counterparty_handle: &ChainB,
+    channel_end: &ChannelEnd,
+) -> Result<ChannelEnd, Error> {
+    let counterparty = channel_end.counterparty();
+
+    let counterparty_channel_id = counterparty
+        .channel_id()
+        .ok_or_else(|| Error::generic(eyre::eyre!("channel end has no counterparty channel id")))?;
+
+    counterparty_handle
+        .query_channel(
+            QueryChannelRequest {
+                port_id: counterparty.port_id().clone(),
+                channel_id: counterparty_channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map(|(channel_end, _)| channel_end)
+        .map_err(handle_generic_error)
+}
+
+fn ensure_channel_state(channel_end: &ChannelEnd, expected: &State) -> Result<(), Error> {
+    if channel_end.state_matches(expected) {
+        Ok(())
+    } else {
+        Err(Error::generic(eyre::eyre!(
+            "expected channel to be in `{}` state, but it is in `{}` state",
+            expected,
+            channel_end.state()
+        )))
+    }
+}
+
+/// Asserts that `channel_end`'s version/ordering/connection hops (the `a`-side hops of
+/// `attrs`) still match its pre-upgrade values.
+fn ensure_channel_attributes(
+    channel_end: &ChannelEnd,
+    attrs: &ChannelUpgradableAttributes,
+) -> Result<(), Error> {
+    if channel_end.version() != &attrs.version {
+        return Err(Error::generic(eyre::eyre!(
+            "expected channel version `{}`, but got `{}`",
+            attrs.version,
+            channel_end.version()
+        )));
+    }
+
+    if channel_end.ordering() != &attrs.ordering {
+        return Err(Error::generic(eyre::eyre!(
+            "expected channel ordering `{:?}`, but got `{:?}`",
+            attrs.ordering,
+            channel_end.ordering()
+        )));
+    }
+
+    if channel_end.connection_hops() != &attrs.connection_hops_a {
+        return Err(Error::generic(eyre::eyre!(
+            "expected channel connection hops `{:?}`, but got `{:?}`",
+            attrs.connection_hops_a,
+            channel_end.connection_hops()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Asserts that the channel identified by `channel_id_a`/`port_id_a` (on `handle_a`) and its
+/// counterparty (on `handle_b`) both eventually reach the `Open` state.
+pub fn assert_eventually_channel_established<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    handle_b: &ChainB,
+    channel_id_a: &TaggedChannelIdRef<'_, ChainA, ChainB>,
+    port_id_a: &TaggedPortIdRef<'_, ChainA, ChainB>,
+) -> Result<(), Error> {
+    assert_eventually_succeed(
+        "channel should eventually be established on both ends",
+        CHANNEL_UPGRADE_ATTEMPTS,
+        CHANNEL_UPGRADE_INTERVAL,
+        || {
+            let channel_end_a = query_channel_end(handle_a, port_id_a, channel_id_a)?;
+            ensure_channel_state(&channel_end_a, &State::Open)?;
+
+            let channel_end_b = query_counterparty_channel_end(handle_b, &channel_end_a)?;
+            ensure_channel_state(&channel_end_b, &State::Open)
+        },
+    )
+}
+
+/// Asserts that `channel_id_a` has moved to `Flushing` to propose an upgrade, while its
+/// counterparty is still `Open` and unchanged (it hasn't seen TRY yet).
+pub fn assert_eventually_channel_upgrade_init<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    handle_b: &ChainB,
+    channel_id_a: &TaggedChannelIdRef<'_, ChainA, ChainB>,
+    port_id_a: &TaggedPortIdRef<'_, ChainA, ChainB>,
+    attrs: &ChannelUpgradableAttributes,
+) -> Result<(), Error> {
+    assert_eventually_succeed(
+        "channel should eventually move to the Flushing state to propose an upgrade",
+        CHANNEL_UPGRADE_ATTEMPTS,
+        CHANNEL_UPGRADE_INTERVAL,
+        || {
+            let channel_end_a = query_channel_end(handle_a, port_id_a, channel_id_a)?;
+            ensure_channel_state(&channel_end_a, &State::Flushing)?;
+
+            let channel_end_b = query_counterparty_channel_end(handle_b, &channel_end_a)?;
+            ensure_channel_state(&channel_end_b, &State::Open)?;
+            ensure_channel_attributes(&channel_end_b, &attrs.flipped())
+        },
+    )
+}
+
+/// Asserts that `channel_id_a` has moved to `Flushing` to accept a proposed upgrade, and that
+/// its counterparty has also moved to `Flushing` after having its TRY verified.
+pub fn assert_eventually_channel_upgrade_try<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    handle_b: &ChainB,
+    channel_id_a: &TaggedChannelIdRef<'_, ChainA, ChainB>,
+    port_id_a: &TaggedPortIdRef<'_, ChainA, ChainB>,
+    attrs: &ChannelUpgradableAttributes,
+) -> Result<(), Error> {
+    assert_eventually_succeed(
+        "channel should eventually move to the Flushing state to accept an upgrade",
+        CHANNEL_UPGRADE_ATTEMPTS,
+        CHANNEL_UPGRADE_INTERVAL,
+        || {
+            let channel_end_a = query_channel_end(handle_a, port_id_a, channel_id_a)?;
+            ensure_channel_state(&channel_end_a, &State::Flushing)?;
+
+            let channel_end_b = query_counterparty_channel_end(handle_b, &channel_end_a)?;
+            ensure_channel_state(&channel_end_b, &State::Flushing)?;
+            ensure_channel_attributes(&channel_end_b, &attrs.flipped())
+        },
+    )
+}
+
+/// Asserts that `channel_id_a` has moved to `FlushComplete` after acking the upgrade (no
+/// in-flight packets remained to drain), while its counterparty is still `Flushing`.
+pub fn assert_eventually_channel_upgrade_ack<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    handle_b: &ChainB,
+    channel_id_a: &TaggedChannelIdRef<'_, ChainA, ChainB>,
+    port_id_a: &TaggedPortIdRef<'_, ChainA, ChainB>,
+    attrs: &ChannelUpgradableAttributes,
+) -> Result<(), Error> {
+    assert_eventually_succeed(
+        "channel should eventually move to FlushComplete after acking an upgrade",
+        CHANNEL_UPGRADE_ATTEMPTS,
+        CHANNEL_UPGRADE_INTERVAL,
+        || {
+            let channel_end_a = query_channel_end(handle_a, port_id_a, channel_id_a)?;
+            ensure_channel_state(&channel_end_a, &State::FlushComplete)?;
+            ensure_channel_attributes(&channel_end_a, attrs)?;
+
+            let channel_end_b = query_counterparty_channel_end(handle_b, &channel_end_a)?;
+            ensure_channel_state(&channel_end_b, &State::Flushing)
+        },
+    )
+}
+
+/// Asserts that `channel_id_a` and its counterparty have both been restored to their
+/// pre-upgrade `attrs` and are back in the `Open` state, i.e. an in-flight upgrade was
+/// cancelled after the counterparty wrote an `ErrorReceipt` and reverted.
+pub fn assert_eventually_channel_upgrade_cancel<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    handle_b: &ChainB,
+    channel_id_a: &TaggedChannelIdRef<'_, ChainA, ChainB>,
+    port_id_a: &TaggedPortIdRef<'_, ChainA, ChainB>,
+    attrs: &ChannelUpgradableAttributes,
+) -> Result<(), Error> {
+    assert_eventually_succeed(
+        "channel should eventually be restored to its pre-upgrade attributes on both ends after being cancelled",
+        CHANNEL_UPGRADE_ATTEMPTS,
+        CHANNEL_UPGRADE_INTERVAL,
+        || {
+            let channel_end_a = query_channel_end(handle_a, port_id_a, channel_id_a)?;
+            ensure_channel_state(&channel_end_a, &State::Open)?;
+            ensure_channel_attributes(&channel_end_a, attrs)?;
+
+            let channel_end_b = query_counterparty_channel_end(handle_b, &channel_end_a)?;
+            ensure_channel_state(&channel_end_b, &State::Open)?;
+            ensure_channel_attributes(&channel_end_b, &attrs.flipped())
+        },
+    )
+}
+
+/// Asserts that the channel identified by `channel_id_a`/`port_id_a` has been restored to its
+/// pre-upgrade `attrs` and is back in the `Open` state, i.e. an in-flight upgrade was timed out
+/// and reverted rather than completed.
+pub fn assert_eventually_channel_upgrade_timeout<ChainA: ChainHandle, ChainB: ChainHandle>(
+    handle_a: &ChainA,
+    channel_id_a: &TaggedChannelIdRef<'_, ChainA, ChainB>,
+    port_id_a: &TaggedPortIdRef<'_, ChainA, ChainB>,
+    attrs: &ChannelUpgradableAttributes,
+) -> Result<(), Error> {
+    assert_eventually_succeed(
+        "channel should eventually be restored to its pre-upgrade attributes after timing out",
+        CHANNEL_UPGRADE_ATTEMPTS,
+        CHANNEL_UPGRADE_INTERVAL,
+        || {
+            let channel_end_a = query_channel_end(handle_a, port_id_a, channel_id_a)?;
+            ensure_channel_state(&channel_end_a, &State::Open)?;
+            ensure_channel_attributes(&channel_end_a, attrs)
+        },
+    )
+}
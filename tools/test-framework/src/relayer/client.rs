@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use ibc::clients::ics07_tendermint::client_state::ClientState as TendermintClientState;
+use ibc::core::ics02_client::client_state::AnyClientState;
+use ibc::core::ics02_client::height::Height;
+use ibc::core::ics24_host::identifier::ClientId;
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::foreign_client::ForeignClient;
+
+use crate::error::{handle_generic_error, Error};
+
+/// Converts the `AnyClientState` a chain handle returns into the concrete client state a caller
+/// expects, so that [`query_client_states`] can be written once and reused for any light client
+/// type, rather than hard-coding a match over `AnyClientState::Tendermint`. A caller exercising a
+/// non-Tendermint light client (e.g. a Beefy/GRANDPA-style client) implements this for its own
+/// `ClientState` type instead of adding another arm here.
+pub trait FromAnyClientState: Sized {
+    fn from_any_client_state(state: AnyClientState) -> Result<Self, Error>;
+}
+
+impl FromAnyClientState for TendermintClientState {
+    fn from_any_client_state(state: AnyClientState) -> Result<Self, Error> {
+        match state {
+            AnyClientState::Tendermint(state) => Ok(state),
+            #[allow(unreachable_patterns)]
+            other => Err(Error::generic(eyre::eyre!(
+                "expected a Tendermint client state, got: {:?}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Queries the client state for a single `(handle, client_id)` pair. Delegates to
+/// [`query_client_states`] so that callers asserting on just one client still benefit from the
+/// same `AnyClientState` conversion logic as the batched API.
+pub fn query_client_state<Chain: ChainHandle, ClientState: FromAnyClientState>(
+    handle: &Chain,
+    client_id: &ClientId,
+) -> Result<ClientState, Error> {
+    let mut states = query_client_states(&[(handle.clone(), client_id.clone())])?;
+
+    states
+        .remove(client_id)
+        .ok_or_else(|| Error::generic(eyre::eyre!("missing client state for {}", client_id)))
+}
+
+/// Queries the client states for many `(chain handle, client_id)` pairs concurrently, rather than
+/// issuing one synchronous gRPC query per client and serializing the whole batch. Useful for
+/// tests that create and then assert on dozens of clients, or on n-ary chain topologies.
+pub fn query_client_states<Chain: ChainHandle, ClientState: FromAnyClientState>(
+    pairs: &[(Chain, ClientId)],
+) -> Result<HashMap<ClientId, ClientState>, Error> {
+    let rt = tokio::runtime::Runtime::new().map_err(handle_generic_error)?;
+
+    rt.block_on(async {
+        let mut join_set = tokio::task::JoinSet::new();
+
+        for (handle, client_id) in pairs.iter().cloned() {
+            join_set.spawn_blocking(move || {
+                let state = handle
+                    .query_client_state(&client_id, Height::zero())
+                    .map_err(handle_generic_error)?;
+                let state = ClientState::from_any_client_state(state)?;
+                Ok::<_, Error>((client_id, state))
+            });
+        }
+
+        let mut results = HashMap::new();
+
+        while let Some(res) = join_set.join_next().await {
+            let (client_id, state) = res.map_err(handle_generic_error)??;
+            results.insert(client_id, state);
+        }
+
+        Ok(results)
+    })
+}
+
+/// Drives `client` to trust a `target_height` that may be *behind* its currently trusted height.
+///
+/// A forward update (`target_height` ahead of the trusted height) can skip straight to
+/// `target_height` using trust-threshold voting-power checks against the already-trusted header.
+/// That shortcut isn't sound going backward, since an earlier header isn't attested to by the
+/// validator set of a *later* trusted header. Instead this walks the destination chain's view of
+/// the source chain down one header at a time -- `trusted_height - 1`, `trusted_height - 2`, ...,
+/// `target_height` -- accepting header `n` only once `hash(header_n)` matches the
+/// `last_block_id.hash` recorded in the already-accepted header `n + 1`, anchoring the chain of
+/// hashes to the header already trusted at `trusted_height`. No voting-power check is made on
+/// this path: it relies on block-hash continuity rather than validator overlap, mirroring the
+/// light client's (unstable) backward-verification mode.
+///
+/// Returns the heights, from `trusted_height - 1` down to `target_height`, for which a consensus
+/// state was installed on the destination chain.
+pub fn build_backward_update_client<DstChain: ChainHandle, SrcChain: ChainHandle>(
+    client: &ForeignClient<DstChain, SrcChain>,
+    target_height: Height,
+) -> Result<Vec<Height>, Error> {
+    let trusted_height = client
+        .latest_consensus_state_height()
+        .map_err(handle_generic_error)?;
+
+    if target_height >= trusted_height {
+        return Err(Error::generic(eyre::eyre!(
+            "backward update requires a target height below the currently trusted height {}, got {}",
+            trusted_height,
+            target_height,
+        )));
+    }
+
+    let mut installed = Vec::new();
+    let mut anchor_height = trusted_height;
+
+    let mut height = trusted_height.decrement().map_err(handle_generic_error)?;
+    loop {
+        client
+            .build_and_send_backward_update_client(&anchor_height, &height)
+            .map_err(handle_generic_error)?;
+
+        installed.push(height);
+        anchor_height = height;
+
+        if height == target_height {
+            break;
+        }
+
+        height = height.decrement().map_err(handle_generic_error)?;
+    }
+
+    Ok(installed)
+}
+
+/// Asserts that the destination chain behind `client` has a consensus state installed for every
+/// height in `heights`, as populated by [`build_backward_update_client`].
+pub fn assert_consensus_states_exist<DstChain: ChainHandle>(
+    handle: &DstChain,
+    client_id: &ClientId,
+    heights: &[Height],
+) -> Result<(), Error> {
+    for height in heights {
+        handle
+            .query_consensus_state(
+                ibc_relayer::chain::requests::QueryConsensusStateRequest {
+                    client_id: client_id.clone(),
+                    consensus_height: *height,
+                    query_height: ibc_relayer::chain::requests::QueryHeight::Latest,
+                },
+                ibc_relayer::chain::requests::IncludeProof::No,
+            )
+            .map_err(handle_generic_error)?;
+    }
+
+    Ok(())
+}
+
+/// Builds a duplicate-vote-style misbehaviour at `client`'s latest trusted height -- two
+/// conflicting signed headers for the same height -- and submits it in a `MsgSubmitMisbehaviour`
+/// against `client`. A client whose `trust_threshold`/`trusting_period` enforcement is wired up
+/// correctly freezes on receiving it; [`query_client_state`] can then be used to assert that the
+/// returned client state's `frozen_height` is set.
+pub fn submit_duplicate_vote_misbehaviour<DstChain: ChainHandle, SrcChain: ChainHandle>(
+    client: &ForeignClient<DstChain, SrcChain>,
+) -> Result<(), Error> {
+    let trusted_height = client
+        .latest_consensus_state_height()
+        .map_err(handle_generic_error)?;
+
+    client
+        .build_and_send_misbehaviour(&trusted_height)
+        .map_err(handle_generic_error)?;
+
+    Ok(())
+}
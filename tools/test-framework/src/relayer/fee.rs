@@ -10,6 +10,7 @@ use ibc_relayer::chain::cosmos::query::fee::{
     query_counterparty_address as raw_query_counterparty_address,
     query_incentivized_packets as raw_query_incentivized_packets,
 };
+use ibc_relayer::chain::cosmos::query::packet::query_unreceived_packets as raw_query_unreceived_packets;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
 
 use crate::error::{handle_generic_error, Error};
@@ -31,9 +32,17 @@ pub async fn ibc_token_transfer_with_fee<SrcChain, DstChain>(
     ack_fee: &TaggedTokenRef<'_, SrcChain>,
     timeout_fee: &TaggedTokenRef<'_, SrcChain>,
     timeout: Duration,
+    memo: &str,
 ) -> Result<Vec<IbcEvent>, Error> {
-    let transfer_message =
-        build_transfer_message(port_id, channel_id, sender, recipient, send_amount, timeout)?;
+    let transfer_message = build_transfer_message(
+        port_id,
+        channel_id,
+        sender,
+        recipient,
+        send_amount,
+        timeout,
+        memo,
+    )?;
 
     let pay_message = build_pay_packet_message(
         port_id.value(),
@@ -88,6 +97,95 @@ pub async fn pay_packet_fee<Chain, Counterparty>(
     Ok(())
 }
 
+/// Like [`pay_packet_fee`], but incentivizes every sequence in `sequences` in a single tx,
+/// one `MsgPayPacketFeeAsync` each, instead of requiring one `pay_packet_fee` call per sequence.
+pub async fn pay_packet_fees<Chain, Counterparty>(
+    tx_config: &MonoTagged<Chain, &TxConfig>,
+    port_id: &TaggedPortIdRef<'_, Chain, Counterparty>,
+    channel_id: &TaggedChannelIdRef<'_, Chain, Counterparty>,
+    sequences: &[DualTagged<Chain, Counterparty, Sequence>],
+    payer: &MonoTagged<Chain, &Wallet>,
+    receive_fee: &TaggedTokenRef<'_, Chain>,
+    ack_fee: &TaggedTokenRef<'_, Chain>,
+    timeout_fee: &TaggedTokenRef<'_, Chain>,
+) -> Result<(), Error> {
+    let payer_address = payer
+        .value()
+        .address
+        .0
+        .parse()
+        .map_err(handle_generic_error)?;
+
+    let messages = sequences
+        .iter()
+        .map(|sequence| {
+            build_pay_packet_fee_async_message(
+                port_id.value(),
+                channel_id.value(),
+                *sequence.value(),
+                &payer_address,
+                vec![receive_fee.value().as_coin()],
+                vec![ack_fee.value().as_coin()],
+                vec![timeout_fee.value().as_coin()],
+            )
+            .map_err(handle_generic_error)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    simple_send_tx(tx_config.value(), &payer.value().key, messages).await?;
+
+    Ok(())
+}
+
+/// Tops up fees for a channel's backlog of pending packets in one shot: queries the packets that
+/// are still unreceived on the counterparty, diffs them against the packets that already have an
+/// incentive registered (via [`query_incentivized_packets`]), and pays `pay_packet_fees` only for
+/// the sequences that are still pending and not yet incentivized.
+pub async fn auto_incentivize_unreceived<Chain, Counterparty>(
+    grpc_address: &Uri,
+    tx_config: &MonoTagged<Chain, &TxConfig>,
+    port_id: &TaggedPortIdRef<'_, Chain, Counterparty>,
+    channel_id: &TaggedChannelIdRef<'_, Chain, Counterparty>,
+    payer: &MonoTagged<Chain, &Wallet>,
+    receive_fee: &TaggedTokenRef<'_, Chain>,
+    ack_fee: &TaggedTokenRef<'_, Chain>,
+    timeout_fee: &TaggedTokenRef<'_, Chain>,
+) -> Result<(), Error> {
+    let unreceived_sequences =
+        raw_query_unreceived_packets(grpc_address, channel_id.value(), port_id.value())
+            .await
+            .map_err(handle_generic_error)?;
+
+    let incentivized_packets =
+        query_incentivized_packets(grpc_address, channel_id, port_id).await?;
+
+    let pending_sequences: Vec<_> = unreceived_sequences
+        .into_iter()
+        .filter(|sequence| {
+            !incentivized_packets
+                .iter()
+                .any(|packet| packet.packet_id.sequence == *sequence)
+        })
+        .map(DualTagged::new)
+        .collect();
+
+    if pending_sequences.is_empty() {
+        return Ok(());
+    }
+
+    pay_packet_fees(
+        tx_config,
+        port_id,
+        channel_id,
+        &pending_sequences,
+        payer,
+        receive_fee,
+        ack_fee,
+        timeout_fee,
+    )
+    .await
+}
+
 pub async fn register_counterparty_address<Chain, Counterparty>(
     tx_config: &MonoTagged<Chain, &TxConfig>,
     wallet: &MonoTagged<Chain, &Wallet>,